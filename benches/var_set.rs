@@ -0,0 +1,42 @@
+//! Micro-benchmark of growing `VarBitmap::set` loop. Compares minimal strategy, which resizes
+//! container directly, with an equivalent strategy that goes through `try_grow`:
+//!
+//! ```text
+//! cargo bench --bench var_set
+//! ```
+
+use std::time::{Duration, Instant};
+
+use bitmac::{grow_strategy::GrowStrategy, LimitStrategy, MinimumRequiredStrategy, VarBitmap, LSB};
+
+fn run<S>(strategy: S) -> (Duration, usize)
+where
+    S: GrowStrategy + Clone,
+{
+    let iterations = 100;
+    let bits = 1 << 16;
+    let start = Instant::now();
+    let mut res = 0;
+    for _ in 0..iterations {
+        let mut bitmap = VarBitmap::<Vec<u8>, LSB, _>::new(Vec::new(), strategy.clone());
+        for idx in (0..bits).step_by(3) {
+            bitmap.set(idx, true);
+        }
+        res += bitmap.as_ref().len();
+    }
+    (start.elapsed() / iterations, res)
+}
+
+fn main() {
+    let (minimal, minimal_res) = run(MinimumRequiredStrategy);
+    let (general, general_res) = run(LimitStrategy {
+        strategy: MinimumRequiredStrategy,
+        limit: usize::MAX,
+    });
+    assert_eq!(minimal_res, general_res);
+
+    println!(
+        "set loop: {:?} per iteration with minimal strategy, {:?} through try_grow (checksum: {})",
+        minimal, general, minimal_res
+    );
+}
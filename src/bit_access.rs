@@ -1,7 +1,19 @@
 use crate::number::Number;
 
+/// Order of bits in slot, a runtime representation of [`BitAccess`] implementation.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BitOrder {
+    /// 0th bit is the least significant bit, see [`LSB`].
+    LSB,
+    /// 0th bit is the most significant bit, see [`MSB`].
+    MSB,
+}
+
 // Trait that provides functions for accessing single bit in number.
 pub trait BitAccess: private::Sealed {
+    /// Order of bits in slot.
+    const ORDER: BitOrder;
+
     /// Changes bit state.
     fn set<N>(num: N, bit_idx: usize, state: bool) -> N
     where
@@ -11,6 +23,18 @@ pub trait BitAccess: private::Sealed {
     fn get<N>(num: N, bit_idx: usize) -> bool
     where
         N: Number;
+
+    /// Moves every bit `count` positions towards 0th bit (bit `i` becomes bit `i - count`).
+    /// Bits shifted out are lost, freed bits are `0`.
+    fn shift_to_first<N>(num: N, count: usize) -> N
+    where
+        N: Number;
+
+    /// Moves every bit `count` positions towards the last bit (bit `i` becomes bit `i + count`).
+    /// Bits shifted out are lost, freed bits are `0`.
+    fn shift_to_last<N>(num: N, count: usize) -> N
+    where
+        N: Number;
 }
 
 /// *Most Significant Bit* is a rule for bit accessing when 0th bit is the most significant bit (the last bit in order).
@@ -24,6 +48,8 @@ pub trait BitAccess: private::Sealed {
 pub struct MSB;
 
 impl BitAccess for MSB {
+    const ORDER: BitOrder = BitOrder::MSB;
+
     fn set<N>(num: N, bit_idx: usize, state: bool) -> N
     where
         N: Number,
@@ -47,6 +73,28 @@ impl BitAccess for MSB {
         let bit_idx = N::BITS_COUNT - bit_idx - 1;
         num & (N::ONE << bit_idx) != N::ZERO
     }
+
+    fn shift_to_first<N>(num: N, count: usize) -> N
+    where
+        N: Number,
+    {
+        if count >= N::BITS_COUNT {
+            N::ZERO
+        } else {
+            num << count
+        }
+    }
+
+    fn shift_to_last<N>(num: N, count: usize) -> N
+    where
+        N: Number,
+    {
+        if count >= N::BITS_COUNT {
+            N::ZERO
+        } else {
+            num >> count
+        }
+    }
 }
 
 /// *Least Significant Bit* is a rule for bit accessing when 0th bit is the least significant bit (the first bit in order).
@@ -60,6 +108,8 @@ impl BitAccess for MSB {
 pub struct LSB;
 
 impl BitAccess for LSB {
+    const ORDER: BitOrder = BitOrder::LSB;
+
     fn set<N>(num: N, bit_idx: usize, state: bool) -> N
     where
         N: Number,
@@ -81,6 +131,28 @@ impl BitAccess for LSB {
 
         num & (N::ONE << bit_idx) != N::ZERO
     }
+
+    fn shift_to_first<N>(num: N, count: usize) -> N
+    where
+        N: Number,
+    {
+        if count >= N::BITS_COUNT {
+            N::ZERO
+        } else {
+            num >> count
+        }
+    }
+
+    fn shift_to_last<N>(num: N, count: usize) -> N
+    where
+        N: Number,
+    {
+        if count >= N::BITS_COUNT {
+            N::ZERO
+        } else {
+            num << count
+        }
+    }
 }
 
 mod private {
@@ -93,6 +165,7 @@ mod private {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -179,4 +252,23 @@ mod tests {
         assert_eq!(LSB::get(0b0100_0000u8, 6usize), true);
         assert_eq!(LSB::get(0b1000_0000u8, 7usize), true);
     }
+
+    #[test]
+    fn test_shift() {
+        assert_eq!(LSB::shift_to_first(0b0000_1100u8, 2), 0b0000_0011);
+        assert_eq!(LSB::shift_to_last(0b0000_1100u8, 2), 0b0011_0000);
+        assert_eq!(LSB::shift_to_first(0b1111_1111u8, 8), 0);
+        assert_eq!(LSB::shift_to_last(0b1111_1111u8, 8), 0);
+
+        assert_eq!(MSB::shift_to_first(0b0011_0000u8, 2), 0b1100_0000);
+        assert_eq!(MSB::shift_to_last(0b0011_0000u8, 2), 0b0000_1100);
+        assert_eq!(MSB::shift_to_first(0b1111_1111u8, 8), 0);
+        assert_eq!(MSB::shift_to_last(0b1111_1111u8, 8), 0);
+
+        // Bit `i` becomes bit `i - 3`
+        let v = LSB::set(0u16, 10, true);
+        assert!(LSB::get(LSB::shift_to_first(v, 3), 7));
+        let v = MSB::set(0u16, 10, true);
+        assert!(MSB::get(MSB::shift_to_first(v, 3), 7));
+    }
 }
@@ -0,0 +1,157 @@
+use std::{marker::PhantomData, ops::Range};
+
+use crate::{container::ContainerRead, BitAccess};
+
+/// A read-only view of a container as a sequence of bits.
+///
+/// Unlike slot access, the view addresses single bits and can be narrowed down to a sub-range
+/// of bits with [`range`]. Its length is the number of bits in the viewed range.
+///
+/// Usage example:
+/// ```
+/// use bitmac::{StaticBitmap, LSB};
+///
+/// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0001]);
+/// let bits = bitmap.bits();
+/// assert_eq!(bits.len(), 16);
+/// assert!(bits.at(3));
+/// let sub = bits.range(3..9);
+/// assert_eq!(sub.iter().collect::<Vec<_>>(), vec![true, false, false, false, false, true]);
+/// ```
+///
+/// [`range`]: crate::bit_slice::BitSlice::range
+pub struct BitSlice<'a, D, B> {
+    data: &'a D,
+    start: usize,
+    len: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, D, B> BitSlice<'a, D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    /// Creates new view over every bit of container.
+    pub fn new(data: &'a D) -> Self {
+        Self {
+            data,
+            start: 0,
+            len: data.bits_count(),
+            phantom: Default::default(),
+        }
+    }
+
+    /// Returns number of bits in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the view has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets state of `idx` bit of the view.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `idx` is out of bounds.
+    /// See non-panic function [`get`].
+    ///
+    /// [`get`]: crate::bit_slice::BitSlice::get
+    pub fn at(&self, idx: usize) -> bool {
+        assert!(idx < self.len, "index {} is out of 0..{}", idx, self.len);
+        self.data.get_bit(self.start + idx)
+    }
+
+    /// Gets state of `idx` bit of the view, returns `None` if `idx` is out of bounds.
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        if idx < self.len {
+            Some(self.data.get_bit(self.start + idx))
+        } else {
+            None
+        }
+    }
+
+    /// Returns iterator over states of every bit of the view.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + 'a {
+        let data = self.data;
+        (self.start..self.start + self.len).map(move |idx| data.get_bit(idx))
+    }
+
+    /// Returns sub-view of bits `range` of this view.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if the range is decreasing or exceeds the view.
+    pub fn range(&self, range: Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "range {:?} is out of 0..{}",
+            range,
+            self.len
+        );
+        Self {
+            data: self.data,
+            start: self.start + range.start,
+            len: range.end - range.start,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B> Clone for BitSlice<'_, D, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D, B> Copy for BitSlice<'_, D, B> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MinimumRequiredStrategy, StaticBitmap, VarBitmap, LSB, MSB};
+
+    #[test]
+    fn at_matches_get() {
+        let bitmap = StaticBitmap::<_, MSB>::new([0b1010_0110u8, 0b0000_1111, 0b1000_0001]);
+        let bits = bitmap.bits();
+        assert_eq!(bits.len(), 24);
+        for i in 0..24 {
+            assert_eq!(bits.at(i), bitmap.get(i));
+            assert_eq!(bits.get(i), Some(bitmap.get(i)));
+        }
+        assert_eq!(bits.get(24), None);
+        assert_eq!(bits.iter().collect::<Vec<_>>(), bitmap.to_bool_vec());
+
+        let bitmap =
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1000u8]);
+        assert_eq!(bitmap.bits().at(3), bitmap.get(3));
+    }
+
+    #[test]
+    fn range() {
+        let bitmap = StaticBitmap::<_, LSB>::new([0b1010_0110u8, 0b0000_1111]);
+        let expected = bitmap.to_bool_vec();
+        let bits = bitmap.bits();
+        for start in 0..=16 {
+            for end in start..=16 {
+                let sub = bits.range(start..end);
+                assert_eq!(sub.len(), end - start);
+                assert_eq!(sub.iter().collect::<Vec<_>>(), &expected[start..end]);
+            }
+        }
+
+        let nested = bits.range(4..14).range(2..6);
+        assert_eq!(nested.iter().collect::<Vec<_>>(), &expected[6..10]);
+        assert!(bits.range(16..16).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn at_out_of_bounds() {
+        let bitmap = StaticBitmap::<_, LSB>::new([0u8; 2]);
+        bitmap.bits().range(4..8).at(4);
+    }
+}
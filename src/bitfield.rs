@@ -0,0 +1,137 @@
+/// Declares a newtype over [`StaticBitmap`] with a single number as container and named
+/// accessors for its bits.
+///
+/// Every field is declared as `getter, setter: bit_index;`, the getter returns state of the bit
+/// and the setter changes it. Indexes are checked at compile time: an index that doesn't fit
+/// the number fails to compile.
+///
+/// The newtype wraps `StaticBitmap<$number, LSB>`, the bitmap is available as field `.0`.
+///
+/// Usage example:
+/// ```
+/// use bitmac::bitfield;
+///
+/// bitfield! {
+///     /// Access permissions.
+///     pub struct Perms: u32 {
+///         read, set_read: 0;
+///         write, set_write: 1;
+///         exec, set_exec: 2;
+///     }
+/// }
+///
+/// let mut perms = Perms::new();
+/// perms.set_read(true);
+/// perms.set_exec(true);
+/// assert!(perms.read());
+/// assert!(!perms.write());
+/// assert_eq!(perms.bits(), 0b101);
+/// assert_eq!(Perms::from_bits(0b101), perms);
+/// ```
+///
+/// ```compile_fail,E0080
+/// use bitmac::bitfield;
+///
+/// bitfield! {
+///     struct Small: u8 {
+///         flag, set_flag: 8;
+///     }
+/// }
+/// ```
+///
+/// [`StaticBitmap`]: crate::static_bitmap::StaticBitmap
+#[macro_export]
+macro_rules! bitfield {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident: $ty:ty {
+            $(
+                $(#[$field_meta:meta])*
+                $get:ident, $set:ident: $idx:expr;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Default, Clone, PartialEq, Eq)]
+        $vis struct $name(pub $crate::StaticBitmap<$ty, $crate::LSB>);
+
+        impl $name {
+            /// Creates value with every bit cleared.
+            #[allow(dead_code)]
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Creates value from raw bits.
+            #[allow(dead_code)]
+            pub fn from_bits(bits: $ty) -> Self {
+                Self($crate::StaticBitmap::new(bits))
+            }
+
+            /// Returns raw bits.
+            #[allow(dead_code)]
+            pub fn bits(&self) -> $ty {
+                *self.0.as_ref()
+            }
+
+            $(
+                $(#[$field_meta])*
+                #[allow(dead_code)]
+                pub fn $get(&self) -> bool {
+                    const _: () = assert!(
+                        $idx < <$ty as $crate::number::Number>::BITS_COUNT,
+                        "bit index is out of bounds of the number",
+                    );
+                    self.0.get($idx)
+                }
+
+                $(#[$field_meta])*
+                #[allow(dead_code)]
+                pub fn $set(&mut self, val: bool) {
+                    self.0.set($idx, val);
+                }
+            )*
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($get), &self.$get()))*
+                    .finish()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    bitfield! {
+        struct Perms: u32 {
+            read, set_read: 0;
+            write, set_write: 1;
+            /// Execute.
+            exec, set_exec: 31;
+        }
+    }
+
+    #[test]
+    fn accessors() {
+        let mut perms = Perms::new();
+        assert_eq!(perms.bits(), 0);
+
+        perms.set_write(true);
+        assert_eq!(perms.bits(), 0b10);
+        perms.set_exec(true);
+        assert_eq!(perms.bits(), 0x8000_0002);
+        assert!(!perms.read());
+        assert!(perms.write());
+        assert!(perms.exec());
+
+        perms.set_write(false);
+        assert_eq!(perms, Perms::from_bits(0x8000_0000));
+        assert_eq!(
+            format!("{:?}", perms),
+            "Perms { read: false, write: false, exec: true }"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    fmt::{Debug, Formatter},
+    iter::FromIterator,
+};
+
+use crate::{iter::Ones, MinimumRequiredStrategy, VarBitmap, LSB};
+
+/// Bitmap that [`BitmapSet`] is built on: bytes as slots, [`LSB`] bit access
+/// and [`MinimumRequiredStrategy`].
+///
+/// [`BitmapSet`]: crate::bitmap_set::BitmapSet
+/// [`LSB`]: crate::bit_access::LSB
+/// [`MinimumRequiredStrategy`]: crate::grow_strategy::MinimumRequiredStrategy
+pub type DefaultVarBitmap = VarBitmap<Vec<u8>, LSB, MinimumRequiredStrategy>;
+
+/// Set of indexes backed by a bitmap. It is the "just give me a bitset" option: no container,
+/// bit access or grow strategy to choose, and the API mirrors `HashSet<usize>`.
+///
+/// Use [`VarBitmap`] or [`StaticBitmap`] directly to control memory layout.
+///
+/// Usage example:
+/// ```
+/// use bitmac::BitmapSet;
+///
+/// let mut set = BitmapSet::new();
+/// assert!(set.insert(3));
+/// assert!(set.insert(100));
+/// assert!(!set.insert(3));
+/// assert!(set.contains(100));
+/// assert_eq!(set.len(), 2);
+///
+/// let other: BitmapSet = [3, 5].into_iter().collect();
+/// assert_eq!(set.union(&other).iter().collect::<Vec<_>>(), vec![3, 5, 100]);
+/// assert_eq!(set.intersection(&other).iter().collect::<Vec<_>>(), vec![3]);
+/// ```
+///
+/// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+/// [`StaticBitmap`]: crate::static_bitmap::StaticBitmap
+#[derive(Default, Clone)]
+pub struct BitmapSet {
+    bitmap: DefaultVarBitmap,
+}
+
+impl BitmapSet {
+    /// Creates empty set. It doesn't allocate.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `idx` to the set. Returns `true` if the set didn't contain it.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if the container cannot grow to hold `idx`: if `idx` is too large to compute
+    /// the required length of the container or if memory allocation fails.
+    pub fn insert(&mut self, idx: usize) -> bool {
+        self.bitmap.set_if_clear(idx)
+    }
+
+    /// Removes `idx` from the set. Returns `true` if the set contained it.
+    pub fn remove(&mut self, idx: usize) -> bool {
+        self.bitmap.clear_if_set(idx)
+    }
+
+    /// Returns `true` if the set contains `idx`.
+    pub fn contains(&self, idx: usize) -> bool {
+        self.bitmap.get(idx)
+    }
+
+    /// Returns number of indexes in the set.
+    pub fn len(&self) -> usize {
+        self.bitmap.count_ones()
+    }
+
+    /// Returns `true` if the set contains no indexes.
+    pub fn is_empty(&self) -> bool {
+        self.bitmap.is_empty()
+    }
+
+    /// Returns iterator over indexes in ascending order.
+    pub fn iter(&self) -> Ones<'_, Vec<u8>, LSB> {
+        self.bitmap.ones()
+    }
+
+    /// Returns set of indexes contained in `self` or in `other`.
+    pub fn union(&self, other: &BitmapSet) -> BitmapSet {
+        Self::from(self.bitmap.union_var(&other.bitmap))
+    }
+
+    /// Returns set of indexes contained both in `self` and in `other`.
+    pub fn intersection(&self, other: &BitmapSet) -> BitmapSet {
+        Self::from(self.bitmap.intersection_var(&other.bitmap))
+    }
+
+    /// Returns reference to underlying bitmap.
+    pub fn as_bitmap(&self) -> &DefaultVarBitmap {
+        &self.bitmap
+    }
+
+    /// Converts set into underlying bitmap.
+    pub fn into_bitmap(self) -> DefaultVarBitmap {
+        self.bitmap
+    }
+}
+
+impl From<DefaultVarBitmap> for BitmapSet {
+    fn from(bitmap: DefaultVarBitmap) -> Self {
+        Self { bitmap }
+    }
+}
+
+impl FromIterator<usize> for BitmapSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for BitmapSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for idx in iter {
+            self.insert(idx);
+        }
+    }
+}
+
+/// Sets are equal if they contain the same indexes, regardless of container length.
+impl PartialEq for BitmapSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for BitmapSet {}
+
+impl Debug for BitmapSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a BitmapSet {
+    type Item = usize;
+    type IntoIter = Ones<'a, Vec<u8>, LSB>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_ops() {
+        let mut set = BitmapSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(0));
+        assert!(set.insert(17));
+        assert!(set.insert(9));
+        assert!(!set.insert(17));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(9));
+        assert!(!set.contains(10));
+        assert!(!set.contains(usize::MAX));
+
+        assert!(set.remove(17));
+        assert!(!set.remove(17));
+        assert!(!set.remove(1000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 9]);
+        assert_eq!(set, [9, 0].into_iter().collect());
+        assert_eq!(format!("{:?}", set), "{0, 9}");
+
+        let other: BitmapSet = [9, 40].into_iter().collect();
+        assert_eq!(set.union(&other), [0, 9, 40].into_iter().collect());
+        assert_eq!(set.intersection(&other), [9].into_iter().collect());
+        assert_eq!(other.intersection(&set), [9].into_iter().collect());
+        assert!(set.intersection(&BitmapSet::new()).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_too_large() {
+        let mut set = BitmapSet::new();
+        set.insert(usize::MAX);
+    }
+}
@@ -0,0 +1,196 @@
+use std::marker::PhantomData;
+
+use crate::{
+    container::{ContainerRead, ContainerWrite},
+    BitAccess,
+};
+
+/// A container over unpacked `Vec<bool>`.
+///
+/// Every 8 bools are presented as a single `u8` slot (according to `B`), so the bools can be used
+/// with bitmaps without packing them first. Bools are packed once when the container is created
+/// and unpacked by [`to_bools`] or [`into_inner`].
+///
+/// The container has exactly `len()` bits, so its last slot may be partial: bits after the end
+/// of the vector are always `0` and cannot be set. Padding bits written through `get_mut_slot`
+/// are ignored.
+///
+/// Usage example:
+/// ```
+/// use bitmac::{bool_vec::BoolVecContainer, StaticBitmap, LSB};
+///
+/// let mut bitmap = StaticBitmap::new(BoolVecContainer::<LSB>::new(vec![false, true, false]));
+/// assert!(bitmap.get(1));
+/// bitmap.set(2, true);
+/// assert!(bitmap.try_set(3, true).is_err());
+/// assert_eq!(bitmap.into_inner().into_inner(), vec![false, true, true]);
+/// ```
+///
+/// [`to_bools`]: crate::bool_vec::BoolVecContainer::to_bools
+/// [`into_inner`]: crate::bool_vec::BoolVecContainer::into_inner
+#[derive(Debug, Default, Clone)]
+pub struct BoolVecContainer<B> {
+    slots: Vec<u8>,
+    len: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<B> BoolVecContainer<B>
+where
+    B: BitAccess,
+{
+    /// Creates new container over bools.
+    pub fn new(bools: Vec<bool>) -> Self {
+        let mut slots = vec![0u8; (bools.len() + 7) / 8];
+        for (idx, _) in bools.iter().enumerate().filter(|(_, &v)| v) {
+            slots[idx / 8] = B::set(slots[idx / 8], idx % 8, true);
+        }
+        Self {
+            slots,
+            len: bools.len(),
+            phantom: Default::default(),
+        }
+    }
+
+    /// Returns number of bools.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if there are no bools.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns stored bools.
+    pub fn to_bools(&self) -> Vec<bool> {
+        (0..self.len).map(|idx| self.get_bit(idx)).collect()
+    }
+
+    /// Converts container into inner bools.
+    pub fn into_inner(self) -> Vec<bool> {
+        self.to_bools()
+    }
+
+    /// Returns mask of bits of slot `idx` that are within `len()`.
+    fn slot_mask(&self, idx: usize) -> u8 {
+        let bits = usize::min(self.len - idx * 8, 8);
+        (0..bits).fold(0, |mask, bit_idx| B::set(mask, bit_idx, true))
+    }
+}
+
+impl<B> From<Vec<bool>> for BoolVecContainer<B>
+where
+    B: BitAccess,
+{
+    fn from(f: Vec<bool>) -> Self {
+        Self::new(f)
+    }
+}
+
+impl<B> ContainerRead<B> for BoolVecContainer<B>
+where
+    B: BitAccess,
+{
+    type Slot = u8;
+
+    fn get_slot(&self, idx: usize) -> Self::Slot {
+        // Padding bits may be written through `get_mut_slot`, hide them
+        self.slots[idx] & self.slot_mask(idx)
+    }
+
+    fn slots_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    fn get_bit(&self, idx: usize) -> bool {
+        if idx >= self.len {
+            return false;
+        }
+        B::get(self.slots[idx / 8], idx % 8)
+    }
+
+    fn bits_count(&self) -> usize {
+        self.len
+    }
+}
+
+impl<B> ContainerWrite<B> for BoolVecContainer<B>
+where
+    B: BitAccess,
+{
+    fn get_mut_slot(&mut self, idx: usize) -> &mut Self::Slot {
+        &mut self.slots[idx]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BitOp, BitmapOps, StaticBitmap, LSB, MSB};
+
+    #[test]
+    fn read_bits() {
+        let bools = vec![
+            true, false, false, true, false, true, true, false, true, true,
+        ];
+        let v = StaticBitmap::new(BoolVecContainer::<LSB>::new(bools.clone()));
+        for (i, &b) in bools.iter().enumerate() {
+            assert_eq!(v.get(i), b);
+        }
+        assert!(!v.get(10));
+        assert!(!v.get(100));
+        assert_eq!(v.get(3), bools[3]);
+        assert_eq!(v.iter().collect::<Vec<_>>(), vec![0b0110_1001, 0b0000_0011]);
+        assert_eq!(v.count_ones(), 6);
+
+        let v = StaticBitmap::new(BoolVecContainer::<MSB>::new(bools.clone()));
+        assert_eq!(v.get(3), bools[3]);
+        assert_eq!(v.iter().collect::<Vec<_>>(), vec![0b1001_0110, 0b1100_0000]);
+    }
+
+    #[test]
+    fn write_slots() {
+        let mut dst = BoolVecContainer::<LSB>::new(vec![true; 12]);
+        let lhs = StaticBitmap::<_, LSB>::new([0b0000_0101u8, 0b1111_1111]);
+        lhs.combine_in(&[0b0000_0111u8, 0b0000_0010], BitOp::And, &mut dst);
+        assert!(dst.get_bit(0));
+        assert!(!dst.get_bit(1));
+        assert!(dst.get_bit(9));
+        assert_eq!(
+            dst.into_inner(),
+            vec![true, false, true, false, false, false, false, false, false, true, false, false]
+        );
+
+        let mut v = StaticBitmap::new(BoolVecContainer::<LSB>::new(vec![false; 9]));
+        *v.get_mut_slot(0) = 0b1000_0001;
+        v.set(8, true);
+        v.set(7, false);
+        assert_eq!(
+            v.into_inner().into_inner(),
+            vec![true, false, false, false, false, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn padding_bits() {
+        let v = StaticBitmap::new(BoolVecContainer::<LSB>::from(vec![true; 11]));
+        assert_eq!(v.validate(), Ok(()));
+
+        let mut v = StaticBitmap::new(BoolVecContainer::<MSB>::from(vec![false; 11]));
+        *v.get_mut_slot(1) = 0xFF;
+        assert_eq!(v.get_slot(1), 0b1110_0000);
+        assert!(!v.get(11));
+        assert_eq!(v.count_ones(), 3);
+        assert_eq!(v.validate(), Ok(()));
+        assert_eq!(v.as_ref().to_bools()[8..], [true, true, true]);
+    }
+
+    #[test]
+    fn out_of_bounds_without_slots_hint() {
+        let mut v = BoolVecContainer::<LSB>::from(vec![false; 12]);
+        let err = ContainerWrite::<LSB>::try_set_bit(&mut v, 12, true).unwrap_err();
+        assert_eq!(err.required_slots(), None);
+        assert_eq!(err.to_string(), "index '12' out of bounds 0..12");
+    }
+}
@@ -0,0 +1,175 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+
+use crate::{container::ContainerRead, iter::Ones, BitAccess, StaticBitmap, VarBitmap};
+
+/// Bitmap with known bit access. It is implemented for [`StaticBitmap`] and [`VarBitmap`].
+///
+/// [`StaticBitmap`]: crate::static_bitmap::StaticBitmap
+/// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+pub trait Bitmap {
+    type Access: BitAccess;
+}
+
+impl<D, B> Bitmap for StaticBitmap<D, B>
+where
+    B: BitAccess,
+{
+    type Access = B;
+}
+
+impl<D, B, S> Bitmap for VarBitmap<D, B, S>
+where
+    B: BitAccess,
+{
+    type Access = B;
+}
+
+/// Wrapper that compares and hashes bitmaps by their set bits only.
+///
+/// Container type, slot type and trailing zeros (e.g. [`bit_len`] vs capacity) don't matter,
+/// so wrapped bitmaps with the same ones are equal. It is useful as a key type for maps and sets.
+/// Bitmaps are ordered lexicographically by ascending indexes of their ones.
+///
+/// Usage example:
+/// ```
+/// use bitmac::{Canonical, StaticBitmap, VarBitmap, LSB, MinimumRequiredStrategy};
+///
+/// let a = Canonical(StaticBitmap::<_, LSB>::new([0b0000_1001u8]));
+/// let b = Canonical(VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b1001u16, 0, 0]));
+/// assert!(a == b);
+/// assert_eq!(a.count_ones(), 2);
+/// ```
+///
+/// [`bit_len`]: crate::static_bitmap::StaticBitmap::bit_len
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Canonical<T>(pub T);
+
+impl<T> Canonical<T> {
+    /// Converts wrapper into inner bitmap.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Canonical<T>
+where
+    T: Bitmap + ContainerRead<T::Access>,
+{
+    fn ones(&self) -> Ones<'_, T, T::Access> {
+        Ones::new(&self.0)
+    }
+}
+
+impl<T> Deref for Canonical<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Canonical<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, U> PartialEq<Canonical<U>> for Canonical<T>
+where
+    T: Bitmap + ContainerRead<T::Access>,
+    U: Bitmap<Access = T::Access> + ContainerRead<T::Access>,
+{
+    fn eq(&self, other: &Canonical<U>) -> bool {
+        self.ones().eq(other.ones())
+    }
+}
+
+impl<T> Eq for Canonical<T> where T: Bitmap + ContainerRead<T::Access> {}
+
+impl<T, U> PartialOrd<Canonical<U>> for Canonical<T>
+where
+    T: Bitmap + ContainerRead<T::Access>,
+    U: Bitmap<Access = T::Access> + ContainerRead<T::Access>,
+{
+    fn partial_cmp(&self, other: &Canonical<U>) -> Option<Ordering> {
+        Some(self.ones().cmp(other.ones()))
+    }
+}
+
+impl<T> Ord for Canonical<T>
+where
+    T: Bitmap + ContainerRead<T::Access>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ones().cmp(other.ones())
+    }
+}
+
+impl<T> Hash for Canonical<T>
+where
+    T: Bitmap + ContainerRead<T::Access>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut count = 0usize;
+        for idx in self.ones() {
+            idx.hash(state);
+            count += 1;
+        }
+        count.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{hash_map::DefaultHasher, BTreeSet, HashSet};
+
+    use super::*;
+    use crate::{MinimumRequiredStrategy, LSB, MSB};
+
+    fn hash<T: Hash>(v: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        v.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn different_containers() {
+        let a = Canonical(StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0001]));
+        let b = Canonical(
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![
+                0b0000_0001_0000_1001u16,
+                0,
+                0,
+            ]),
+        );
+        let c = Canonical(StaticBitmap::<_, LSB>::new(0b0000_0001_0000_1001u64));
+        assert!(a == b);
+        assert!(b == a);
+        assert!(a == c);
+        assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+
+        let d = Canonical(StaticBitmap::<_, LSB>::new(0b0000_0001_0000_1000u64));
+        assert!(c != d);
+        assert!(c < d);
+
+        let e = Canonical(StaticBitmap::<_, MSB>::new([0b1001_0000u8, 0b1000_0000]));
+        let f = Canonical(StaticBitmap::<_, MSB>::new(0b1001_0000_1000_0000u16));
+        assert!(e == f);
+    }
+
+    #[test]
+    fn as_key() {
+        let a = Canonical(StaticBitmap::<_, LSB>::new(vec![0b0000_1001u8, 0]));
+        let b = Canonical(StaticBitmap::<_, LSB>::new(vec![0b0000_1001u8]));
+        assert_eq!(hash(&a), hash(&b));
+
+        let set = HashSet::<_>::from_iter([a.clone(), b.clone()]);
+        assert_eq!(set.len(), 1);
+        let set = BTreeSet::from_iter([a, b]);
+        assert_eq!(set.len(), 1);
+    }
+}
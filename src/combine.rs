@@ -0,0 +1,297 @@
+use std::marker::PhantomData;
+
+use crate::{
+    container::{ContainerRead, ContainerWrite},
+    number::Number,
+    resizable::Resizable,
+    update::xor,
+    with_slots::TryWithSlots,
+    BitAccess, CombineError, SmallContainerSizeError,
+};
+
+/// Binary operation applied slot by slot to combine two bitmaps.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BitOp {
+    /// Intersection, `lhs & rhs`.
+    And,
+    /// Union, `lhs | rhs`.
+    Or,
+    /// Symmetric difference, `lhs ^ rhs`.
+    Xor,
+    /// Difference, `lhs & !rhs`.
+    AndNot,
+}
+
+impl BitOp {
+    /// Applies the operation to a pair of slots.
+    #[inline]
+    pub fn apply<N>(self, lhs: N, rhs: N) -> N
+    where
+        N: Number,
+    {
+        match self {
+            BitOp::And => lhs & rhs,
+            BitOp::Or => lhs | rhs,
+            BitOp::Xor => xor(lhs, rhs),
+            BitOp::AndNot => lhs & !rhs,
+        }
+    }
+
+    /// Returns number of slots that can hold a non-zero result for operands
+    /// of `lhs_len` and `rhs_len` slots.
+    #[inline]
+    pub fn result_len(self, lhs_len: usize, rhs_len: usize) -> usize {
+        match self {
+            BitOp::And => usize::min(lhs_len, rhs_len),
+            BitOp::Or | BitOp::Xor => usize::max(lhs_len, rhs_len),
+            BitOp::AndNot => lhs_len,
+        }
+    }
+}
+
+/// Binary operation over bitmaps, the operation itself is chosen by [`BitOp`].
+///
+/// Replaces deprecated [`Intersection`] and [`Union`], a single trait covers every operation,
+/// missing slots of shorter operand are treated as zeros.
+///
+/// [`BitOp`]: crate::combine::BitOp
+/// [`Intersection`]: crate::intersection::Intersection
+/// [`Union`]: crate::union::Union
+pub trait BitmapOps<Rhs, N, B>
+where
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    /// Calculates `op(self, rhs)` in-place. Result will be stored in `dst`.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `dst` cannot fit the entire result.
+    /// See non-panic function [`try_combine_in`].
+    ///
+    /// [`try_combine_in`]: crate::combine::BitmapOps::try_combine_in
+    fn combine_in<Dst>(&self, rhs: &Rhs, op: BitOp, dst: &mut Dst)
+    where
+        Dst: ContainerWrite<B, Slot = N>;
+
+    /// Calculates `op(self, rhs)` in-place. Result will be stored in `dst`.
+    ///
+    /// Returns `Err(_)` if `dst` cannot fit the entire result.
+    fn try_combine_in<Dst>(&self, rhs: &Rhs, op: BitOp, dst: &mut Dst) -> Result<(), CombineError>
+    where
+        Dst: ContainerWrite<B, Slot = N>;
+
+    /// Calculates `op(self, rhs)`. Result container will be created with [`try_with_slots_exact`] function.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `Dst` cannot fit the entire result.
+    /// See non-panic function [`try_combine`].
+    ///
+    /// [`try_combine`]: crate::combine::BitmapOps::try_combine
+    /// [`try_with_slots_exact`]: crate::with_slots::TryWithSlots::try_with_slots_exact
+    fn combine<Dst>(&self, rhs: &Rhs, op: BitOp) -> Dst
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots;
+
+    /// Calculates `op(self, rhs)`. Result container will be created with [`try_with_slots_exact`] function.
+    ///
+    /// Returns `Err(_)` if `Dst` cannot fit the entire result.
+    ///
+    /// [`try_with_slots_exact`]: crate::with_slots::TryWithSlots::try_with_slots_exact
+    fn try_combine<Dst>(&self, rhs: &Rhs, op: BitOp) -> Result<Dst, CombineError>
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots;
+
+    /// Calculates ones count of `op(self, rhs)`. It doesn't allocate for storing the result.
+    fn combine_len(&self, rhs: &Rhs, op: BitOp) -> usize;
+}
+
+/// Returns slot `i` of `data`, or zero if it is missing.
+#[inline]
+fn slot_or_zero<D, N, B>(data: &D, i: usize) -> N
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    if i < data.slots_count() {
+        data.get_slot(i)
+    } else {
+        N::ZERO
+    }
+}
+
+pub(crate) fn try_combine_in_impl<Lhs, Rhs, Dst, N, B>(
+    lhs: &Lhs,
+    rhs: &Rhs,
+    op: BitOp,
+    dst: &mut Dst,
+) -> Result<(), CombineError>
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    Dst: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let required_dst_len = op.result_len(lhs.slots_count(), rhs.slots_count());
+    if dst.slots_count() < required_dst_len {
+        return Err(SmallContainerSizeError::new(format!(
+            "size of container should be >= {} slots of {} bits ({} bits), but handled {} slots ({} bits)",
+            required_dst_len,
+            N::BITS_COUNT,
+            required_dst_len * N::BITS_COUNT,
+            dst.slots_count(),
+            dst.slots_count() * N::BITS_COUNT,
+        ))
+        .into());
+    }
+
+    for i in 0..required_dst_len {
+        *dst.get_mut_slot(i) = op.apply(slot_or_zero(lhs, i), slot_or_zero(rhs, i));
+    }
+    Ok(())
+}
+
+pub(crate) fn try_combine_impl<Lhs, Rhs, Dst, N, B>(
+    lhs: &Lhs,
+    rhs: &Rhs,
+    op: BitOp,
+) -> Result<Dst, CombineError>
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    N: Number,
+    B: BitAccess,
+{
+    let slots_count = op.result_len(lhs.slots_count(), rhs.slots_count());
+    let mut dst = Dst::try_with_slots_exact(slots_count)?;
+
+    try_combine_in_impl(lhs, rhs, op, &mut dst)?;
+    Ok(dst)
+}
+
+pub(crate) fn combine_len_impl<Lhs, Rhs, N, B>(lhs: &Lhs, rhs: &Rhs, op: BitOp) -> usize
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    (0..op.result_len(lhs.slots_count(), rhs.slots_count()))
+        .map(|i| {
+            op.apply(slot_or_zero(lhs, i), slot_or_zero(rhs, i))
+                .count_ones() as usize
+        })
+        .sum()
+}
+
+pub(crate) fn combine_into_impl<Lhs, Rhs, Dst, N, B>(lhs: &Lhs, rhs: &Rhs, op: BitOp, dst: &mut Dst)
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let slots_count = op.result_len(lhs.slots_count(), rhs.slots_count());
+    dst.resize(slots_count, N::ZERO);
+
+    // Destination has exactly required size, so it cannot fail
+    try_combine_in_impl(lhs, rhs, op, dst).unwrap();
+}
+
+/// An iterator over indexes of set bits of `op(lhs, rhs)` in ascending order.
+///
+/// Slots are combined lazily, one at a time, missing slots of shorter container are zeros.
+/// Slots without ones are skipped entirely.
+pub struct CombinedOnes<'a, L, R, N, B> {
+    lhs: &'a L,
+    rhs: &'a R,
+    op: BitOp,
+    slots_count: usize,
+    next_slot_idx: usize,
+    slot: N,
+    bit_idx: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, L, R, N, B> CombinedOnes<'a, L, R, N, B>
+where
+    L: ContainerRead<B, Slot = N>,
+    R: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    pub(crate) fn new(lhs: &'a L, rhs: &'a R, op: BitOp) -> Self {
+        Self {
+            lhs,
+            rhs,
+            op,
+            slots_count: op.result_len(lhs.slots_count(), rhs.slots_count()),
+            next_slot_idx: 0,
+            slot: N::ZERO,
+            bit_idx: N::BITS_COUNT,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<L, R, N, B> Iterator for CombinedOnes<'_, L, R, N, B>
+where
+    L: ContainerRead<B, Slot = N>,
+    R: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.slot != N::ZERO {
+                while self.bit_idx < N::BITS_COUNT {
+                    let bit_idx = self.bit_idx;
+                    self.bit_idx += 1;
+                    if B::get(self.slot, bit_idx) {
+                        return Some((self.next_slot_idx - 1) * N::BITS_COUNT + bit_idx);
+                    }
+                }
+            }
+
+            if self.next_slot_idx >= self.slots_count {
+                return None;
+            }
+            let i = self.next_slot_idx;
+            self.slot = self
+                .op
+                .apply(slot_or_zero(self.lhs, i), slot_or_zero(self.rhs, i));
+            self.bit_idx = 0;
+            self.next_slot_idx += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LSB;
+
+    #[test]
+    fn combine_into() {
+        let lhs: &[u8] = &[0b1100_1100, 0b0000_1111];
+        let rhs: &[u8] = &[0b1010_1010];
+        let combine = |op| {
+            let mut dst = Vec::new();
+            combine_into_impl::<_, _, _, _, LSB>(&lhs, &rhs, op, &mut dst);
+            dst
+        };
+
+        assert_eq!(combine(BitOp::And), vec![0b1000_1000]);
+        assert_eq!(combine(BitOp::Or), vec![0b1110_1110, 0b0000_1111]);
+        assert_eq!(combine(BitOp::Xor), vec![0b0110_0110, 0b0000_1111]);
+        assert_eq!(combine(BitOp::AndNot), vec![0b0100_0100, 0b0000_1111]);
+    }
+}
@@ -0,0 +1,74 @@
+//! Run-length encoding of bitmap bytes.
+//!
+//! Compressed form is a sequence of runs, every run takes two bytes: the number of repeats
+//! (`1..=255`) and the repeated byte. Longer runs are split. Bytes are in [`to_byte_vec`] layout,
+//! so the form doesn't depend on slot width. Sparse bitmaps are mostly zero bytes,
+//! so they shrink to a few runs.
+//!
+//! [`to_byte_vec`]: crate::static_bitmap::StaticBitmap::to_byte_vec
+
+use crate::DecompressError;
+
+pub(crate) fn compress_impl(bytes: &[u8]) -> Vec<u8> {
+    let mut res = Vec::new();
+    let mut iter = bytes.iter().copied().peekable();
+    while let Some(byte) = iter.next() {
+        let mut count = 1u8;
+        while count < u8::MAX && iter.peek() == Some(&byte) {
+            iter.next();
+            count += 1;
+        }
+        res.push(count);
+        res.push(byte);
+    }
+    res
+}
+
+pub(crate) fn decompress_impl(compressed: &[u8]) -> Result<Vec<u8>, DecompressError> {
+    if compressed.len() % 2 != 0 {
+        return Err(DecompressError::new(format!(
+            "length {} is odd, runs take 2 bytes",
+            compressed.len()
+        )));
+    }
+
+    let mut res = Vec::new();
+    for (run_idx, run) in compressed.chunks(2).enumerate() {
+        let (count, byte) = (run[0], run[1]);
+        if count == 0 {
+            return Err(DecompressError::new(format!(
+                "run {} has zero length",
+                run_idx
+            )));
+        }
+        res.extend(std::iter::repeat(byte).take(count as usize));
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut bytes = vec![0u8; 600];
+        bytes[3] = 0b0000_1000;
+        bytes[599] = 0xFF;
+        let compressed = compress_impl(&bytes);
+        assert_eq!(
+            compressed,
+            vec![3, 0, 1, 0b0000_1000, 255, 0, 255, 0, 85, 0, 1, 0xFF]
+        );
+        assert_eq!(decompress_impl(&compressed).unwrap(), bytes);
+
+        assert!(compress_impl(&[]).is_empty());
+        assert!(decompress_impl(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(decompress_impl(&[1, 0, 2]).is_err());
+        assert!(decompress_impl(&[1, 0, 0, 7]).is_err());
+    }
+}
@@ -17,18 +17,40 @@ where
     /// Gets number of stored slots.
     fn slots_count(&self) -> usize;
 
+    /// Gets value of stored slot, returns `None` if `idx >= slots_count()`.
+    ///
+    /// It only checks `idx` against `slots_count()` before calling [`get_slot`], so it may still
+    /// panic if the container reports more slots than it actually stores.
+    ///
+    /// [`get_slot`]: crate::container::ContainerRead::get_slot
+    #[inline]
+    fn get_slot_checked(&self, idx: usize) -> Option<Self::Slot> {
+        if idx < self.slots_count() {
+            Some(self.get_slot(idx))
+        } else {
+            None
+        }
+    }
+
     /// Gets bit state.
     ///
     /// You usually don't need to override this method yourself, but you can do it
-    /// for performance reasons. Method is hidden because you don't need to call it
-    /// directly. Instead, you should use one of the bitmap implementations.
-    #[doc(hidden)]
+    /// for performance reasons. Bitmaps always read single bits through this method.
+    ///
+    /// ## Contract
+    ///
+    /// The result must be consistent with [`get_slot`]: for `idx < bits_count()` it returns
+    /// the state of bit `idx % Slot::BITS_COUNT` (according to `B`) of slot `idx / Slot::BITS_COUNT`.
+    /// Out of bounds bits must be reported as `false`.
+    ///
+    /// [`get_slot`]: crate::container::ContainerRead::get_slot
     fn get_bit(&self, idx: usize) -> bool {
         // If idx out of bounds
         if idx >= self.bits_count() {
             return false;
         }
 
+        let () = <Self::Slot as Number>::ASSERT_NON_ZERO_WIDTH;
         let slot_idx = idx / <Self::Slot as Number>::BITS_COUNT;
         let bit_idx = idx - slot_idx * <Self::Slot as Number>::BITS_COUNT;
 
@@ -36,12 +58,109 @@ where
     }
 
     /// Gets max number of bits.
+    ///
+    /// The count saturates at `usize::MAX` instead of overflowing, so the max supported bit index
+    /// is `usize::MAX - 1`. Bits beyond it are treated as out of bounds.
+    ///
+    /// ## Contract
+    ///
+    /// It is `slots_count() * Slot::BITS_COUNT`, unless the last slot is partial: containers
+    /// like [`BoolVecContainer`] may report less bits, but only bits of the last slot can be
+    /// excluded. Excluded (padding) bits must read as `0` both from [`get_slot`] and [`get_bit`].
+    ///
+    /// [`BoolVecContainer`]: crate::bool_vec::BoolVecContainer
+    /// [`get_slot`]: crate::container::ContainerRead::get_slot
+    /// [`get_bit`]: crate::container::ContainerRead::get_bit
     #[inline]
     fn bits_count(&self) -> usize {
-        self.slots_count() * <Self::Slot as Number>::BITS_COUNT
+        self.slots_count()
+            .saturating_mul(<Self::Slot as Number>::BITS_COUNT)
+    }
+
+    /// Gets all slots as a contiguous slice if container stores them so.
+    ///
+    /// Bitmaps use it for faster processing of whole container. The default implementation
+    /// returns `None`, containers that cannot provide the slice should keep it.
+    #[inline]
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        None
     }
 }
 
+/// Checks that `data` follows the [`ContainerRead`] contract: `bits_count()` matches `slots_count()`
+/// (the last slot may be partial), `as_slot_slice` (if any) holds `slots_count()` slots equal to
+/// `get_slot` and `get_bit` agrees with `get_slot`. Returns description of the first violation.
+///
+/// Slots are read only after the slice length check, so a container that reports more slots than
+/// it stores is reported instead of panicking if it provides `as_slot_slice`.
+pub(crate) fn validate_impl<D, N, B>(data: &D) -> Result<(), String>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let slots_count = data.slots_count();
+    let expected_bits = slots_count.saturating_mul(N::BITS_COUNT);
+    let min_bits = slots_count.saturating_sub(1).saturating_mul(N::BITS_COUNT);
+    let bits_count = data.bits_count();
+    if bits_count > expected_bits || (slots_count > 0 && bits_count <= min_bits) {
+        return Err(format!(
+            "bits_count() is {}, but {} slots of {} bits hold {} bits",
+            data.bits_count(),
+            slots_count,
+            N::BITS_COUNT,
+            expected_bits
+        ));
+    }
+
+    let slice = data.as_slot_slice();
+    if let Some(slice) = slice {
+        if slice.len() != slots_count {
+            return Err(format!(
+                "as_slot_slice() has {} slots, but slots_count() is {}",
+                slice.len(),
+                slots_count
+            ));
+        }
+    }
+
+    for slot_idx in 0..slots_count {
+        let slot = data.get_slot(slot_idx);
+        if let Some(slice) = slice {
+            if slice[slot_idx] != slot {
+                return Err(format!(
+                    "as_slot_slice()[{}] differs from get_slot({})",
+                    slot_idx, slot_idx
+                ));
+            }
+        }
+        for bit_idx in 0..N::BITS_COUNT {
+            // Bits from `usize::MAX` on are out of bounds, see `bits_count`
+            let idx = match slot_idx
+                .checked_mul(N::BITS_COUNT)
+                .and_then(|idx| idx.checked_add(bit_idx))
+            {
+                Some(idx) if idx != usize::MAX => idx,
+                _ => return Ok(()),
+            };
+            let bit = data.get_bit(idx);
+            if bit != B::get(slot, bit_idx) {
+                return Err(format!(
+                    "get_bit({}) is {}, but bit {} of get_slot({}) is {}",
+                    idx, bit, bit_idx, slot_idx, !bit
+                ));
+            }
+        }
+    }
+
+    let idx = expected_bits;
+    if idx != usize::MAX && data.get_bit(idx) {
+        return Err(format!("out of bounds get_bit({}) is true", idx));
+    }
+
+    Ok(())
+}
+
 pub trait ContainerWrite<B>: ContainerRead<B>
 where
     B: BitAccess,
@@ -49,15 +168,41 @@ where
     /// Gets mutable reference to stored slot.
     fn get_mut_slot(&mut self, idx: usize) -> &mut Self::Slot;
 
+    /// Gets mutable reference to stored slot, returns `None` if `idx >= slots_count()`.
+    ///
+    /// Like [`get_slot_checked`], it only checks `idx` against `slots_count()` before calling
+    /// [`get_mut_slot`].
+    ///
+    /// [`get_mut_slot`]: crate::container::ContainerWrite::get_mut_slot
+    /// [`get_slot_checked`]: crate::container::ContainerRead::get_slot_checked
+    #[inline]
+    fn get_mut_slot_checked(&mut self, idx: usize) -> Option<&mut Self::Slot> {
+        if idx < self.slots_count() {
+            Some(self.get_mut_slot(idx))
+        } else {
+            None
+        }
+    }
+
     /// Sets bit state with bounds check.
     ///
     /// You usually don't need to override this method yourself, but you can do it
-    /// for performance reasons. Method is hidden because you don't need to call it
-    /// directly. Instead, you should use one of the bitmap implementations.
-    #[doc(hidden)]
+    /// for performance reasons. [`StaticBitmap`] sets single bits through this method.
+    ///
+    /// ## Contract
+    ///
+    /// Returns `Err(_)` and leaves the container untouched if `idx >= bits_count()`,
+    /// otherwise behaves like [`set_bit_unchecked`].
+    ///
+    /// [`StaticBitmap`]: crate::static_bitmap::StaticBitmap
+    /// [`set_bit_unchecked`]: crate::container::ContainerWrite::set_bit_unchecked
     fn try_set_bit(&mut self, idx: usize, val: bool) -> Result<(), OutOfBoundsError> {
         if idx >= self.bits_count() {
-            return Err(OutOfBoundsError::new(idx, 0..self.bits_count()));
+            return Err(OutOfBoundsError::new(
+                idx,
+                0..self.bits_count(),
+                <Self::Slot as Number>::BITS_COUNT,
+            ));
         }
 
         self.set_bit_unchecked(idx, val);
@@ -67,10 +212,19 @@ where
     /// Sets bit state without bounds check.
     ///
     /// You usually don't need to override this method yourself, but you can do it
-    /// for performance reasons. Method is hidden because you don't need to call it
-    /// directly. Instead, you should use one of the bitmap implementations.
-    #[doc(hidden)]
+    /// for performance reasons (e.g. if the container can change a single bit cheaper than
+    /// read-modify-write of the whole slot). [`VarBitmap`] sets single bits through this method
+    /// after it has made sure the container is large enough.
+    ///
+    /// ## Contract
+    ///
+    /// Callers guarantee that `idx < bits_count()`. After the call [`get_bit`] must return `val`
+    /// for `idx` and all other bits must stay unchanged.
+    ///
+    /// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+    /// [`get_bit`]: crate::container::ContainerRead::get_bit
     fn set_bit_unchecked(&mut self, idx: usize, val: bool) {
+        let () = <Self::Slot as Number>::ASSERT_NON_ZERO_WIDTH;
         let slot_idx = idx / <Self::Slot as Number>::BITS_COUNT;
         let bit_idx = idx - slot_idx * <Self::Slot as Number>::BITS_COUNT;
 
@@ -95,6 +249,11 @@ where
     fn slots_count(&self) -> usize {
         self.len()
     }
+
+    #[inline]
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(self)
+    }
 }
 
 impl<N, B> ContainerRead<B> for &'_ mut [N]
@@ -113,6 +272,11 @@ where
     fn slots_count(&self) -> usize {
         self.len()
     }
+
+    #[inline]
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(self)
+    }
 }
 
 impl<N, B> ContainerWrite<B> for &'_ mut [N]
@@ -142,6 +306,11 @@ where
     fn slots_count(&self) -> usize {
         self.len()
     }
+
+    #[inline]
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(self)
+    }
 }
 
 impl<N, B> ContainerWrite<B> for Box<[N]>
@@ -171,6 +340,11 @@ where
     fn slots_count(&self) -> usize {
         self.len()
     }
+
+    #[inline]
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(self)
+    }
 }
 
 impl<N, const LEN: usize, B> ContainerWrite<B> for [N; LEN]
@@ -198,6 +372,10 @@ where
     fn slots_count(&self) -> usize {
         self.len()
     }
+
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(self)
+    }
 }
 
 impl<N, B> ContainerWrite<B> for Vec<N>
@@ -226,6 +404,10 @@ where
     fn slots_count(&self) -> usize {
         self.len()
     }
+
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(self)
+    }
 }
 
 #[cfg(feature = "smallvec")]
@@ -254,6 +436,10 @@ where
     fn slots_count(&self) -> usize {
         self.len()
     }
+
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(self)
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -270,6 +456,10 @@ where
     fn slots_count(&self) -> usize {
         self.len()
     }
+
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(self)
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -316,6 +506,11 @@ macro_rules! container_impl {
             fn bits_count(&self) -> usize {
                 <Self as Number>::BITS_COUNT
             }
+
+            #[inline]
+            fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+                Some(std::slice::from_ref(self))
+            }
         }
 
         impl<B> ContainerWrite<B> for $ty
@@ -332,7 +527,11 @@ macro_rules! container_impl {
                     <Self as ContainerWrite<B>>::set_bit_unchecked(self, idx, val);
                     Ok(())
                 } else {
-                    Err(OutOfBoundsError::new(idx, 0..<Self as Number>::BITS_COUNT))
+                    Err(OutOfBoundsError::new(
+                        idx,
+                        0..<Self as Number>::BITS_COUNT,
+                        <Self as Number>::BITS_COUNT,
+                    ))
                 }
             }
 
@@ -349,3 +548,258 @@ container_impl!(u16);
 container_impl!(u32);
 container_impl!(u64);
 container_impl!(u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MinimumRequiredStrategy, StaticBitmap, VarBitmap, LSB};
+
+    #[derive(Default)]
+    struct CountingContainer {
+        slots: Vec<u8>,
+        set_calls: usize,
+    }
+
+    impl<B> ContainerRead<B> for CountingContainer
+    where
+        B: BitAccess,
+    {
+        type Slot = u8;
+
+        fn get_slot(&self, idx: usize) -> Self::Slot {
+            self.slots[idx]
+        }
+
+        fn slots_count(&self) -> usize {
+            self.slots.len()
+        }
+    }
+
+    impl<B> ContainerWrite<B> for CountingContainer
+    where
+        B: BitAccess,
+    {
+        fn get_mut_slot(&mut self, idx: usize) -> &mut Self::Slot {
+            &mut self.slots[idx]
+        }
+
+        fn set_bit_unchecked(&mut self, idx: usize, val: bool) {
+            self.set_calls += 1;
+            let slot = &mut self.slots[idx / 8];
+            *slot = B::set(*slot, idx % 8, val);
+        }
+    }
+
+    impl crate::resizable::Resizable for CountingContainer {
+        type Slot = u8;
+
+        fn resize(&mut self, new_len: usize, value: Self::Slot) {
+            self.slots.resize(new_len, value);
+        }
+    }
+
+    #[test]
+    fn overridden_set_bit_unchecked() {
+        let mut v = StaticBitmap::<_, LSB>::new(CountingContainer {
+            slots: vec![0; 2],
+            set_calls: 0,
+        });
+        v.set(0, true);
+        v.set(15, true);
+        assert!(v.try_set(16, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(15));
+        assert_eq!(v.as_ref().set_calls, 2);
+
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(
+            CountingContainer::default(),
+        );
+        v.set(3, true);
+        v.set(20, true);
+        v.set(100, false);
+        assert!(v.get(3));
+        assert!(v.get(20));
+        assert_eq!(v.as_ref().slots.len(), 3);
+        assert_eq!(v.as_ref().set_calls, 2);
+
+        // Bitmaps used as containers dispatch to the inner overrides too
+        let mut v = StaticBitmap::<_, LSB>::new(StaticBitmap::<_, LSB>::new(CountingContainer {
+            slots: vec![0; 1],
+            set_calls: 0,
+        }));
+        v.set(1, true);
+        assert!(v.get(1));
+        assert_eq!(v.into_inner().into_inner().set_calls, 1);
+    }
+
+    #[test]
+    fn conditional_set_skips_writes() {
+        let mut v = StaticBitmap::<_, LSB>::new(CountingContainer {
+            slots: vec![0b0000_0001; 2],
+            set_calls: 0,
+        });
+        assert!(!v.set_if_clear(0));
+        assert!(!v.clear_if_set(1));
+        assert_eq!(v.as_ref().set_calls, 0);
+        assert!(v.set_if_clear(1));
+        assert!(v.clear_if_set(8));
+        assert_eq!(v.as_ref().set_calls, 2);
+        assert!(v.try_set_if_clear(16).is_err());
+        assert!(!v.clear_if_set(16));
+        assert_eq!(v.as_ref().slots, vec![0b0000_0011, 0b0000_0000]);
+
+        let mut v =
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(CountingContainer {
+                slots: vec![0b0000_0001],
+                set_calls: 0,
+            });
+        assert!(!v.set_if_clear(0));
+        assert!(!v.clear_if_set(20));
+        assert_eq!(v.as_ref().set_calls, 0);
+        assert!(v.set_if_clear(20));
+        assert_eq!(v.as_ref().set_calls, 1);
+        assert_eq!(v.as_ref().slots, vec![0b0000_0001, 0, 0b0001_0000]);
+    }
+
+    /// Container that pretends to have a lot of zero slots without storing them.
+    struct HugeContainer {
+        slots_count: usize,
+    }
+
+    impl<B> ContainerRead<B> for HugeContainer
+    where
+        B: BitAccess,
+    {
+        type Slot = u128;
+
+        fn get_slot(&self, _idx: usize) -> Self::Slot {
+            0
+        }
+
+        fn slots_count(&self) -> usize {
+            self.slots_count
+        }
+    }
+
+    #[test]
+    fn bits_count_saturates() {
+        let max_exact = usize::MAX / 128;
+        let c = HugeContainer {
+            slots_count: max_exact,
+        };
+        assert_eq!(ContainerRead::<LSB>::bits_count(&c), max_exact * 128);
+
+        let c = HugeContainer {
+            slots_count: max_exact + 1,
+        };
+        assert_eq!(ContainerRead::<LSB>::bits_count(&c), usize::MAX);
+        assert!(!ContainerRead::<LSB>::get_bit(&c, usize::MAX));
+        assert!(!ContainerRead::<LSB>::get_bit(&c, usize::MAX - 1));
+
+        let c = HugeContainer {
+            slots_count: usize::MAX,
+        };
+        assert_eq!(ContainerRead::<LSB>::bits_count(&c), usize::MAX);
+    }
+
+    #[test]
+    fn count_ones_u8_words() {
+        let mut data = vec![0u8; 1_000_003];
+        for (i, v) in data.iter_mut().enumerate() {
+            *v = (i.wrapping_mul(0x9E37_79B9) >> 7) as u8;
+        }
+        for len in [0, 1, 7, 8, 9, 17, data.len()] {
+            let v = StaticBitmap::<_, LSB>::new(&data[..len]);
+            let expected: usize = data[..len].iter().map(|v| v.count_ones() as usize).sum();
+            assert_eq!(v.count_ones(), expected, "{}", len);
+        }
+
+        #[cfg(feature = "bytes")]
+        {
+            let expected: usize = data.iter().map(|v| v.count_ones() as usize).sum();
+            let v = StaticBitmap::<_, LSB>::new(Bytes::from(data));
+            assert_eq!(v.count_ones(), expected);
+        }
+    }
+
+    #[test]
+    fn validate() {
+        use crate::{MinimumRequiredStrategy, StaticBitmap, VarBitmap, MSB};
+
+        assert!(StaticBitmap::<_, LSB>::new(0b1010_0101u16)
+            .validate()
+            .is_ok());
+        assert!(StaticBitmap::<_, MSB>::new([1u8, 2, 3]).validate().is_ok());
+        assert!(StaticBitmap::<_, LSB>::new(&[1u32, 2][..])
+            .validate()
+            .is_ok());
+        assert!(
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![7u64; 3])
+                .validate()
+                .is_ok()
+        );
+
+        // Reports more slots than it stores
+        struct TooManySlots(Vec<u8>);
+
+        impl ContainerRead<LSB> for TooManySlots {
+            type Slot = u8;
+
+            fn get_slot(&self, idx: usize) -> u8 {
+                self.0[idx]
+            }
+
+            fn slots_count(&self) -> usize {
+                self.0.len() + 1
+            }
+
+            fn as_slot_slice(&self) -> Option<&[u8]> {
+                Some(&self.0)
+            }
+        }
+
+        let err = StaticBitmap::<_, LSB>::new(TooManySlots(vec![1, 2]))
+            .validate()
+            .unwrap_err();
+        assert!(err.contains("slots_count() is 3"), "{}", err);
+
+        // Bit reading disagrees with slots
+        struct InvertedBits(Vec<u8>);
+
+        impl ContainerRead<LSB> for InvertedBits {
+            type Slot = u8;
+
+            fn get_slot(&self, idx: usize) -> u8 {
+                self.0[idx]
+            }
+
+            fn slots_count(&self) -> usize {
+                self.0.len()
+            }
+
+            fn get_bit(&self, idx: usize) -> bool {
+                idx < self.bits_count() && !LSB::get(self.0[idx / 8], idx % 8)
+            }
+        }
+
+        let err = StaticBitmap::<_, LSB>::new(InvertedBits(vec![1]))
+            .validate()
+            .unwrap_err();
+        assert!(err.contains("get_bit(0)"), "{}", err);
+    }
+
+    #[test]
+    fn checked_slot_access() {
+        let mut v = vec![1u8, 2];
+        assert_eq!(ContainerRead::<LSB>::get_slot_checked(&v, 1), Some(2));
+        assert_eq!(ContainerRead::<LSB>::get_slot_checked(&v, 2), None);
+        assert_eq!(ContainerRead::<LSB>::get_slot_checked(&v, usize::MAX), None);
+
+        *ContainerWrite::<LSB>::get_mut_slot_checked(&mut v, 0).unwrap() = 7;
+        assert!(ContainerWrite::<LSB>::get_mut_slot_checked(&mut v, 2).is_none());
+        assert_eq!(v, vec![7, 2]);
+
+        let empty: &[u8] = &[];
+        assert_eq!(ContainerRead::<LSB>::get_slot_checked(&empty, 0), None);
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    fmt::{Debug, Formatter, Write},
+    marker::PhantomData,
+};
+
+use crate::{
+    container::ContainerRead,
+    number::{Endianness, Number},
+    BitAccess,
+};
+
+/// Formats bits of a container in logical order.
+///
+/// Unlike `Debug` of bitmaps, which prints raw bytes of every slot, it prints bits in order of their
+/// indices (according to the `BitAccess`): the first printed character is the state of 0th bit.
+/// Slots are separated by space.
+///
+/// Usage example:
+/// ```
+/// use bitmac::{StaticBitmap, MSB};
+///
+/// let bitmap = StaticBitmap::<_, MSB>::new([0b1000_0000_0000_0001u16, 0b0000_0000_0000_0010]);
+/// assert_eq!(
+///     format!("{:?}", bitmap.debug_logical()),
+///     "1000000000000001 0000000000000010",
+/// );
+/// ```
+pub struct DebugLogical<'a, D, B> {
+    data: &'a D,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, D, B> DebugLogical<'a, D, B> {
+    pub(crate) fn new(data: &'a D) -> Self {
+        Self {
+            data,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B, N> Debug for DebugLogical<'_, D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    N: Number,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for i in 0..self.data.slots_count() {
+            if i != 0 {
+                f.write_char(' ')?;
+            }
+            let slot = self.data.get_slot(i);
+            for j in 0..N::BITS_COUNT {
+                f.write_char(if B::get(slot, j) { '1' } else { '0' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats raw bytes of every slot of a container, bytes of a slot are printed in specified order.
+///
+/// `Debug` of bitmaps is the same as this formatter with [`Endianness::Little`].
+///
+/// Usage example:
+/// ```
+/// use bitmac::{StaticBitmap, Endianness, LSB};
+///
+/// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0011_0000_0001u16]);
+/// assert_eq!(
+///     format!("{:?}", bitmap.debug_bytes(Endianness::Little)),
+///     "[0b00000001, 0b00000011]",
+/// );
+/// assert_eq!(
+///     format!("{:?}", bitmap.debug_bytes(Endianness::Big)),
+///     "[0b00000011, 0b00000001]",
+/// );
+/// ```
+///
+/// [`Endianness::Little`]: crate::number::Endianness::Little
+pub struct DebugBytes<'a, D, B> {
+    data: &'a D,
+    endianness: Endianness,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, D, B> DebugBytes<'a, D, B> {
+    pub(crate) fn new(data: &'a D, endianness: Endianness) -> Self {
+        Self {
+            data,
+            endianness,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B, N> Debug for DebugBytes<'_, D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    N: Number,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut list = f.debug_list();
+        for i in 0..self.data.slots_count() {
+            let slot = self.data.get_slot(i);
+            for byte in self.endianness.slot_bytes(slot) {
+                list.entry(&format_args!("{:#010b}", byte));
+            }
+        }
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LSB, MSB};
+
+    #[test]
+    fn logical_order() {
+        let v = 0b0000_0000_0000_0001u16;
+        assert_eq!(
+            format!("{:?}", DebugLogical::<_, LSB>::new(&v)),
+            "1000000000000000"
+        );
+        assert_eq!(
+            format!("{:?}", DebugLogical::<_, MSB>::new(&v)),
+            "0000000000000001"
+        );
+
+        let v = 0b1000_0000_0000_0000u16;
+        let s = format!("{:?}", DebugLogical::<_, MSB>::new(&v));
+        assert!(s.starts_with('1'));
+        assert_eq!(s, "1000000000000000");
+
+        let v = [0b0000_0011u8, 0b1000_0000];
+        assert_eq!(
+            format!("{:?}", DebugLogical::<_, LSB>::new(&v)),
+            "11000000 00000001"
+        );
+        assert_eq!(
+            format!("{:?}", DebugLogical::<_, MSB>::new(&v)),
+            "00000011 10000000"
+        );
+
+        let v: Vec<u8> = vec![];
+        assert_eq!(format!("{:?}", DebugLogical::<_, LSB>::new(&v)), "");
+    }
+
+    #[test]
+    fn bytes_order() {
+        let v = [0x8001u16, 0x0102];
+        assert_eq!(
+            format!("{:?}", DebugBytes::<_, LSB>::new(&v, Endianness::Big)),
+            "[0b10000000, 0b00000001, 0b00000001, 0b00000010]"
+        );
+        assert_eq!(
+            format!("{:?}", DebugBytes::<_, LSB>::new(&v, Endianness::Little)),
+            "[0b00000001, 0b10000000, 0b00000010, 0b00000001]"
+        );
+    }
+}
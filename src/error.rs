@@ -1,15 +1,49 @@
 use std::ops::Range;
 
 #[derive(Debug, thiserror::Error)]
-#[error("index '{actual_idx}' out of bounds {bounds:?}")]
+#[error(
+    "index '{actual_idx}' out of bounds {bounds:?}{}",
+    required_slots_hint(.required_slots, .slot_bits)
+)]
 pub struct OutOfBoundsError {
     actual_idx: usize,
     bounds: Range<usize>,
+    slot_bits: usize,
+    required_slots: Option<usize>,
 }
 
 impl OutOfBoundsError {
-    pub(crate) fn new(actual_idx: usize, bounds: Range<usize>) -> Self {
-        Self { actual_idx, bounds }
+    pub(crate) fn new(actual_idx: usize, bounds: Range<usize>, slot_bits: usize) -> Self {
+        // Required slots can only be derived if bounds end at a slot boundary
+        let required_slots = if slot_bits != 0 && bounds.end % slot_bits == 0 {
+            Some(actual_idx / slot_bits + 1)
+        } else {
+            None
+        };
+        Self {
+            actual_idx,
+            bounds,
+            slot_bits,
+            required_slots,
+        }
+    }
+
+    /// Returns minimum number of slots that container needs to hold the index.
+    ///
+    /// Returns `None` if bounds don't end at a slot boundary, e.g. for containers
+    /// with a partial last slot.
+    pub fn required_slots(&self) -> Option<usize> {
+        self.required_slots
+    }
+}
+
+fn required_slots_hint(required_slots: &Option<usize>, slot_bits: &usize) -> String {
+    match required_slots {
+        Some(required_slots) => format!(
+            ", container needs at least {} slots of {} bits to hold it",
+            required_slots, slot_bits
+        ),
+        None => String::new(),
     }
 }
 
@@ -67,6 +101,24 @@ impl WithSlotsError {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+#[error("compressed bitmap is malformed: {details}")]
+pub struct DecompressError {
+    details: String,
+}
+
+impl DecompressError {
+    /// Creates new error with details.
+    pub(crate) fn new<C>(details: C) -> Self
+    where
+        C: Into<String>,
+    {
+        Self {
+            details: details.into(),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum IntersectionError {
     #[error(transparent)]
@@ -82,3 +134,11 @@ pub enum UnionError {
     #[error(transparent)]
     WithSlotsError(#[from] WithSlotsError),
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum CombineError {
+    #[error(transparent)]
+    SmallContainerSizeError(#[from] SmallContainerSizeError),
+    #[error(transparent)]
+    WithSlotsError(#[from] WithSlotsError),
+}
@@ -21,6 +21,24 @@ pub trait GrowStrategy {
     fn is_force_grow(&self) -> bool {
         false
     }
+
+    /// Checks if the strategy always grows to the minimum required length and never fails.
+    ///
+    /// If it returns `true`, bitmaps resize container to the minimum required length directly,
+    /// without calling [`try_grow`]. The default behavior is to return `false`.
+    ///
+    /// [`try_grow`]: crate::grow_strategy::GrowStrategy::try_grow
+    fn is_minimal(&self) -> bool {
+        false
+    }
+
+    /// Returns human readable description of the strategy and its parameters,
+    /// e.g. `"fixed(3)"`. Useful for logging which policy a bitmap uses.
+    ///
+    /// The default implementation returns `"custom"`.
+    fn describe(&self) -> String {
+        String::from("custom")
+    }
 }
 
 /// Increases the size of the container to the minimum required size.
@@ -36,6 +54,8 @@ pub trait GrowStrategy {
 /// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(5), 3, 35).unwrap().value(), 5);
 /// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(6), 3, 47).unwrap().value(), 6);
 /// assert!(!s.is_force_grow());
+/// assert!(s.is_minimal());
+/// assert_eq!(s.describe(), "minimum-required");
 /// ```
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MinimumRequiredStrategy;
@@ -49,10 +69,20 @@ impl GrowStrategy for MinimumRequiredStrategy {
     ) -> Result<FinalLength, ResizeError> {
         Ok(min_req_len.finalize())
     }
+
+    fn describe(&self) -> String {
+        String::from("minimum-required")
+    }
+
+    fn is_minimal(&self) -> bool {
+        true
+    }
 }
 
 /// Increases the size of the container by a fixed increment.
 ///
+/// Growing fails if the increment is `0` or the rounded length overflows `usize`.
+///
 /// Example:
 /// ```
 /// use bitmac::grow_strategy::{GrowStrategy, FixedStrategy, MinimumRequiredLength};
@@ -64,6 +94,7 @@ impl GrowStrategy for MinimumRequiredStrategy {
 /// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(5), 3, 35).unwrap().value(), 6);
 /// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(6), 3, 47).unwrap().value(), 6);
 /// assert!(!s.is_force_grow());
+/// assert_eq!(s.describe(), "fixed(3)");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
@@ -76,13 +107,89 @@ impl GrowStrategy for FixedStrategy {
         _old_len: usize,
         _bit_idx: usize,
     ) -> Result<FinalLength, ResizeError> {
+        if self.0 == 0 {
+            return Err(ResizeError::new("fixed increment must be greater than 0"));
+        }
         if min_req_len.value() % self.0 == 0 {
             Ok(min_req_len.finalize())
         } else {
-            let rest = (min_req_len.value() / self.0 + 1) * self.0 - min_req_len.value();
+            let new_len = (min_req_len.value() / self.0 + 1)
+                .checked_mul(self.0)
+                .ok_or_else(|| {
+                    ResizeError::new(format!(
+                        "length {} rounded up to multiple of {} is too large",
+                        min_req_len.value(),
+                        self.0
+                    ))
+                })?;
+            let rest = new_len - min_req_len.value();
             Ok(min_req_len.advance_by(rest))
         }
     }
+
+    fn describe(&self) -> String {
+        format!("fixed({})", self.0)
+    }
+}
+
+/// Increases the size of the container geometrically: new length is `old_len * factor`
+/// (rounded up), but at least `old_len + min_growth` and at least minimum required length.
+///
+/// The default strategy grows by `1.5` times and at least by one slot.
+///
+/// Example:
+/// ```
+/// use bitmac::grow_strategy::{GrowStrategy, GrowByFactorStrategy, LimitStrategy, MinimumRequiredLength};
+/// let mut s = GrowByFactorStrategy{ factor: 1.5, min_growth: 2 };
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(1), 0, 0).unwrap().value(), 2);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(3), 2, 16).unwrap().value(), 4);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(5), 4, 32).unwrap().value(), 6);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(11), 10, 80).unwrap().value(), 15);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(20), 10, 152).unwrap().value(), 20);
+/// assert!(!s.is_force_grow());
+///
+/// // It can be limited
+/// let mut s = LimitStrategy{ strategy: GrowByFactorStrategy::default(), limit: 15 };
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(11), 10, 80).unwrap().value(), 15);
+/// assert!(s.try_grow(MinimumRequiredLength::new_unchecked(12), 11, 88).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrowByFactorStrategy {
+    pub factor: f64,
+    pub min_growth: usize,
+}
+
+impl Default for GrowByFactorStrategy {
+    fn default() -> Self {
+        Self {
+            factor: 1.5,
+            min_growth: 1,
+        }
+    }
+}
+
+impl GrowStrategy for GrowByFactorStrategy {
+    fn try_grow(
+        &mut self,
+        min_req_len: MinimumRequiredLength,
+        old_len: usize,
+        _bit_idx: usize,
+    ) -> Result<FinalLength, ResizeError> {
+        // Float to int conversion saturates, so huge factors cannot overflow
+        let by_factor = (old_len as f64 * self.factor).ceil() as usize;
+        let new_len = by_factor
+            .max(old_len.saturating_add(self.min_growth))
+            .max(min_req_len.value());
+        let rest = new_len - min_req_len.value();
+        Ok(min_req_len.advance_by(rest))
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "grow-by-factor({}, min_growth: {})",
+            self.factor, self.min_growth
+        )
+    }
 }
 
 /// Increases the size of the container until the limit is reached.
@@ -101,6 +208,7 @@ impl GrowStrategy for FixedStrategy {
 /// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(5), 3, 35).unwrap().value(), 5);
 /// assert!(s.try_grow(MinimumRequiredLength::new_unchecked(6), 3, 47).is_err());
 /// assert!(!s.is_force_grow());
+/// assert_eq!(s.describe(), "limit(5, minimum-required)");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct LimitStrategy<S> {
@@ -129,6 +237,138 @@ where
             )))
         }
     }
+
+    fn describe(&self) -> String {
+        format!("limit({}, {})", self.limit, self.strategy.describe())
+    }
+}
+
+/// Increases the size of the container until it can hold `max_bits` bits.
+///
+/// Unlike [`LimitStrategy`], the limit is expressed in bits. Slot width of the container
+/// is passed to [`new`], so the container is capped at `ceil(max_bits / slot_bits)` slots.
+///
+/// Example:
+/// ```
+/// use bitmac::grow_strategy::{GrowStrategy, BitLimitStrategy, MinimumRequiredStrategy, MinimumRequiredLength};
+/// let mut s = BitLimitStrategy::new(MinimumRequiredStrategy, 10, 8);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(1), 0, 0).unwrap().value(), 1);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(2), 1, 9).unwrap().value(), 2);
+/// assert!(s.try_grow(MinimumRequiredLength::new_unchecked(3), 2, 16).is_err());
+/// assert!(!s.is_force_grow());
+/// assert_eq!(s.describe(), "bit-limit(10, minimum-required)");
+/// ```
+///
+/// [`LimitStrategy`]: crate::grow_strategy::LimitStrategy
+/// [`new`]: crate::grow_strategy::BitLimitStrategy::new
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitLimitStrategy<S> {
+    pub strategy: S,
+    pub max_bits: usize,
+    slot_bits: usize,
+}
+
+impl<S> BitLimitStrategy<S> {
+    /// Creates strategy that limits lengths chosen by `strategy` to hold at most `max_bits` bits
+    /// in slots of `slot_bits` bits, e.g. [`Number::BITS_COUNT`] of the container slot.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `slot_bits` is `0`.
+    ///
+    /// [`Number::BITS_COUNT`]: crate::number::Number::BITS_COUNT
+    pub fn new(strategy: S, max_bits: usize, slot_bits: usize) -> Self {
+        assert!(slot_bits > 0, "slot width must be non-zero");
+        Self {
+            strategy,
+            max_bits,
+            slot_bits,
+        }
+    }
+
+    /// Returns slot width the limit is computed for.
+    pub fn slot_bits(&self) -> usize {
+        self.slot_bits
+    }
+
+    /// Returns max number of slots that container may have.
+    pub fn max_slots(&self) -> usize {
+        self.max_bits / self.slot_bits + (self.max_bits % self.slot_bits != 0) as usize
+    }
+}
+
+impl<S> GrowStrategy for BitLimitStrategy<S>
+where
+    S: GrowStrategy,
+{
+    fn try_grow(
+        &mut self,
+        min_req_len: MinimumRequiredLength,
+        old_len: usize,
+        bit_idx: usize,
+    ) -> Result<FinalLength, ResizeError> {
+        let final_length = self.strategy.try_grow(min_req_len, old_len, bit_idx)?;
+        let max_slots = self.max_slots();
+        if final_length.value() <= max_slots {
+            Ok(final_length)
+        } else {
+            Err(ResizeError::new(format!(
+                "the new size {} is over the limit of {} bits ({} slots of {} bits)",
+                final_length.value(),
+                self.max_bits,
+                max_slots,
+                self.slot_bits
+            )))
+        }
+    }
+
+    fn is_force_grow(&self) -> bool {
+        self.strategy.is_force_grow()
+    }
+
+    fn describe(&self) -> String {
+        format!("bit-limit({}, {})", self.max_bits, self.strategy.describe())
+    }
+}
+
+/// Never increases the size of the container, every attempt to grow returns `Err(_)`.
+///
+/// It turns [`VarBitmap`] into a bitmap of fixed length: setting a bit past the container
+/// returns the strategy error instead of growing. Containers that cannot grow at all
+/// should also fail in [`try_reserve`], so that other strategies report an error too.
+///
+/// Example:
+/// ```
+/// use bitmac::grow_strategy::{GrowStrategy, NoGrowStrategy, MinimumRequiredLength};
+/// let mut s = NoGrowStrategy;
+/// assert!(s.try_grow(MinimumRequiredLength::new_unchecked(1), 0, 0).is_err());
+/// assert!(s.try_grow(MinimumRequiredLength::new_unchecked(4), 3, 24).is_err());
+/// assert!(!s.is_force_grow());
+/// ```
+///
+/// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+/// [`try_reserve`]: crate::resizable::Resizable::try_reserve
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NoGrowStrategy;
+
+impl GrowStrategy for NoGrowStrategy {
+    fn try_grow(
+        &mut self,
+        min_req_len: MinimumRequiredLength,
+        old_len: usize,
+        bit_idx: usize,
+    ) -> Result<FinalLength, ResizeError> {
+        Err(ResizeError::new(format!(
+            "container cannot grow: bit {} requires {} slots, but container has {} slots",
+            bit_idx,
+            min_req_len.value(),
+            old_len
+        )))
+    }
+
+    fn describe(&self) -> String {
+        String::from("no-grow")
+    }
 }
 
 /// Increases the size of the container despite new bit state is `0` (`false`).
@@ -144,6 +384,7 @@ where
 /// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(4), 3, 24).unwrap().value(), 4);
 /// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(5), 3, 35).unwrap().value(), 5);
 /// assert!(s.is_force_grow());
+/// assert_eq!(s.describe(), "force-grow(minimum-required)");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ForceGrowStrategy<S>(pub S);
@@ -164,6 +405,90 @@ where
     fn is_force_grow(&self) -> bool {
         true
     }
+
+    fn describe(&self) -> String {
+        format!("force-grow({})", self.0.describe())
+    }
+
+    fn is_minimal(&self) -> bool {
+        self.0.is_minimal()
+    }
+}
+
+/// Size of a cache line in bytes that [`CacheLineStrategy`] aligns to.
+pub const CACHE_LINE_BYTES: usize = 64;
+
+/// Rounds the length chosen by the inner strategy up so that container of slots
+/// of `slot_bits` bits occupies a multiple of [`CACHE_LINE_BYTES`] bytes.
+/// Slots wider than a cache line are left as is.
+///
+/// Example:
+/// ```
+/// use bitmac::grow_strategy::{GrowStrategy, CacheLineStrategy, MinimumRequiredStrategy, MinimumRequiredLength};
+/// let mut s = CacheLineStrategy::new(MinimumRequiredStrategy, 32);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(1), 0, 0).unwrap().value(), 16);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(16), 0, 511).unwrap().value(), 16);
+/// assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(17), 16, 512).unwrap().value(), 32);
+/// assert!(!s.is_force_grow());
+/// ```
+///
+/// [`CACHE_LINE_BYTES`]: crate::grow_strategy::CACHE_LINE_BYTES
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheLineStrategy<S> {
+    pub strategy: S,
+    slot_bits: usize,
+}
+
+impl<S> CacheLineStrategy<S> {
+    /// Creates strategy that aligns lengths chosen by `strategy` for slots of `slot_bits` bits,
+    /// e.g. [`Number::BITS_COUNT`] of the container slot.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `slot_bits` is `0`.
+    ///
+    /// [`Number::BITS_COUNT`]: crate::number::Number::BITS_COUNT
+    pub fn new(strategy: S, slot_bits: usize) -> Self {
+        assert!(slot_bits > 0, "slot width must be non-zero");
+        Self {
+            strategy,
+            slot_bits,
+        }
+    }
+
+    /// Returns slot width the alignment is computed for.
+    pub fn slot_bits(&self) -> usize {
+        self.slot_bits
+    }
+}
+
+impl<S> GrowStrategy for CacheLineStrategy<S>
+where
+    S: GrowStrategy,
+{
+    fn try_grow(
+        &mut self,
+        min_req_len: MinimumRequiredLength,
+        old_len: usize,
+        bit_idx: usize,
+    ) -> Result<FinalLength, ResizeError> {
+        let FinalLength(len) = self.strategy.try_grow(min_req_len, old_len, bit_idx)?;
+        // Slots wider than a cache line are aligned to a single slot
+        let slots_per_line = usize::max(CACHE_LINE_BYTES * 8 / self.slot_bits, 1);
+        let aligned_len = len
+            .checked_add(slots_per_line - 1)
+            .map(|v| v / slots_per_line * slots_per_line)
+            .ok_or_else(|| ResizeError::new(format!("length too large to align: {}", len)))?;
+        Ok(FinalLength(aligned_len))
+    }
+
+    fn is_force_grow(&self) -> bool {
+        self.strategy.is_force_grow()
+    }
+
+    fn describe(&self) -> String {
+        format!("cache-line({})", self.strategy.describe())
+    }
 }
 
 /// Minimum required length of bitmap container for storing Nth bit.
@@ -211,8 +536,10 @@ impl FinalLength {
 }
 
 #[cfg(test)]
+#[allow(clippy::default_constructed_unit_structs)]
 mod tests {
     use super::*;
+    use crate::number::Number;
 
     #[test]
     #[rustfmt::skip]
@@ -306,4 +633,90 @@ mod tests {
         assert!(s.try_grow(MinimumRequiredLength::new_unchecked(21), 5, 0).is_err());
         assert!(s.try_grow(MinimumRequiredLength::new_unchecked(25), 5, 0).is_err());
     }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_grow_by_factor() {
+        let mut s = GrowByFactorStrategy{ factor: 2.0, min_growth: 1 };
+
+        assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(1), 0, 0).unwrap().value(), 1);
+        assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(2), 1, 0).unwrap().value(), 2);
+        assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(3), 2, 0).unwrap().value(), 4);
+        assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(5), 4, 0).unwrap().value(), 8);
+        assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(21), 4, 0).unwrap().value(), 21);
+
+        // Factor below one still satisfies minimum required length
+        let mut s = GrowByFactorStrategy{ factor: 0.5, min_growth: 0 };
+        assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(5), 4, 0).unwrap().value(), 5);
+
+        let mut s = LimitStrategy{ strategy: GrowByFactorStrategy{ factor: 2.0, min_growth: 1 }, limit: 6 };
+        assert_eq!(s.try_grow(MinimumRequiredLength::new_unchecked(4), 3, 0).unwrap().value(), 6);
+        assert!(s.try_grow(MinimumRequiredLength::new_unchecked(5), 4, 0).is_err());
+    }
+
+    #[test]
+    fn cache_line() {
+        fn check<N: Number>() {
+            let mut s = CacheLineStrategy::new(FixedStrategy(3), N::BITS_COUNT);
+            for min_req_len in 1..200 {
+                let len = s
+                    .try_grow(MinimumRequiredLength::new_unchecked(min_req_len), 0, 0)
+                    .unwrap()
+                    .value();
+                assert!(len >= min_req_len);
+                assert_eq!(len * N::BYTES_COUNT % CACHE_LINE_BYTES, 0);
+                assert!(
+                    (len - min_req_len) * N::BYTES_COUNT < CACHE_LINE_BYTES + 3 * N::BYTES_COUNT
+                );
+            }
+        }
+
+        check::<u8>();
+        check::<u16>();
+        check::<u32>();
+        check::<u64>();
+        check::<u128>();
+
+        let mut s = CacheLineStrategy::new(MinimumRequiredStrategy, 8);
+        assert!(s
+            .try_grow(MinimumRequiredLength::new_unchecked(usize::MAX), 0, 0)
+            .is_err());
+        assert!(
+            CacheLineStrategy::new(ForceGrowStrategy(MinimumRequiredStrategy), 8).is_force_grow()
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        assert!(FixedStrategy(3).describe().contains("fixed"));
+        assert_eq!(NoGrowStrategy.describe(), "no-grow");
+        assert_eq!(
+            GrowByFactorStrategy::default().describe(),
+            "grow-by-factor(1.5, min_growth: 1)"
+        );
+        assert_eq!(
+            CacheLineStrategy::new(ForceGrowStrategy(FixedStrategy(2)), 8).describe(),
+            "cache-line(force-grow(fixed(2)))"
+        );
+    }
+
+    #[test]
+    fn test_fixed_invalid() {
+        let mut s = FixedStrategy(0);
+        assert!(s
+            .try_grow(MinimumRequiredLength::new_unchecked(1), 0, 0)
+            .is_err());
+
+        let mut s = FixedStrategy(4);
+        assert!(s
+            .try_grow(MinimumRequiredLength::new_unchecked(usize::MAX), 0, 0)
+            .is_err());
+        // Already a multiple, no rounding needed
+        assert_eq!(
+            s.try_grow(MinimumRequiredLength::new_unchecked(usize::MAX - 3), 0, 0)
+                .unwrap()
+                .value(),
+            usize::MAX - 3
+        );
+    }
 }
@@ -1,11 +1,19 @@
 use crate::{
+    combine::{BitOp, BitmapOps},
     container::{ContainerRead, ContainerWrite},
     number::Number,
+    resizable::Resizable,
     with_slots::TryWithSlots,
-    BitAccess, IntersectionError, SmallContainerSizeError,
+    BitAccess, CombineError, IntersectionError, SmallContainerSizeError,
 };
 
 /// Intersection operator (a & b).
+///
+/// Implemented for every [`BitmapOps`] type as a shorthand for [`BitOp::And`].
+///
+/// [`BitmapOps`]: crate::combine::BitmapOps
+/// [`BitOp::And`]: crate::combine::BitOp::And
+#[deprecated(note = "use `BitmapOps` with `BitOp::And` instead")]
 pub trait Intersection<Rhs, N, B>
 where
     Rhs: ContainerRead<B, Slot = N>,
@@ -31,7 +39,7 @@ where
     where
         Dst: ContainerWrite<B, Slot = N>;
 
-    /// Calculates intersection. Result container will be created with [`try_with_slots`] function.
+    /// Calculates intersection. Result container will be created with [`try_with_slots_exact`] function.
     ///
     /// ## Panic
     ///
@@ -39,16 +47,16 @@ where
     /// See non-panic function [`try_intersection`].
     ///
     /// [`try_intersection`]: crate::intersection::Intersection::try_intersection
-    /// [`try_with_slots`]: crate::with_slots::TryWithSlots::try_with_slots
+    /// [`try_with_slots_exact`]: crate::with_slots::TryWithSlots::try_with_slots_exact
     fn intersection<Dst>(&self, rhs: &Rhs) -> Dst
     where
         Dst: ContainerWrite<B, Slot = N> + TryWithSlots;
 
-    /// Calculates intersection. Result container will be created with [`try_with_slots`] function.
+    /// Calculates intersection. Result container will be created with [`try_with_slots_exact`] function.
     ///
     /// Returns `Err(_)` if `Dst` cannot fit the entire result.
     ///
-    /// [`try_with_slots`]: crate::with_slots::TryWithSlots::try_with_slots
+    /// [`try_with_slots_exact`]: crate::with_slots::TryWithSlots::try_with_slots_exact
     fn try_intersection<Dst>(&self, rhs: &Rhs) -> Result<Dst, IntersectionError>
     where
         Dst: ContainerWrite<B, Slot = N> + TryWithSlots;
@@ -59,6 +67,56 @@ where
     fn intersection_len(&self, rhs: &Rhs) -> usize;
 }
 
+#[allow(deprecated)]
+impl<T, Rhs, N, B> Intersection<Rhs, N, B> for T
+where
+    T: BitmapOps<Rhs, N, B>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    fn intersection_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst)
+    where
+        Dst: ContainerWrite<B, Slot = N>,
+    {
+        self.combine_in(rhs, BitOp::And, dst)
+    }
+
+    fn try_intersection_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst) -> Result<(), IntersectionError>
+    where
+        Dst: ContainerWrite<B, Slot = N>,
+    {
+        self.try_combine_in(rhs, BitOp::And, dst)
+            .map_err(from_combine_error)
+    }
+
+    fn intersection<Dst>(&self, rhs: &Rhs) -> Dst
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    {
+        self.combine(rhs, BitOp::And)
+    }
+
+    fn try_intersection<Dst>(&self, rhs: &Rhs) -> Result<Dst, IntersectionError>
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    {
+        self.try_combine(rhs, BitOp::And)
+            .map_err(from_combine_error)
+    }
+
+    fn intersection_len(&self, rhs: &Rhs) -> usize {
+        self.combine_len(rhs, BitOp::And)
+    }
+}
+
+fn from_combine_error(e: CombineError) -> IntersectionError {
+    match e {
+        CombineError::SmallContainerSizeError(e) => e.into(),
+        CombineError::WithSlotsError(e) => e.into(),
+    }
+}
+
 pub(crate) fn try_intersection_in_impl<Lhs, Rhs, Dst, N, B>(
     lhs: &Lhs,
     rhs: &Rhs,
@@ -75,9 +133,12 @@ where
     let required_dst_len = usize::min(lhs.slots_count(), rhs.slots_count());
     if dst.slots_count() < required_dst_len {
         return Err(SmallContainerSizeError::new(format!(
-            "size of container should be >= {}, but handled {}",
+            "size of container should be >= {} slots of {} bits ({} bits), but handled {} slots ({} bits)",
             required_dst_len,
-            dst.slots_count()
+            N::BITS_COUNT,
+            required_dst_len * N::BITS_COUNT,
+            dst.slots_count(),
+            dst.slots_count() * N::BITS_COUNT,
         ))
         .into());
     }
@@ -106,12 +167,30 @@ where
 {
     // TODO: shrink size
     let slots_count = usize::min(lhs.slots_count(), rhs.slots_count());
-    let mut dst = Dst::try_with_slots(slots_count)?;
+    let mut dst = Dst::try_with_slots_exact(slots_count)?;
 
     try_intersection_in_impl(lhs, rhs, &mut dst)?;
     Ok(dst)
 }
 
+pub(crate) fn intersection_into_resized_impl<Lhs, Rhs, Dst, N, B>(
+    lhs: &Lhs,
+    rhs: &Rhs,
+    dst: &mut Dst,
+) where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let slots_count = usize::min(lhs.slots_count(), rhs.slots_count());
+    dst.resize(slots_count, N::ZERO);
+
+    // Destination has exactly required size, so it cannot fail
+    try_intersection_in_impl(lhs, rhs, dst).unwrap();
+}
+
 pub(crate) fn intersection_len_impl<Lhs, Rhs, N, B>(lhs: &Lhs, rhs: &Rhs) -> usize
 where
     Lhs: ContainerRead<B, Slot = N>,
@@ -131,6 +210,17 @@ where
     len
 }
 
+pub(crate) fn intersection_is_empty_impl<Lhs, Rhs, N, B>(lhs: &Lhs, rhs: &Rhs) -> bool
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let max_idx = usize::min(lhs.slots_count(), rhs.slots_count());
+    (0..max_idx).all(|i| lhs.get_slot(i) & rhs.get_slot(i) == N::ZERO)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -536,4 +626,42 @@ mod tests {
         let rhs: [u8; 2] = [0b0010_0100, 0b0101_0000];
         assert_eq!(intersection_len_impl::<_, _, _, LSB>(&lhs, &rhs), 2);
     }
+
+    #[test]
+    fn try_intersection_exact_capacity() {
+        let lhs = vec![0b0000_1111u8; 3];
+        let rhs = vec![0b0000_0011u8; 5];
+        let dst: Vec<u8> = try_intersection_impl::<_, _, _, _, LSB>(&lhs, &rhs).unwrap();
+        assert_eq!(dst.len(), 3);
+        assert_eq!(dst.capacity(), 3);
+    }
+
+    #[test]
+    fn intersection_into_resized() {
+        let lhs = [0b0000_1111u8, 0b1111_0000, 0b1111_1111];
+        let rhs = [0b0000_0011u8, 0b0011_0000];
+
+        let mut dst = vec![0xFFu8; 5];
+        intersection_into_resized_impl::<_, _, _, _, LSB>(&lhs, &rhs, &mut dst);
+        assert_eq!(dst, vec![0b0000_0011, 0b0011_0000]);
+
+        let mut dst = Vec::new();
+        intersection_into_resized_impl::<_, _, _, _, LSB>(&lhs, &rhs, &mut dst);
+        assert_eq!(dst, vec![0b0000_0011, 0b0011_0000]);
+    }
+
+    #[test]
+    fn intersection_is_empty() {
+        let lhs: u8 = 0b0010_1100;
+        let rhs: u8 = 0b0101_0011;
+        assert!(intersection_is_empty_impl::<_, _, _, LSB>(&lhs, &rhs));
+
+        let lhs: u8 = 0b0010_1100;
+        let rhs: [u8; 2] = [0b0000_0100, 0b0101_0000];
+        assert!(!intersection_is_empty_impl::<_, _, _, LSB>(&lhs, &rhs));
+
+        let lhs: [u8; 2] = [0b0010_1100, 0b0000_0000];
+        let rhs: [u8; 3] = [0b0000_0011, 0b1111_1111, 0b1111_1111];
+        assert!(intersection_is_empty_impl::<_, _, _, LSB>(&lhs, &rhs));
+    }
 }
@@ -1,6 +1,10 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Range};
 
-use crate::{container::ContainerRead, number::Number, BitAccess};
+use crate::{
+    container::ContainerRead,
+    number::{Endianness, Number},
+    BitAccess,
+};
 
 /// An iterator over slots that moves out of a container.
 pub struct IntoIter<D, B> {
@@ -155,3 +159,263 @@ where
         }
     }
 }
+
+/// An iterator over `(index, state)` pairs of every bit.
+pub struct IndexedBits<'a, D, B> {
+    idx: usize,
+    slot_idx: usize,
+    bit_idx: usize,
+    data: &'a D,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, D, B> IndexedBits<'a, D, B> {
+    pub(crate) fn new(data: &'a D) -> Self {
+        Self {
+            idx: 0,
+            slot_idx: 0,
+            bit_idx: 0,
+            data,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B, N> Iterator for IndexedBits<'_, D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    N: Number,
+{
+    type Item = (usize, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slot_idx < self.data.slots_count() {
+            let slot = self.data.get_slot(self.slot_idx);
+            let v = (self.idx, B::get(slot, self.bit_idx));
+
+            self.idx += 1;
+            self.bit_idx += 1;
+            if self.bit_idx == N::BITS_COUNT {
+                self.bit_idx = 0;
+                self.slot_idx += 1;
+            }
+
+            Some(v)
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator over indexes of set bits (ones) in ascending order.
+///
+/// Slots without ones are skipped entirely.
+pub struct Ones<'a, D, B> {
+    slot_idx: usize,
+    bit_idx: usize,
+    data: &'a D,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, D, B> Ones<'a, D, B> {
+    pub(crate) fn new(data: &'a D) -> Self {
+        Self {
+            slot_idx: 0,
+            bit_idx: 0,
+            data,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B, N> Iterator for Ones<'_, D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    N: Number,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.slot_idx < self.data.slots_count() {
+            let slot = self.data.get_slot(self.slot_idx);
+            if slot != N::ZERO {
+                while self.bit_idx < N::BITS_COUNT {
+                    let bit_idx = self.bit_idx;
+                    self.bit_idx += 1;
+                    if B::get(slot, bit_idx) {
+                        return Some(self.slot_idx * N::BITS_COUNT + bit_idx);
+                    }
+                }
+            }
+
+            self.slot_idx += 1;
+            self.bit_idx = 0;
+        }
+        None
+    }
+}
+
+/// An iterator over raw bytes of every slot, bytes of a slot are in specified order.
+pub struct SlotBytes<'a, D, B> {
+    slot_idx: usize,
+    byte_idx: usize,
+    endianness: Endianness,
+    data: &'a D,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, D, B> SlotBytes<'a, D, B> {
+    pub(crate) fn new(data: &'a D, endianness: Endianness) -> Self {
+        Self {
+            slot_idx: 0,
+            byte_idx: 0,
+            endianness,
+            data,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B, N> Iterator for SlotBytes<'_, D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    N: Number,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slot_idx >= self.data.slots_count() {
+            return None;
+        }
+
+        let slot = self.data.get_slot(self.slot_idx);
+        let byte_idx = match self.endianness {
+            Endianness::Little => self.byte_idx,
+            Endianness::Big => N::BYTES_COUNT - 1 - self.byte_idx,
+        };
+        self.byte_idx += 1;
+        if self.byte_idx == N::BYTES_COUNT {
+            self.slot_idx += 1;
+            self.byte_idx = 0;
+        }
+        Some(slot.byte_at(byte_idx))
+    }
+}
+
+/// An iterator over numbers of set bits in every window of fixed width, in order of window start.
+///
+/// Count is updated incrementally: the bit entering the window is added and the bit leaving
+/// it is subtracted, so the whole pass takes `O(bits_count)`.
+pub struct SlidingPopcount<'a, D, B> {
+    start: usize,
+    width: usize,
+    count: usize,
+    data: &'a D,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, D, B> SlidingPopcount<'a, D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    pub(crate) fn new(data: &'a D, width: usize) -> Self {
+        let count = if width <= data.bits_count() {
+            (0..width).filter(|&idx| data.get_bit(idx)).count()
+        } else {
+            0
+        };
+        Self {
+            start: 0,
+            width,
+            count,
+            data,
+            phantom: Default::default(),
+        }
+    }
+
+    fn windows_left(&self) -> usize {
+        (self.data.bits_count() + 1).saturating_sub(self.width + self.start)
+    }
+}
+
+impl<D, B> Iterator for SlidingPopcount<'_, D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.windows_left() == 0 {
+            return None;
+        }
+
+        let res = self.count;
+        let end = self.start + self.width;
+        if self.width > 0 && end < self.data.bits_count() {
+            self.count += self.data.get_bit(end) as usize;
+            self.count -= self.data.get_bit(self.start) as usize;
+        }
+        self.start += 1;
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.windows_left();
+        (len, Some(len))
+    }
+}
+
+/// An iterator over maximal runs of cleared bits (gaps) in ascending order.
+///
+/// The last gap ends at `bits_count()`, bits beyond the container are not reported.
+pub struct Gaps<'a, D, B> {
+    start: usize,
+    end: usize,
+    ones: Ones<'a, D, B>,
+}
+
+impl<'a, D, B> Gaps<'a, D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    pub(crate) fn new(data: &'a D) -> Self {
+        Self {
+            start: 0,
+            end: data.bits_count(),
+            ones: Ones::new(data),
+        }
+    }
+}
+
+impl<D, B, N> Iterator for Gaps<'_, D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    N: Number,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for idx in &mut self.ones {
+            let start = self.start;
+            self.start = idx + 1;
+            if idx > start {
+                return Some(start..idx);
+            }
+        }
+
+        if self.start < self.end {
+            let start = self.start;
+            self.start = self.end;
+            Some(start..self.end)
+        } else {
+            None
+        }
+    }
+}
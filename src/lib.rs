@@ -8,6 +8,9 @@
 //! |------------|----------------------------------------------------------------------------------------------------------------------------------------|
 //! | `bytes`    | to implement [`ContainerRead`] trait for [`Bytes`] and [`ContainerRead`], [`ContainerWrite`] and [`Resizable`] traits for [`BytesMut`] |
 //! | `smallvec` | to implement [`ContainerRead`], [`ContainerWrite`] and [`Resizable`] traits for [`SmallVec`]                                           |
+//! | `mmap`     | to provide [`MmapSlots`] container over memory-mapped files                                                                            |
+//! | `rayon`    | to count ones and intersection length in bitmaps over contiguous slots in parallel                                                     |
+//! | `roaring`  | to convert bitmaps to and from [`RoaringBitmap`], requires Rust 1.65 or newer                                                          |
 //!
 //! ## BitAccess
 //!
@@ -32,9 +35,24 @@
 //! - `Bytes`
 //! - `BytesMut`
 //! - `SmallVec`
+//! - `MmapSlots`
 //!
 //! You can implement them for your custom containers, the only one constraint is that containers should
-//! consist of `Number`'s.
+//! consist of `Number`'s. If your container can access single bits cheaper than whole slots, you can also
+//! override `get_bit`, `try_set_bit` and `set_bit_unchecked`: bitmaps always access bits through them.
+//!
+//! ## BitmapSet
+//!
+//! [`BitmapSet`] is a set of indexes with `HashSet`-like API. Use it if you just need a bitset
+//! and don't care about container, bit access or grow strategy.
+//!
+//! ```
+//! use bitmac::BitmapSet;
+//!
+//! let mut set = BitmapSet::new();
+//! set.insert(5);
+//! assert!(set.contains(5));
+//! ```
 //!
 //! ## StaticBitmap
 //!
@@ -66,19 +84,19 @@
 //! assert_eq!(iter.next(), Some((4, false)));
 //!
 //! // You can check multiple bits at the same time through the intersection
-//! use bitmac::Intersection;
+//! use bitmac::{BitOp, BitmapOps};
 //! let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_1000]);
 //! // .. by creating specific new container for result
 //! let test = [0b0000_1001u8, 0b0000_0000];
-//! assert_eq!(bitmap.intersection::<[u8; 2]>(&test), test);
+//! assert_eq!(bitmap.combine::<[u8; 2]>(&test, BitOp::And), test);
 //! // .. by using preallocated container for result
 //! let test = [0b0000_1001u8, 0b0000_0000];
 //! let mut result = [0u8; 2];
-//! bitmap.intersection_in(&test, &mut result);
+//! bitmap.combine_in(&test, BitOp::And, &mut result);
 //! assert_eq!(result, test);
 //! // .. by comparing length of difference that is equivalent to count of ones (bits) in result
 //! let test = [0b0000_1001u8, 0b0000_0000];
-//! assert_eq!(bitmap.intersection_len(&test), test.iter().fold(0, |acc, &v| acc + v.count_ones() as usize));
+//! assert_eq!(bitmap.combine_len(&test, BitOp::And), test.iter().fold(0, |acc, &v| acc + v.count_ones() as usize));
 //!
 //! // You can directly change every single bit
 //! let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0001_1000]);
@@ -129,19 +147,19 @@
 //! assert_eq!(iter.next(), Some((4, false)));
 //!
 //! // You can check multiple bits at the same time through the intersection
-//! use bitmac::Intersection;
+//! use bitmac::{BitOp, BitmapOps};
 //! let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_1000]);
 //! // .. by creating specific new container for result
 //! let test = [0b0000_1001u8, 0b0000_0000];
-//! assert_eq!(bitmap.intersection::<[u8; 2]>(&test), test);
+//! assert_eq!(bitmap.combine::<[u8; 2]>(&test, BitOp::And), test);
 //! // .. by using preallocated container for result
 //! let test = [0b0000_1001u8, 0b0000_0000];
 //! let mut result = [0u8; 2];
-//! bitmap.intersection_in(&test, &mut result);
+//! bitmap.combine_in(&test, BitOp::And, &mut result);
 //! assert_eq!(result, test);
 //! // .. by comparing length of difference that is equivalent to count of ones (bits) in result
 //! let test = [0b0000_1001u8, 0b0000_0000];
-//! assert_eq!(bitmap.intersection_len(&test), test.iter().fold(0, |acc, &v| acc + v.count_ones() as usize));
+//! assert_eq!(bitmap.combine_len(&test, BitOp::And), test.iter().fold(0, |acc, &v| acc + v.count_ones() as usize));
 //!
 //! // You can directly change every bit
 //! let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0001_1000]);
@@ -170,8 +188,12 @@
 //!
 //! - [`MinimumRequiredStrategy`]
 //! - [`FixedStrategy`]
+//! - [`GrowByFactorStrategy`]
 //! - [`LimitStrategy`]
+//! - [`BitLimitStrategy`]
 //! - [`ForceGrowStrategy`]
+//! - [`NoGrowStrategy`]
+//! - [`CacheLineStrategy`]
 //!
 //! ### TryWithSlots
 //!
@@ -182,8 +204,12 @@
 //! [`GrowStrategy`]: crate::grow_strategy::GrowStrategy
 //! [`MinimumRequiredStrategy`]: crate::grow_strategy::MinimumRequiredStrategy
 //! [`FixedStrategy`]: crate::grow_strategy::FixedStrategy
+//! [`GrowByFactorStrategy`]: crate::grow_strategy::GrowByFactorStrategy
 //! [`LimitStrategy`]: crate::grow_strategy::LimitStrategy
+//! [`BitLimitStrategy`]: crate::grow_strategy::BitLimitStrategy
 //! [`ForceGrowStrategy`]: crate::grow_strategy::ForceGrowStrategy
+//! [`NoGrowStrategy`]: crate::grow_strategy::NoGrowStrategy
+//! [`CacheLineStrategy`]: crate::grow_strategy::CacheLineStrategy
 //! [`BitAccess`]: crate::bit_access::BitAccess
 //! [`LSB`]: crate::bit_access::LSB
 //! [`MSB`]: crate::bit_access::MSB
@@ -194,30 +220,64 @@
 //! [`TryWithSlots`]: crate::with_slots::TryWithSlots
 //! [`VarBitmap`]: crate::var_bitmap::VarBitmap
 //! [`StaticBitmap`]: crate::static_bitmap::StaticBitmap
+//! [`BitmapSet`]: crate::bitmap_set::BitmapSet
 //! [`Bytes`]: https://docs.rs/bytes/latest/bytes/
 //! [`BytesMut`]: https://docs.rs/bytes/latest/bytes/
 //! [`SmallVec`]: https://docs.rs/smallvec/latest/smallvec/
+//! [`RoaringBitmap`]: https://docs.rs/roaring/latest/roaring/
+//! [`MmapSlots`]: https://docs.rs/bitmac/latest/bitmac/mmap/struct.MmapSlots.html
 
 pub mod bit_access;
+pub mod bit_slice;
+mod bitfield;
+pub mod bitmap_set;
+pub mod bool_vec;
+pub mod canonical;
+pub mod combine;
+mod compress;
 pub mod container;
+pub mod debug;
 pub mod error;
 pub mod grow_strategy;
 pub mod intersection;
 pub mod iter;
+#[cfg(feature = "mmap")]
+pub mod mmap;
 pub mod number;
+pub mod ordering;
+#[cfg(feature = "rayon")]
+mod par;
+mod random;
+mod range;
+pub mod raw;
 pub mod resizable;
+mod shift;
+pub mod shifted_view;
 pub mod static_bitmap;
+pub mod transpose;
 pub mod union;
+mod update;
 pub mod var_bitmap;
 pub mod with_slots;
 
-pub use bit_access::{BitAccess, LSB, MSB};
+pub use bit_access::{BitAccess, BitOrder, LSB, MSB};
+pub use bit_slice::BitSlice;
+pub use bitmap_set::{BitmapSet, DefaultVarBitmap};
+pub use canonical::Canonical;
+pub use combine::{BitOp, BitmapOps};
 pub use error::{
-    IntersectionError, OutOfBoundsError, ResizeError, SmallContainerSizeError, UnionError,
-    WithSlotsError,
+    CombineError, DecompressError, IntersectionError, OutOfBoundsError, ResizeError,
+    SmallContainerSizeError, UnionError, WithSlotsError,
+};
+pub use grow_strategy::{
+    BitLimitStrategy, CacheLineStrategy, FixedStrategy, GrowByFactorStrategy, LimitStrategy,
+    MinimumRequiredStrategy, NoGrowStrategy,
 };
-pub use grow_strategy::{FixedStrategy, LimitStrategy, MinimumRequiredStrategy};
+#[allow(deprecated)]
 pub use intersection::Intersection;
+pub use number::Endianness;
+pub use ordering::{BitmapOrdering, OnesSummary};
 pub use static_bitmap::StaticBitmap;
+#[allow(deprecated)]
 pub use union::Union;
-pub use var_bitmap::VarBitmap;
+pub use var_bitmap::{SetOutcome, VarBitmap};
@@ -0,0 +1,141 @@
+use memmap2::MmapMut;
+
+use crate::{
+    container::{ContainerRead, ContainerWrite},
+    BitAccess,
+};
+
+/// A container over memory-mapped file (or anonymous memory map).
+///
+/// Every byte of the map is a single slot, so bitmaps read and write bits directly in the mapped memory.
+/// The container cannot be resized, use it with [`StaticBitmap`].
+///
+/// Usage example:
+/// ```
+/// use bitmac::{mmap::MmapSlots, StaticBitmap, LSB};
+/// use memmap2::MmapMut;
+///
+/// let mut bitmap = StaticBitmap::<_, LSB>::new(MmapSlots::new(MmapMut::map_anon(2).unwrap()));
+/// bitmap.set(9, true);
+/// assert!(bitmap.get(9));
+/// assert!(bitmap.try_set(16, true).is_err());
+/// ```
+///
+/// [`StaticBitmap`]: crate::static_bitmap::StaticBitmap
+#[derive(Debug)]
+pub struct MmapSlots {
+    map: MmapMut,
+}
+
+impl MmapSlots {
+    /// Creates new container over memory map.
+    pub fn new(map: MmapMut) -> Self {
+        Self { map }
+    }
+
+    /// Flushes outstanding memory map modifications to disk.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.map.flush()
+    }
+
+    /// Converts container into inner memory map.
+    pub fn into_inner(self) -> MmapMut {
+        self.map
+    }
+}
+
+impl From<MmapMut> for MmapSlots {
+    fn from(f: MmapMut) -> Self {
+        Self::new(f)
+    }
+}
+
+impl AsRef<MmapMut> for MmapSlots {
+    fn as_ref(&self) -> &MmapMut {
+        &self.map
+    }
+}
+
+impl AsMut<MmapMut> for MmapSlots {
+    fn as_mut(&mut self) -> &mut MmapMut {
+        &mut self.map
+    }
+}
+
+impl<B> ContainerRead<B> for MmapSlots
+where
+    B: BitAccess,
+{
+    type Slot = u8;
+
+    #[inline]
+    fn get_slot(&self, idx: usize) -> Self::Slot {
+        self.map[idx]
+    }
+
+    #[inline]
+    fn slots_count(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline]
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        Some(&self.map)
+    }
+}
+
+impl<B> ContainerWrite<B> for MmapSlots
+where
+    B: BitAccess,
+{
+    #[inline]
+    fn get_mut_slot(&mut self, idx: usize) -> &mut Self::Slot {
+        &mut self.map[idx]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::OpenOptions;
+
+    use super::*;
+    use crate::{StaticBitmap, LSB};
+
+    #[test]
+    fn file_round_trip() {
+        let path = std::env::temp_dir().join(format!("bitmac-mmap-{}", std::process::id()));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .unwrap();
+        file.set_len(4).unwrap();
+
+        let map = unsafe { MmapMut::map_mut(&file).unwrap() };
+        let mut v = StaticBitmap::<_, LSB>::new(MmapSlots::new(map));
+        v.set(0, true);
+        v.set(9, true);
+        v.set(31, true);
+        assert!(v.try_set(32, true).is_err());
+        v.as_ref().flush().unwrap();
+        drop(v);
+
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            vec![0b0000_0001, 0b0000_0010, 0, 0b1000_0000]
+        );
+
+        let map = unsafe { MmapMut::map_mut(&file).unwrap() };
+        let v = StaticBitmap::<_, LSB>::new(MmapSlots::new(map));
+        assert!(v.get(0));
+        assert!(v.get(9));
+        assert!(v.get(31));
+        assert_eq!(v.count_ones(), 3);
+
+        drop(v);
+        drop(file);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
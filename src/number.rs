@@ -3,6 +3,55 @@ use std::{
     ops::{Add, BitAnd, BitOr, Not, Shl, Shr, Sub},
 };
 
+/// Unsigned integer that is used as a slot of containers.
+///
+/// The trait is implemented for `u8`, `u16`, `u32`, `u64` and `u128`. Custom implementations
+/// must have non-zero `BITS_COUNT`, otherwise setting or getting bits of bitmaps over them
+/// fails to compile:
+/// ```compile_fail,E0080
+/// use std::{fmt, ops::*};
+/// use bitmac::{number::Number, StaticBitmap, LSB};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Empty;
+///
+/// macro_rules! op {
+///     ($tr:ident, $f:ident, $rhs:ty) => {
+///         impl $tr<$rhs> for Empty {
+///             type Output = Empty;
+///             fn $f(self, _: $rhs) -> Empty { Empty }
+///         }
+///     };
+/// }
+/// op!(Add, add, Empty); op!(Sub, sub, Empty); op!(BitAnd, bitand, Empty);
+/// op!(BitOr, bitor, Empty);
+/// op!(Shl, shl, usize); op!(Shr, shr, usize);
+///
+/// impl Not for Empty {
+///     type Output = Empty;
+///     fn not(self) -> Empty { Empty }
+/// }
+///
+/// impl fmt::Binary for Empty {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { Ok(()) }
+/// }
+///
+/// impl Number for Empty {
+///     const BITS_COUNT: usize = 0;
+///     const BYTES_COUNT: usize = 0;
+///     const ONE: Self = Empty;
+///     const ZERO: Self = Empty;
+///     const MAX: Self = Empty;
+///     const MIN: Self = Empty;
+///     const BYTE_MASK: Self = Empty;
+///
+///     fn count_ones(self) -> u32 { 0 }
+///     fn count_zeros(self) -> u32 { 0 }
+/// }
+///
+/// let mut bitmap = StaticBitmap::<_, LSB>::new(vec![Empty]);
+/// bitmap.set(0, true);
+/// ```
 pub trait Number:
     Copy
     + Add<Self, Output = Self>
@@ -16,6 +65,9 @@ pub trait Number:
     + Ord
     + Binary
 {
+    /// Number of bits. It must be non-zero: bitmaps divide by it to find slot of a bit,
+    /// so accessing bits of a bitmap over a number with zero width fails to compile
+    /// (see example in the trait documentation).
     const BITS_COUNT: usize;
     const BYTES_COUNT: usize;
     const ONE: Self;
@@ -24,12 +76,95 @@ pub trait Number:
     const MIN: Self;
     const BYTE_MASK: Self;
 
+    /// Compile-time check of `BITS_COUNT` invariant. It is evaluated by single bit access
+    /// (`get_bit`, `set_bit_unchecked`) and by growth of [`VarBitmap`], so a zero width is
+    /// reported when such code is instantiated. The check is partial: other code that divides
+    /// by `BITS_COUNT` panics at runtime instead.
+    /// Method is hidden for the same reason as `count_ones_in`.
+    ///
+    /// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+    #[doc(hidden)]
+    const ASSERT_NON_ZERO_WIDTH: () =
+        assert!(Self::BITS_COUNT > 0, "`BITS_COUNT` must be non-zero");
+
     fn count_ones(self) -> u32;
     fn count_zeros(self) -> u32;
+
+    /// Returns `idx`-th byte of the number, `0`th byte is the least significant one.
+    ///
+    /// Method is hidden for the same reason as `count_ones_in`.
+    #[doc(hidden)]
+    fn byte_at(self, idx: usize) -> u8 {
+        let byte = (self >> (idx * 8)) & Self::BYTE_MASK;
+        (0..8).fold(0, |acc, j| {
+            acc | ((((byte >> j) & Self::ONE) == Self::ONE) as u8) << j
+        })
+    }
+
+    /// Counts ones in all slots.
+    ///
+    /// Implementations may override it with a faster algorithm. Method is hidden because you
+    /// don't need to call it directly. Instead, you should use one of the bitmap implementations.
+    #[doc(hidden)]
+    fn count_ones_in(slots: &[Self]) -> usize {
+        slots.iter().map(|v| v.count_ones() as usize).sum()
+    }
+
+    /// Returns index of the first non-zero slot.
+    ///
+    /// Zero regions are skipped by blocks: a block is checked by a single comparison of
+    /// its slots combined with `|`. Method is hidden for the same reason as `count_ones_in`.
+    #[doc(hidden)]
+    fn first_nonzero_in(slots: &[Self]) -> Option<usize> {
+        const BLOCK_LEN: usize = 16;
+
+        let mut offset = 0;
+        for block in slots.chunks(BLOCK_LEN) {
+            if block.iter().fold(Self::ZERO, |acc, &v| acc | v) != Self::ZERO {
+                return block
+                    .iter()
+                    .position(|&v| v != Self::ZERO)
+                    .map(|idx| offset + idx);
+            }
+            offset += block.len();
+        }
+        None
+    }
+}
+
+/// Order of bytes of a slot when it is exported as bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// The least significant byte first. It is the default.
+    Little,
+    /// The most significant byte first, as in network byte order.
+    Big,
+}
+
+impl Default for Endianness {
+    fn default() -> Self {
+        Endianness::Little
+    }
+}
+
+impl Endianness {
+    /// Returns bytes of `slot` in this order.
+    pub(crate) fn slot_bytes<N>(self, slot: N) -> impl Iterator<Item = u8>
+    where
+        N: Number,
+    {
+        (0..N::BYTES_COUNT).map(move |j| match self {
+            Endianness::Little => slot.byte_at(j),
+            Endianness::Big => slot.byte_at(N::BYTES_COUNT - 1 - j),
+        })
+    }
 }
 
 macro_rules! number_impl {
     ($ty:ty, $bits:literal) => {
+        number_impl!($ty, $bits, {});
+    };
+    ($ty:ty, $bits:literal, { $($extra:tt)* }) => {
         impl Number for $ty {
             const BITS_COUNT: usize = $bits;
             const BYTES_COUNT: usize = $bits / 8;
@@ -48,11 +183,35 @@ macro_rules! number_impl {
             fn count_zeros(self) -> u32 {
                 <$ty>::count_zeros(self)
             }
+
+            #[inline]
+            fn byte_at(self, idx: usize) -> u8 {
+                (self >> (idx * 8)) as u8
+            }
+
+            $($extra)*
         }
     };
 }
 
-number_impl!(u8, 8);
+number_impl!(u8, 8, {
+    // Bytes are counted as words, e.g. for network buffers used as bitmaps
+    fn count_ones_in(slots: &[Self]) -> usize {
+        let chunks = slots.chunks_exact(8);
+        let rest = chunks.remainder();
+
+        let mut res = 0;
+        for chunk in chunks {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(chunk);
+            res += u64::from_ne_bytes(word).count_ones() as usize;
+        }
+        for v in rest {
+            res += v.count_ones() as usize;
+        }
+        res
+    }
+});
 number_impl!(u16, 16);
 number_impl!(u32, 32);
 number_impl!(u64, 64);
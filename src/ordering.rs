@@ -0,0 +1,219 @@
+use crate::{container::ContainerRead, number::Number, BitAccess};
+
+/// Relation between sets of ones of two bitmaps.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BitmapOrdering {
+    /// Both bitmaps have the same ones.
+    Equal,
+    /// Every one of `self` is presented in `other`, but `other` has more ones.
+    Subset,
+    /// Every one of `other` is presented in `self`, but `self` has more ones.
+    Superset,
+    /// Both bitmaps have ones that the other one lacks.
+    Incomparable,
+}
+
+/// Number of set bits together with indexes of the first and the last of them,
+/// see [`StaticBitmap::ones_summary`].
+///
+/// [`StaticBitmap::ones_summary`]: crate::static_bitmap::StaticBitmap::ones_summary
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct OnesSummary {
+    /// Number of set bits.
+    pub count: usize,
+    /// Index of the first set bit, `None` if no bit is set.
+    pub first: Option<usize>,
+    /// Index of the last set bit, `None` if no bit is set.
+    pub last: Option<usize>,
+}
+
+pub(crate) fn compare_impl<Lhs, Rhs, N, B>(lhs: &Lhs, rhs: &Rhs) -> BitmapOrdering
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let mut lhs_extra = false;
+    let mut rhs_extra = false;
+
+    let max_idx = usize::max(lhs.slots_count(), rhs.slots_count());
+    for i in 0..max_idx {
+        // Missing slots of shorter container are zeros
+        let lhs_slot = if i < lhs.slots_count() {
+            lhs.get_slot(i)
+        } else {
+            N::ZERO
+        };
+        let rhs_slot = if i < rhs.slots_count() {
+            rhs.get_slot(i)
+        } else {
+            N::ZERO
+        };
+
+        lhs_extra |= lhs_slot & !rhs_slot != N::ZERO;
+        rhs_extra |= rhs_slot & !lhs_slot != N::ZERO;
+        if lhs_extra && rhs_extra {
+            return BitmapOrdering::Incomparable;
+        }
+    }
+
+    match (lhs_extra, rhs_extra) {
+        (false, false) => BitmapOrdering::Equal,
+        (false, true) => BitmapOrdering::Subset,
+        (true, false) => BitmapOrdering::Superset,
+        (true, true) => BitmapOrdering::Incomparable,
+    }
+}
+
+/// Returns index of the last set bit plus one, or `0` if no bit is set.
+pub(crate) fn bit_len_impl<D, N, B>(data: &D) -> usize
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    for i in (0..data.slots_count()).rev() {
+        let slot = data.get_slot(i);
+        if let Some(bit_idx) = (0..N::BITS_COUNT).rev().find(|&j| B::get(slot, j)) {
+            return i * N::BITS_COUNT + bit_idx + 1;
+        }
+    }
+    0
+}
+
+/// Counts set bits, all slots are counted at once if the container provides them as a slice.
+pub(crate) fn count_ones_impl<D, N, B>(data: &D) -> usize
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    if let Some(slots) = data.as_slot_slice() {
+        return N::count_ones_in(slots);
+    }
+
+    let mut res = 0;
+    for i in 0..data.slots_count() {
+        res += data.get_slot(i).count_ones() as usize;
+    }
+    res
+}
+
+/// Counts set bits until the count reaches `limit`, so the result is exact only if it is less
+/// than `limit`. Slots of the slice (if container provides it) are counted in chunks.
+pub(crate) fn count_ones_up_to_impl<D, N, B>(data: &D, limit: usize) -> usize
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    const CHUNK_LEN: usize = 64;
+
+    let mut res = 0;
+    if let Some(slots) = data.as_slot_slice() {
+        for chunk in slots.chunks(CHUNK_LEN) {
+            if res >= limit {
+                break;
+            }
+            res += N::count_ones_in(chunk);
+        }
+        return res;
+    }
+
+    for i in 0..data.slots_count() {
+        if res >= limit {
+            break;
+        }
+        res += data.get_slot(i).count_ones() as usize;
+    }
+    res
+}
+
+/// Counts set bits and finds the first and the last of them in a single pass over slots.
+pub(crate) fn ones_summary_impl<D, N, B>(data: &D) -> OnesSummary
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let mut count = 0;
+    let mut first_slot = None;
+    let mut last_slot = None;
+    for i in 0..data.slots_count() {
+        let slot = data.get_slot(i);
+        if slot != N::ZERO {
+            count += slot.count_ones() as usize;
+            first_slot.get_or_insert((i, slot));
+            last_slot = Some((i, slot));
+        }
+    }
+
+    let first = first_slot.map(|(i, slot)| {
+        let bit_idx = (0..N::BITS_COUNT).find(|&j| B::get(slot, j)).unwrap();
+        i * N::BITS_COUNT + bit_idx
+    });
+    let last = last_slot.map(|(i, slot)| {
+        let bit_idx = (0..N::BITS_COUNT).rev().find(|&j| B::get(slot, j)).unwrap();
+        i * N::BITS_COUNT + bit_idx
+    });
+    OnesSummary { count, first, last }
+}
+
+/// Checks if both containers have the same ones. Logical lengths are compared first,
+/// then only the slots up to the last set bit, stopping at the first different slot.
+pub(crate) fn bits_eq_impl<Lhs, Rhs, N, B>(lhs: &Lhs, rhs: &Rhs) -> bool
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let bit_len = bit_len_impl(lhs);
+    if bit_len != bit_len_impl(rhs) {
+        return false;
+    }
+
+    let slots_count = (bit_len + N::BITS_COUNT - 1) / N::BITS_COUNT;
+    (0..slots_count).all(|i| lhs.get_slot(i) == rhs.get_slot(i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LSB;
+
+    #[test]
+    fn compare() {
+        let cmp = |lhs: &[u8], rhs: &[u8]| compare_impl::<_, _, _, LSB>(&lhs, &rhs);
+
+        assert_eq!(cmp(&[0b0000_1001], &[0b0000_1001]), BitmapOrdering::Equal);
+        assert_eq!(
+            cmp(&[0b0000_1001], &[0b0000_1001, 0]),
+            BitmapOrdering::Equal
+        );
+        assert_eq!(cmp(&[], &[]), BitmapOrdering::Equal);
+        assert_eq!(cmp(&[0b0000_0001], &[0b0000_1001]), BitmapOrdering::Subset);
+        assert_eq!(
+            cmp(&[0b0000_1001], &[0b0000_1001, 1]),
+            BitmapOrdering::Subset
+        );
+        assert_eq!(
+            cmp(&[0b0000_1001], &[0b0000_0001]),
+            BitmapOrdering::Superset
+        );
+        assert_eq!(cmp(&[0b0000_1001, 1], &[]), BitmapOrdering::Superset);
+        assert_eq!(
+            cmp(&[0b0000_1000], &[0b0000_0001]),
+            BitmapOrdering::Incomparable
+        );
+        assert_eq!(
+            cmp(&[0b0000_1000], &[0b0000_1000, 1]),
+            BitmapOrdering::Subset
+        );
+        assert_eq!(
+            cmp(&[0b0000_1001, 0], &[0b0000_1000, 1]),
+            BitmapOrdering::Incomparable
+        );
+    }
+}
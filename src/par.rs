@@ -0,0 +1,54 @@
+use rayon::prelude::*;
+
+use crate::number::Number;
+
+/// Number of slots processed by a single task.
+const CHUNK_LEN: usize = 4096;
+
+pub(crate) fn par_count_ones_impl<N>(slots: &[N]) -> usize
+where
+    N: Number + Sync,
+{
+    slots.par_chunks(CHUNK_LEN).map(N::count_ones_in).sum()
+}
+
+pub(crate) fn par_intersection_len_impl<N>(lhs: &[N], rhs: &[N]) -> usize
+where
+    N: Number + Sync,
+{
+    lhs.par_chunks(CHUNK_LEN)
+        .zip(rhs.par_chunks(CHUNK_LEN))
+        .map(|(lhs, rhs)| {
+            lhs.iter()
+                .zip(rhs)
+                .map(|(&l, &r)| (l & r).count_ones() as usize)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn par_count() {
+        let lhs: Vec<u32> = (0..10_000u32)
+            .map(|i| i.wrapping_mul(0x9E37_79B9))
+            .collect();
+        let rhs: Vec<u32> = (0..12_345u32)
+            .map(|i| i.rotate_left(7) ^ 0xA5A5_A5A5)
+            .collect();
+
+        let count: usize = lhs.iter().map(|v| v.count_ones() as usize).sum();
+        assert_eq!(par_count_ones_impl(&lhs), count);
+
+        let len: usize = lhs
+            .iter()
+            .zip(&rhs)
+            .map(|(&l, &r)| (l & r).count_ones() as usize)
+            .sum();
+        assert_eq!(par_intersection_len_impl(&lhs, &rhs), len);
+        assert_eq!(par_intersection_len_impl(&rhs, &lhs), len);
+    }
+}
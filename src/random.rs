@@ -0,0 +1,65 @@
+use crate::{container::ContainerWrite, number::Number, BitAccess};
+
+/// Tiny xorshift64* generator. It is not cryptographically secure and is intended
+/// for reproducible test data only.
+pub(crate) struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        // State must not be zero, otherwise generator yields only zeros
+        let state = seed ^ 0x9e37_79b9_7f4a_7c15;
+        Self {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Returns number in `[0, 1)`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Sets every bit of container with probability `density`, clears otherwise.
+pub(crate) fn fill_pseudo_random_impl<D, N, B>(data: &mut D, seed: u64, density: f64)
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let mut rng = XorShift64::new(seed);
+    for idx in 0..data.bits_count() {
+        let val = rng.next_f64() < density;
+        data.set_bit_unchecked(idx, val);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LSB;
+
+    #[test]
+    fn reproducible() {
+        let mut a = vec![0u8; 16];
+        let mut b = vec![0u8; 16];
+        fill_pseudo_random_impl::<_, _, LSB>(&mut a, 42, 0.5);
+        fill_pseudo_random_impl::<_, _, LSB>(&mut b, 42, 0.5);
+        assert_eq!(a, b);
+        fill_pseudo_random_impl::<_, _, LSB>(&mut b, 43, 0.5);
+        assert_ne!(a, b);
+
+        let mut rng = XorShift64::new(0);
+        assert!((0..1000)
+            .map(|_| rng.next_f64())
+            .all(|v| (0.0..1.0).contains(&v)));
+    }
+}
@@ -0,0 +1,178 @@
+use std::ops::Range;
+
+use crate::{
+    container::{ContainerRead, ContainerWrite},
+    number::Number,
+    shift::low_mask,
+    BitAccess,
+};
+
+/// Writes `bits[j]` to bit `start + j`. Every affected slot is read and written only once.
+///
+/// `start + bits.len()` must be less or equal to `bits_count()`.
+pub(crate) fn set_bits_from_impl<D, N, B>(data: &mut D, start: usize, bits: &[bool])
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let mut idx = start;
+    let mut rest = bits;
+    while !rest.is_empty() {
+        let slot_idx = idx / N::BITS_COUNT;
+        let bit_idx = idx % N::BITS_COUNT;
+        let count = usize::min(N::BITS_COUNT - bit_idx, rest.len());
+
+        let mut slot = data.get_slot(slot_idx);
+        for (j, &bit) in rest[..count].iter().enumerate() {
+            slot = B::set(slot, bit_idx + j, bit);
+        }
+        *data.get_mut_slot(slot_idx) = slot;
+
+        idx += count;
+        rest = &rest[count..];
+    }
+}
+
+/// Fills `out[j]` with state of bit `start + j`. Every affected slot is read only once,
+/// out of bounds bits are `false`.
+pub(crate) fn get_bits_into_impl<D, N, B>(data: &D, start: usize, out: &mut [bool])
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let bits_count = data.bits_count();
+    let in_bounds = bits_count.saturating_sub(start).min(out.len());
+    let (head, tail) = out.split_at_mut(in_bounds);
+
+    let mut idx = start;
+    let mut rest = head;
+    while !rest.is_empty() {
+        let slot_idx = idx / N::BITS_COUNT;
+        let bit_idx = idx % N::BITS_COUNT;
+        let count = usize::min(N::BITS_COUNT - bit_idx, rest.len());
+
+        let slot = data.get_slot(slot_idx);
+        let (chunk, next) = rest.split_at_mut(count);
+        for (j, bit) in chunk.iter_mut().enumerate() {
+            *bit = B::get(slot, bit_idx + j);
+        }
+
+        idx += count;
+        rest = next;
+    }
+
+    for bit in tail {
+        *bit = false;
+    }
+}
+
+/// Collects bytes produced by `to_byte_vec` back into slots: `k`-th byte holds bits `8k..8k + 8`
+/// placed according to `BitAccess`.
+pub(crate) fn slots_from_bytes_impl<N, B>(bytes: &[u8]) -> Vec<N>
+where
+    N: Number,
+    B: BitAccess,
+{
+    let bits_count = bytes.len() * 8;
+    let mut res = vec![N::ZERO; (bits_count + N::BITS_COUNT - 1) / N::BITS_COUNT];
+    for (byte_idx, &byte) in bytes.iter().enumerate() {
+        for j in (0..8).filter(|&j| B::get(byte, j)) {
+            let idx = byte_idx * 8 + j;
+            let slot = &mut res[idx / N::BITS_COUNT];
+            *slot = B::set(*slot, idx % N::BITS_COUNT, true);
+        }
+    }
+    res
+}
+
+/// Sets bits `range` to `val`. Every affected slot is read and written only once,
+/// slots fully covered by the range are overwritten without reading.
+///
+/// `range.end` must be less or equal to `bits_count()`.
+pub(crate) fn set_range_impl<D, N, B>(data: &mut D, range: Range<usize>, val: bool)
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let mut idx = range.start;
+    while idx < range.end {
+        let slot_idx = idx / N::BITS_COUNT;
+        let bit_idx = idx % N::BITS_COUNT;
+        let count = usize::min(N::BITS_COUNT - bit_idx, range.end - idx);
+
+        let slot = data.get_mut_slot(slot_idx);
+        if count == N::BITS_COUNT {
+            *slot = if val { N::MAX } else { N::ZERO };
+        } else {
+            let mask = B::shift_to_last(low_mask::<N, B>(count), bit_idx);
+            *slot = if val { *slot | mask } else { *slot & !mask };
+        }
+
+        idx += count;
+    }
+}
+
+/// Creates `ceil(bits_count / BITS_COUNT)` slots with bits `range` set. Slots fully covered
+/// by the range are filled with `MAX`, partially covered ones get a shifted mask.
+pub(crate) fn mask_slots_impl<N, B>(bits_count: usize, range: Range<usize>) -> Vec<N>
+where
+    N: Number,
+    B: BitAccess,
+{
+    assert!(
+        range.start <= range.end && range.end <= bits_count,
+        "range {:?} is out of 0..{}",
+        range,
+        bits_count
+    );
+
+    let slots_count = (bits_count + N::BITS_COUNT - 1) / N::BITS_COUNT;
+    let mut res = vec![N::ZERO; slots_count];
+    for (slot_idx, slot) in res.iter_mut().enumerate() {
+        let slot_start = slot_idx * N::BITS_COUNT;
+        let slot_end = slot_start + N::BITS_COUNT;
+        let lo = range.start.clamp(slot_start, slot_end) - slot_start;
+        let hi = range.end.clamp(slot_start, slot_end) - slot_start;
+        *slot = B::shift_to_last(low_mask::<N, B>(hi - lo), lo);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LSB, MSB};
+
+    #[test]
+    fn set_bits_from() {
+        let mut data = [0u8; 3];
+        set_bits_from_impl::<_, _, LSB>(&mut data, 6, &[true, true, false, true]);
+        assert_eq!(data, [0b1100_0000, 0b0000_0010, 0]);
+
+        let mut data = [0xFFu8; 3];
+        set_bits_from_impl::<_, _, MSB>(&mut data, 4, &[false; 12]);
+        assert_eq!(data, [0b1111_0000, 0, 0xFF]);
+
+        set_bits_from_impl::<_, _, MSB>(&mut data, 24, &[]);
+        assert_eq!(data, [0b1111_0000, 0, 0xFF]);
+    }
+
+    #[test]
+    fn get_bits_into() {
+        let data = [0b1100_0000u8, 0b0000_0010];
+        let mut out = [true; 6];
+        get_bits_into_impl::<_, _, LSB>(&data, 6, &mut out);
+        assert_eq!(out, [true, true, false, true, false, false]);
+
+        let mut out = [true; 4];
+        get_bits_into_impl::<_, _, LSB>(&data, 14, &mut out);
+        assert_eq!(out, [false; 4]);
+
+        let mut out = [true; 4];
+        get_bits_into_impl::<_, _, MSB>(&data, 20, &mut out);
+        assert_eq!(out, [false; 4]);
+    }
+}
@@ -0,0 +1,95 @@
+//! Functions for accessing single bits in a slice of slots without constructing a bitmap.
+//!
+//! They use the same index math as the bitmaps: bit `idx` is the bit `idx % N::BITS_COUNT`
+//! (according to `B`) of the slot `idx / N::BITS_COUNT`.
+
+use crate::{
+    container::{ContainerRead, ContainerWrite},
+    number::Number,
+    BitAccess, OutOfBoundsError,
+};
+
+/// Gets single bit state. Out of bounds bits always return `false`.
+///
+/// Usage example:
+/// ```
+/// use bitmac::{raw, LSB};
+///
+/// let slots = [0b0000_0001u8, 0b0000_1000];
+/// assert!(raw::get_bit::<_, LSB>(&slots, 0));
+/// assert!(raw::get_bit::<_, LSB>(&slots, 11));
+/// assert!(!raw::get_bit::<_, LSB>(&slots, 13));
+/// assert!(!raw::get_bit::<_, LSB>(&slots, 128));
+/// ```
+pub fn get_bit<N, B>(slots: &[N], idx: usize) -> bool
+where
+    N: Number,
+    B: BitAccess,
+{
+    <&[N] as ContainerRead<B>>::get_bit(&slots, idx)
+}
+
+/// Sets new state for a single bit.
+///
+/// ## Panic
+///
+/// Panics if `idx` is out of bounds.
+/// See non-panic function [`try_set_bit`].
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{raw, LSB};
+///
+/// let mut slots = [0b0000_0001u8, 0b0000_1000];
+/// raw::set_bit::<_, LSB>(&mut slots, 0, false);
+/// raw::set_bit::<_, LSB>(&mut slots, 9, true);
+/// assert_eq!(slots, [0b0000_0000, 0b0000_1010]);
+/// ```
+///
+/// [`try_set_bit`]: crate::raw::try_set_bit
+pub fn set_bit<N, B>(slots: &mut [N], idx: usize, val: bool)
+where
+    N: Number,
+    B: BitAccess,
+{
+    try_set_bit::<N, B>(slots, idx, val).unwrap();
+}
+
+/// Sets new state for a single bit.
+///
+/// Returns `Err(_)` if `idx` is out of bounds.
+pub fn try_set_bit<N, B>(mut slots: &mut [N], idx: usize, val: bool) -> Result<(), OutOfBoundsError>
+where
+    N: Number,
+    B: BitAccess,
+{
+    <&mut [N] as ContainerWrite<B>>::try_set_bit(&mut slots, idx, val)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{StaticBitmap, LSB, MSB};
+
+    #[test]
+    fn agrees_with_bitmap() {
+        let slots = [0b1010_0110u16, 0b0111_0001_1100_1011];
+        let lsb = StaticBitmap::<_, LSB>::new(slots);
+        let msb = StaticBitmap::<_, MSB>::new(slots);
+        for i in 0..40 {
+            assert_eq!(get_bit::<_, LSB>(&slots, i), lsb.get(i));
+            assert_eq!(get_bit::<_, MSB>(&slots, i), msb.get(i));
+        }
+
+        let mut raw_slots = [0u32; 2];
+        let mut bitmap = StaticBitmap::<[u32; 2], MSB>::default();
+        for i in (0..64).step_by(3) {
+            set_bit::<_, MSB>(&mut raw_slots, i, true);
+            bitmap.set(i, true);
+        }
+        set_bit::<_, MSB>(&mut raw_slots, 9, false);
+        bitmap.set(9, false);
+        assert_eq!(&raw_slots, bitmap.as_ref());
+        assert!(try_set_bit::<_, MSB>(&mut raw_slots, 64, true).is_err());
+    }
+}
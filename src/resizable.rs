@@ -1,10 +1,30 @@
-use crate::number::Number;
+use crate::{number::Number, ResizeError};
 
 pub trait Resizable {
     type Slot: Number;
 
     /// Resizes the `Self` in-place so that `len` is equal to `new_len`.
     fn resize(&mut self, new_len: usize, value: Self::Slot);
+
+    /// Tries to reserve capacity for at least `additional` more slots without changing length.
+    ///
+    /// Returns `Err(_)` if allocation fails. The default implementation does nothing,
+    /// containers that support fallible allocation should override it.
+    #[inline]
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ResizeError> {
+        let _ = additional;
+        Ok(())
+    }
+
+    /// Tries to reserve capacity for exactly `additional` more slots without changing length.
+    ///
+    /// Returns `Err(_)` if allocation fails. The default implementation forwards to [`try_reserve`].
+    ///
+    /// [`try_reserve`]: crate::resizable::Resizable::try_reserve
+    #[inline]
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ResizeError> {
+        self.try_reserve(additional)
+    }
 }
 
 impl<N> Resizable for Vec<N>
@@ -17,6 +37,16 @@ where
     fn resize(&mut self, new_len: usize, value: Self::Slot) {
         Vec::resize(self, new_len, value);
     }
+
+    #[inline]
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ResizeError> {
+        Vec::try_reserve(self, additional).map_err(|e| ResizeError::new(e.to_string()))
+    }
+
+    #[inline]
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ResizeError> {
+        Vec::try_reserve_exact(self, additional).map_err(|e| ResizeError::new(e.to_string()))
+    }
 }
 
 #[cfg(feature = "bytes")]
@@ -41,4 +71,16 @@ where
     fn resize(&mut self, new_len: usize, value: Self::Slot) {
         smallvec::SmallVec::resize(self, new_len, value);
     }
+
+    #[inline]
+    fn try_reserve(&mut self, additional: usize) -> Result<(), ResizeError> {
+        smallvec::SmallVec::try_reserve(self, additional)
+            .map_err(|e| ResizeError::new(format!("{:?}", e)))
+    }
+
+    #[inline]
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), ResizeError> {
+        smallvec::SmallVec::try_reserve_exact(self, additional)
+            .map_err(|e| ResizeError::new(format!("{:?}", e)))
+    }
 }
@@ -0,0 +1,124 @@
+use crate::{container::ContainerWrite, number::Number, BitAccess};
+
+/// Returns mask with bits `0..count` set.
+#[inline]
+pub(crate) fn low_mask<N, B>(count: usize) -> N
+where
+    N: Number,
+    B: BitAccess,
+{
+    if count == 0 {
+        N::ZERO
+    } else {
+        B::shift_to_first(N::MAX, N::BITS_COUNT - count)
+    }
+}
+
+/// Removes bit `idx` and shifts all higher bits down by one. The last bit becomes `0`.
+/// Returns state of removed bit.
+///
+/// `idx` must be less than `bits_count()`.
+pub(crate) fn remove_bit_impl<D, N, B>(data: &mut D, idx: usize) -> bool
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let slots_count = data.slots_count();
+    let slot_idx = idx / N::BITS_COUNT;
+    let bit_idx = idx % N::BITS_COUNT;
+    let last_bit_idx = N::BITS_COUNT - 1;
+
+    let slot = data.get_slot(slot_idx);
+    let removed = B::get(slot, bit_idx);
+    let low = low_mask::<N, B>(bit_idx);
+    let mut new_slot = (slot & low) | (B::shift_to_first(slot, 1) & !low);
+
+    for i in slot_idx + 1..slots_count {
+        let next_slot = data.get_slot(i);
+        *data.get_mut_slot(i - 1) = B::set(new_slot, last_bit_idx, B::get(next_slot, 0));
+        new_slot = B::shift_to_first(next_slot, 1);
+    }
+    *data.get_mut_slot(slots_count - 1) = new_slot;
+
+    removed
+}
+
+/// Inserts bit `idx` and shifts all bits from `idx` up by one.
+/// Returns state of the last bit that was shifted out of container.
+///
+/// `idx` must be less than `bits_count()`.
+pub(crate) fn insert_bit_impl<D, N, B>(data: &mut D, idx: usize, val: bool) -> bool
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let slots_count = data.slots_count();
+    let slot_idx = idx / N::BITS_COUNT;
+    let bit_idx = idx % N::BITS_COUNT;
+    let last_bit_idx = N::BITS_COUNT - 1;
+
+    let slot = data.get_slot(slot_idx);
+    let mut carry = B::get(slot, last_bit_idx);
+    let low = low_mask::<N, B>(bit_idx);
+    let new_slot = (slot & low) | (B::shift_to_last(slot, 1) & !low);
+    *data.get_mut_slot(slot_idx) = B::set(new_slot, bit_idx, val);
+
+    for i in slot_idx + 1..slots_count {
+        let slot = data.get_slot(i);
+        let next_carry = B::get(slot, last_bit_idx);
+        *data.get_mut_slot(i) = B::set(B::shift_to_last(slot, 1), 0, carry);
+        carry = next_carry;
+    }
+
+    carry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container::ContainerRead, LSB, MSB};
+
+    fn to_bits<B: BitAccess>(v: &[u8]) -> Vec<bool> {
+        (0..v.len() * 8)
+            .map(|i| <&[u8] as ContainerRead<B>>::get_bit(&v, i))
+            .collect()
+    }
+
+    fn check<B: BitAccess>(src: [u8; 3]) {
+        for idx in 0..24 {
+            let mut exp = to_bits::<B>(&src);
+            let exp_removed = exp.remove(idx);
+            exp.push(false);
+            let mut v = src;
+            let removed = remove_bit_impl::<_, _, B>(&mut v, idx);
+            assert_eq!(removed, exp_removed);
+            assert_eq!(to_bits::<B>(&v), exp);
+
+            for val in [false, true] {
+                let mut exp = to_bits::<B>(&src);
+                exp.insert(idx, val);
+                let exp_carry = exp.pop().unwrap();
+                let mut v = src;
+                let carry = insert_bit_impl::<_, _, B>(&mut v, idx, val);
+                assert_eq!(carry, exp_carry);
+                assert_eq!(to_bits::<B>(&v), exp);
+            }
+        }
+    }
+
+    #[test]
+    fn remove_insert() {
+        let mut v = 0b1101u8;
+        assert!(remove_bit_impl::<_, _, LSB>(&mut v, 2));
+        assert_eq!(v, 0b0101);
+        assert!(!insert_bit_impl::<_, _, LSB>(&mut v, 2, true));
+        assert_eq!(v, 0b1101);
+
+        check::<LSB>([0b1010_0110, 0b0111_0001, 0b1100_1011]);
+        check::<MSB>([0b1010_0110, 0b0111_0001, 0b1100_1011]);
+        check::<LSB>([0b1111_1111, 0b0000_0000, 0b1000_0001]);
+        check::<MSB>([0b1111_1111, 0b0000_0000, 0b1000_0001]);
+    }
+}
@@ -0,0 +1,141 @@
+use std::marker::PhantomData;
+
+use crate::{container::ContainerRead, number::Number, BitAccess};
+
+/// A read-only view over a container that starts at a bit offset.
+///
+/// The 0th bit of the view is the `offset`-th bit of the source container, so the view can be used
+/// to combine bitmaps aligned at arbitrary bit (not slot) offset. Every slot of the view is
+/// synthesized from two adjacent source slots. Bits after the end of the source are `0`.
+///
+/// Usage example:
+/// ```
+/// use bitmac::{shifted_view::ShiftedView, BitOp, BitmapOps, StaticBitmap, LSB};
+///
+/// let lhs = StaticBitmap::<_, LSB>::new([0b0000_0101u8, 0b0000_0000]);
+/// let rhs = [0b0010_1000u8, 0b0000_0001];
+/// let view = ShiftedView::<_, LSB>::new(&rhs, 3);
+/// assert_eq!(lhs.combine::<Vec<u8>>(&view, BitOp::And), vec![0b0000_0101, 0b0000_0000]);
+/// ```
+pub struct ShiftedView<'a, D, B> {
+    data: &'a D,
+    offset: usize,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, D, B> ShiftedView<'a, D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    /// Creates new view that starts at `offset` bit of container.
+    pub fn new(data: &'a D, offset: usize) -> Self {
+        Self {
+            data,
+            offset,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Returns the bit offset of the view.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<D, B, N> ContainerRead<B> for ShiftedView<'_, D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    N: Number,
+{
+    type Slot = N;
+
+    fn get_slot(&self, idx: usize) -> Self::Slot {
+        let first_bit = self.offset + idx * N::BITS_COUNT;
+        let src_idx = first_bit / N::BITS_COUNT;
+        let shift = first_bit % N::BITS_COUNT;
+
+        let lo = B::shift_to_first(self.data.get_slot(src_idx), shift);
+        if shift == 0 || src_idx + 1 >= self.data.slots_count() {
+            lo
+        } else {
+            let hi = B::shift_to_last(self.data.get_slot(src_idx + 1), N::BITS_COUNT - shift);
+            lo | hi
+        }
+    }
+
+    fn slots_count(&self) -> usize {
+        let bits_count = self.data.bits_count().saturating_sub(self.offset);
+        (bits_count + N::BITS_COUNT - 1) / N::BITS_COUNT
+    }
+
+    fn get_bit(&self, idx: usize) -> bool {
+        match idx.checked_add(self.offset) {
+            Some(idx) => self.data.get_bit(idx),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BitOp, BitmapOps, StaticBitmap, LSB, MSB};
+
+    fn check_view<B: BitAccess>(src: &[u8], offset: usize) {
+        let view = ShiftedView::<_, B>::new(&src, offset);
+        let bits_count = (src.len() * 8).saturating_sub(offset);
+        assert_eq!(view.slots_count(), (bits_count + 7) / 8);
+        for i in 0..view.bits_count() {
+            let exp = i + offset < src.len() * 8
+                && <&[u8] as ContainerRead<B>>::get_bit(&src, i + offset);
+            assert_eq!(view.get_bit(i), exp);
+            let slot = view.get_slot(i / 8);
+            assert_eq!(B::get(slot, i % 8), exp);
+        }
+    }
+
+    #[test]
+    fn view_bits() {
+        let src = [0b1010_0110u8, 0b0111_0001, 0b1100_1011];
+        for offset in 0..30 {
+            check_view::<LSB>(&src, offset);
+            check_view::<MSB>(&src, offset);
+        }
+    }
+
+    #[test]
+    fn intersection_with_shifted() {
+        // Bits 2, 5, 11 of `rhs` are bits 0, 3, 9 of `lhs` after 2-bit shift
+        let lhs = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0010]);
+        let rhs = [0b0010_0100u8, 0b0000_1000];
+
+        let view = ShiftedView::<_, LSB>::new(&rhs, 2);
+        assert_eq!(
+            lhs.combine::<Vec<u8>>(&view, BitOp::And),
+            vec![0b0000_1001, 0b0000_0010]
+        );
+        assert_eq!(lhs.combine_len(&view, BitOp::And), 3);
+
+        // With 3-bit shift bits 5, 11 of `rhs` become bits 2, 8 of view, only bit 2 matches `lhs`
+        let lhs = StaticBitmap::<_, LSB>::new([0b0000_0101u8, 0b0000_0000]);
+        let view = ShiftedView::<_, LSB>::new(&rhs, 3);
+        assert_eq!(
+            lhs.combine::<Vec<u8>>(&view, BitOp::And),
+            vec![0b0000_0100, 0b0000_0000]
+        );
+        assert_eq!(
+            lhs.combine::<Vec<u8>>(&view, BitOp::Or),
+            vec![0b0000_0101, 0b0000_0001]
+        );
+
+        let lhs = StaticBitmap::<_, MSB>::new([0b1001_0000u8, 0b0100_0000]);
+        let rhs = [0b0010_0100u8, 0b0001_0000];
+        let view = ShiftedView::<_, MSB>::new(&rhs, 2);
+        assert_eq!(
+            lhs.combine::<Vec<u8>>(&view, BitOp::And),
+            vec![0b1001_0000, 0b0100_0000]
+        );
+    }
+}
@@ -1,18 +1,45 @@
 use std::{
+    collections::BTreeSet,
     fmt::{Debug, Formatter},
+    iter::Enumerate,
     marker::PhantomData,
+    ops::{BitAndAssign, BitOr, Index, IndexMut, Range, Sub},
 };
 
+#[cfg(feature = "rayon")]
+use crate::par::{par_count_ones_impl, par_intersection_len_impl};
 use crate::{
-    container::{ContainerRead, ContainerWrite},
+    bit_slice::BitSlice,
+    combine::{
+        combine_into_impl, combine_len_impl, try_combine_impl, try_combine_in_impl, BitOp,
+        BitmapOps, CombinedOnes,
+    },
+    compress::{compress_impl, decompress_impl},
+    container::{validate_impl, ContainerRead, ContainerWrite},
+    debug::{DebugBytes, DebugLogical},
+    grow_strategy::GrowStrategy,
     intersection::{
-        intersection_len_impl, try_intersection_impl, try_intersection_in_impl, Intersection,
+        intersection_into_resized_impl, intersection_is_empty_impl, intersection_len_impl,
+        try_intersection_impl,
+    },
+    iter::{Gaps, IndexedBits, IntoIter, Iter, Ones, SlidingPopcount, SlotBytes},
+    number::{Endianness, Number},
+    ordering::{
+        bit_len_impl, bits_eq_impl, compare_impl, count_ones_impl, count_ones_up_to_impl,
+        ones_summary_impl, BitmapOrdering, OnesSummary,
+    },
+    random::fill_pseudo_random_impl,
+    range::{get_bits_into_impl, set_bits_from_impl, set_range_impl, slots_from_bytes_impl},
+    resizable::Resizable,
+    shift::{insert_bit_impl, low_mask, remove_bit_impl},
+    union::{try_union_impl, union_into_resized_impl},
+    update::{
+        difference_len_impl, difference_update_impl, intersect_update_impl, invert_impl,
+        invert_to_len_impl, symmetric_difference_update_impl, union_update_impl,
     },
-    iter::{IntoIter, Iter},
-    number::Number,
-    union::{try_union_impl, try_union_in_impl, union_len_impl, Union},
     with_slots::TryWithSlots,
-    BitAccess, IntersectionError, OutOfBoundsError, UnionError, WithSlotsError,
+    BitAccess, BitOrder, CombineError, DecompressError, OutOfBoundsError, VarBitmap,
+    WithSlotsError,
 };
 
 /// A bitmap that cannot be resized.
@@ -43,19 +70,19 @@ use crate::{
 /// assert_eq!(iter.next(), Some((4, false)));
 ///
 /// // You can check multiple bits at the same time through the intersection
-/// use bitmac::Intersection;
+/// use bitmac::{BitOp, BitmapOps};
 /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_1000]);
 /// // .. by creating specific new container for result
 /// let test = [0b0000_1001u8, 0b0000_0000];
-/// assert_eq!(bitmap.intersection::<[u8; 2]>(&test), test);
+/// assert_eq!(bitmap.combine::<[u8; 2]>(&test, BitOp::And), test);
 /// // .. by using preallocated container for result
 /// let test = [0b0000_1001u8, 0b0000_0000];
 /// let mut result = [0u8; 2];
-/// bitmap.intersection_in(&test, &mut result);
+/// bitmap.combine_in(&test, BitOp::And, &mut result);
 /// assert_eq!(result, test);
 /// // .. by comparing length of difference that is equivalent to count of ones (bits) in result
 /// let test = [0b0000_1001u8, 0b0000_0000];
-/// assert_eq!(bitmap.intersection_len(&test), test.iter().fold(0, |acc, &v| acc + v.count_ones() as usize));
+/// assert_eq!(bitmap.combine_len(&test, BitOp::And), test.iter().fold(0, |acc, &v| acc + v.count_ones() as usize));
 ///
 /// // You can directly change every single bit
 /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0001_1000]);
@@ -94,396 +121,2283 @@ where
         }
     }
 
+    /// Returns number of bits in a single slot.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{BitOrder, StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0u16; 2]);
+    /// assert_eq!(bitmap.slot_bits(), 16);
+    /// assert_eq!(bitmap.bit_order(), BitOrder::LSB);
+    /// ```
+    pub fn slot_bits(&self) -> usize {
+        N::BITS_COUNT
+    }
+
+    /// Returns order of bits in slots, see [`BitAccess::ORDER`].
+    ///
+    /// [`BitAccess::ORDER`]: crate::bit_access::BitAccess::ORDER
+    pub fn bit_order(&self) -> BitOrder {
+        B::ORDER
+    }
+
     /// Returns number of ones in the bitmap.
     pub fn count_ones(&self) -> usize {
-        let mut res = 0;
-        for v in self.iter() {
-            res += v.count_ones() as usize;
-        }
-        res
+        count_ones_impl(&self.data)
     }
 
-    /// Returns number of zeros in the bitmap.
-    pub fn count_zeros(&self) -> usize {
-        let mut res = 0;
+    /// Returns `true` if the bitmap has at least `n` ones. Stops counting as soon as `n` is reached.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b1111_0000u8, 0b0000_0011]);
+    /// assert!(bitmap.has_at_least_ones(6));
+    /// assert!(!bitmap.has_at_least_ones(7));
+    /// ```
+    pub fn has_at_least_ones(&self, n: usize) -> bool {
+        count_ones_up_to_impl(&self.data, n) >= n
+    }
+
+    /// Returns `true` if the bitmap has at most `n` ones. Stops counting as soon as `n` is exceeded.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b1111_0000u8, 0b0000_0011]);
+    /// assert!(bitmap.has_at_most_ones(6));
+    /// assert!(!bitmap.has_at_most_ones(5));
+    /// ```
+    pub fn has_at_most_ones(&self, n: usize) -> bool {
+        count_ones_up_to_impl(&self.data, n.saturating_add(1)) <= n
+    }
+
+    /// Returns number of ones in slots `0..i` for every slot index `i` (prefix sums of ones).
+    ///
+    /// Rank of any bit can be computed from it by counting ones only in the bit's own slot.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0001, 0b1111_0000]);
+    /// assert_eq!(bitmap.slot_rank_prefix(), vec![0, 2, 3]);
+    /// ```
+    pub fn slot_rank_prefix(&self) -> Vec<usize> {
+        let mut res = Vec::with_capacity(self.data.slots_count());
+        let mut rank = 0;
         for v in self.iter() {
-            res += v.count_zeros() as usize;
+            res.push(rank);
+            rank += v.count_ones() as usize;
         }
         res
     }
-}
 
-impl<D, B> StaticBitmap<D, B> {
-    /// Converts bitmap into inner container.
-    pub fn into_inner(self) -> D {
-        self.data
+    /// Fills `out[j]` with state of bit `start + j`. Out of bounds bits are `false`.
+    ///
+    /// Unlike [`to_bool_vec`], it doesn't allocate, so the buffer can be reused.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0100_0000u8, 0b0000_0001]);
+    /// let mut out = [false; 4];
+    /// bitmap.get_bits_into(6, &mut out);
+    /// assert_eq!(out, [true, false, true, false]);
+    /// bitmap.get_bits_into(14, &mut out);
+    /// assert_eq!(out, [false; 4]);
+    /// ```
+    ///
+    /// [`to_bool_vec`]: crate::static_bitmap::StaticBitmap::to_bool_vec
+    pub fn get_bits_into(&self, start: usize, out: &mut [bool]) {
+        get_bits_into_impl(&self.data, start, out);
     }
-}
 
-impl<D, B> StaticBitmap<D, B>
-where
-    D: ContainerRead<B>,
-    B: BitAccess,
-{
-    /// Gets single bit state.
+    /// Collects every bit up to [`bits_count`] into a vector, `i`-th element is state of `i`-th bit.
     ///
     /// Usage example:
     /// ```
     /// use bitmac::{StaticBitmap, LSB};
     ///
-    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001u8, 0b0000_1000]);
-    /// assert!(bitmap.get(0));
-    /// assert!(bitmap.get(11));
-    /// assert!(!bitmap.get(13));
-    /// // Out of bounds bits always return false
-    /// assert!(!bitmap.get(128));
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001_0000_1001u16]);
+    /// let bits = bitmap.to_bool_vec();
+    /// assert_eq!(bits.len(), 16);
+    /// assert_eq!(&bits[..9], &[true, false, false, true, false, false, false, false, true]);
     /// ```
-    pub fn get(&self, idx: usize) -> bool {
-        self.data.get_bit(idx)
+    ///
+    /// [`bits_count`]: crate::container::ContainerRead::bits_count
+    pub fn to_bool_vec(&self) -> Vec<bool> {
+        (0..self.data.bits_count())
+            .map(|idx| self.data.get_bit(idx))
+            .collect()
     }
 
-    /// Returns iterator over slots.
-    pub fn iter(&self) -> Iter<'_, D, B> {
-        Iter::new(&self.data)
+    /// Collects bits into bytes regardless of slot width: `k`-th byte contains bits `8k..8k + 8`
+    /// placed according to `BitAccess`. So the result is the same bitmap over `u8` slots.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001_0000_1001u16]);
+    /// assert_eq!(bitmap.to_byte_vec(), vec![0b0000_1001, 0b0000_0001]);
+    /// ```
+    pub fn to_byte_vec(&self) -> Vec<u8> {
+        let bits_count = self.data.bits_count();
+        let mut res = vec![0u8; (bits_count + 7) / 8];
+        for idx in self.ones() {
+            if idx >= bits_count {
+                break;
+            }
+            let byte = &mut res[idx / 8];
+            *byte = B::set(*byte, idx % 8, true);
+        }
+        res
     }
-}
 
-impl<D, B> StaticBitmap<D, B>
-where
-    D: ContainerWrite<B>,
-    B: BitAccess,
-{
-    /// Sets new state for a single bit.
+    /// Compresses bytes in [`to_byte_vec`] layout with run-length encoding.
     ///
-    /// ## Panic
-    ///
-    /// Panics if `idx` is out of bounds.
-    /// See non-panic function [`try_set`].
+    /// The result is a sequence of two-byte runs: the number of repeats (`1..=255`)
+    /// and the repeated byte. It doesn't depend on slot width and is much smaller than raw bytes
+    /// for mostly-zero bitmaps. Use [`from_compressed`] to restore the bitmap.
     ///
-    /// ## Usage example:
+    /// Usage example:
     /// ```
     /// use bitmac::{StaticBitmap, LSB};
     ///
-    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0001_1000]);
-    /// bitmap.set(12, false);
-    /// assert!(!bitmap.get(12));
-    /// bitmap.set(13, true);
-    /// assert!(bitmap.get(13));
+    /// let bitmap = StaticBitmap::<_, LSB>::new(vec![0u8; 64]) | 100;
+    /// let compressed = bitmap.to_compressed();
+    /// assert_eq!(compressed, vec![12, 0, 1, 0b0001_0000, 51, 0]);
+    /// assert_eq!(StaticBitmap::<Vec<u8>, LSB>::from_compressed(&compressed).unwrap(), bitmap);
     /// ```
     ///
-    /// [`try_set`]: crate::static_bitmap::StaticBitmap::try_set
-    pub fn set(&mut self, idx: usize, val: bool) {
-        self.try_set(idx, val).unwrap();
+    /// [`to_byte_vec`]: crate::static_bitmap::StaticBitmap::to_byte_vec
+    /// [`from_compressed`]: crate::static_bitmap::StaticBitmap::from_compressed
+    pub fn to_compressed(&self) -> Vec<u8> {
+        compress_impl(&self.to_byte_vec())
     }
 
-    /// Sets new state for a single bit.
-    ///
-    /// Returns `Err(_)` if `idx` is out of bounds.
+    /// Same as [`to_byte_vec`], but the result has exactly `byte_len` bytes: it is zero-padded
+    /// if the bitmap is shorter and truncated if it is longer. Bits beyond `byte_len * 8` are
+    /// dropped even if they are set, check [`bit_len`] beforehand if they matter.
     ///
-    /// ## Usage example:
+    /// Usage example:
     /// ```
     /// use bitmac::{StaticBitmap, LSB};
     ///
-    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0001_1000]);
-    /// assert!(bitmap.try_set(12, true).is_ok());
-    /// assert!(bitmap.get(12));
-    /// assert!(bitmap.try_set(12, false).is_ok());
-    /// assert!(!bitmap.get(12));
-    /// // Out of bounds bits return error
-    /// assert!(bitmap.try_set(128, true).is_err());
-    /// assert!(!bitmap.get(128));
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001_0000_1001u16]);
+    /// assert_eq!(bitmap.to_bytes_padded(3), vec![0b0000_1001, 0b0000_0001, 0]);
+    /// assert_eq!(bitmap.to_bytes_padded(1), vec![0b0000_1001]);
     /// ```
-    pub fn try_set(&mut self, idx: usize, val: bool) -> Result<(), OutOfBoundsError> {
-        self.data.try_set_bit(idx, val)
+    ///
+    /// [`to_byte_vec`]: crate::static_bitmap::StaticBitmap::to_byte_vec
+    /// [`bit_len`]: crate::static_bitmap::StaticBitmap::bit_len
+    pub fn to_bytes_padded(&self, byte_len: usize) -> Vec<u8> {
+        let mut res = self.to_byte_vec();
+        res.resize(byte_len, 0);
+        res
     }
-}
 
-impl<D, B> AsRef<D> for StaticBitmap<D, B> {
-    fn as_ref(&self) -> &D {
-        &self.data
+    /// Collects raw bytes of every slot, bytes of a slot are in specified order.
+    ///
+    /// Unlike [`to_byte_vec`], it doesn't reorder bits, so the result depends on slot width.
+    /// [`Endianness::Little`] matches `Debug` output, [`Endianness::Big`] matches big-endian
+    /// wire formats.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, Endianness, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0xAABBu16, 0x0102]);
+    /// assert_eq!(bitmap.to_slot_bytes(Endianness::Big), vec![0xAA, 0xBB, 0x01, 0x02]);
+    /// assert_eq!(bitmap.to_slot_bytes(Endianness::Little), vec![0xBB, 0xAA, 0x02, 0x01]);
+    /// ```
+    ///
+    /// [`to_byte_vec`]: crate::static_bitmap::StaticBitmap::to_byte_vec
+    /// [`Endianness::Little`]: crate::number::Endianness::Little
+    /// [`Endianness::Big`]: crate::number::Endianness::Big
+    pub fn to_slot_bytes(&self, endianness: Endianness) -> Vec<u8> {
+        let mut res = Vec::with_capacity(self.data.slots_count().saturating_mul(N::BYTES_COUNT));
+        for slot in self.iter() {
+            res.extend(endianness.slot_bytes(slot));
+        }
+        res
     }
-}
 
-impl<D, B> AsMut<D> for StaticBitmap<D, B> {
-    fn as_mut(&mut self) -> &mut D {
-        &mut self.data
+    /// Returns iterator over raw bytes of every slot, bytes of a slot are in specified order.
+    ///
+    /// It is a lazy version of [`to_slot_bytes`], useful to stream the bitmap into a writer
+    /// without collecting bytes first.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, Endianness, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0xAABBCCDDu32]);
+    /// let bytes = bitmap.by_bytes_endian(Endianness::Big).collect::<Vec<_>>();
+    /// assert_eq!(bytes, 0xAABBCCDDu32.to_be_bytes());
+    /// ```
+    ///
+    /// [`to_slot_bytes`]: crate::static_bitmap::StaticBitmap::to_slot_bytes
+    pub fn by_bytes_endian(&self, endianness: Endianness) -> SlotBytes<'_, D, B> {
+        SlotBytes::new(&self.data, endianness)
     }
-}
 
-impl<D, B> ContainerRead<B> for StaticBitmap<D, B>
-where
-    D: ContainerRead<B>,
-    B: BitAccess,
-{
-    type Slot = D::Slot;
+    /// Returns logical length of the bitmap: index of the last set bit plus one,
+    /// or `0` if no bit is set. Trailing zero slots are not counted.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// assert_eq!(StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0]).bit_len(), 4);
+    /// assert_eq!(StaticBitmap::<_, LSB>::new([0u8, 0]).bit_len(), 0);
+    /// ```
+    pub fn bit_len(&self) -> usize {
+        bit_len_impl(&self.data)
+    }
 
-    fn get_slot(&self, idx: usize) -> Self::Slot {
-        self.data.get_slot(idx)
+    /// Returns number of set bits with indexes of the first and the last of them,
+    /// computed in a single pass over slots.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{OnesSummary, StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1010u8, 0, 0b0000_0001]);
+    /// assert_eq!(
+    ///     bitmap.ones_summary(),
+    ///     OnesSummary { count: 3, first: Some(1), last: Some(16) }
+    /// );
+    /// ```
+    pub fn ones_summary(&self) -> OnesSummary {
+        ones_summary_impl(&self.data)
     }
 
-    fn slots_count(&self) -> usize {
-        self.data.slots_count()
+    /// Returns `true` if no bit is set. Stops at the first non-zero slot.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// assert!(StaticBitmap::<_, LSB>::new([0u8; 2]).is_empty());
+    /// assert!(!StaticBitmap::<_, LSB>::new([0u8, 0b0000_0100]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.first_nonzero_slot().is_none()
     }
-}
 
-impl<D, B> ContainerWrite<B> for StaticBitmap<D, B>
-where
-    D: ContainerWrite<B>,
-    B: BitAccess,
-{
-    fn get_mut_slot(&mut self, idx: usize) -> &mut Self::Slot {
-        self.data.get_mut_slot(idx)
+    /// Checks that the container is consistent: `bits_count()` matches `slots_count()`,
+    /// `as_slot_slice` (if any) holds the same slots and every bit read by `get_bit` matches
+    /// its slot. Returns description of the first inconsistency.
+    ///
+    /// It reads every bit of the container, so it is meant as a debugging aid for implementors
+    /// of custom containers, not for regular use.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if the container panics on reading a slot in `0..slots_count()`, e.g. if it
+    /// reports more slots than it stores and doesn't provide `as_slot_slice`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0u8, 0b0000_0100]);
+    /// assert!(bitmap.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        validate_impl(&self.data)
     }
-}
 
-impl<D, B> TryWithSlots for StaticBitmap<D, B>
-where
-    D: TryWithSlots,
-    B: BitAccess,
-{
-    fn try_with_slots(len: usize) -> Result<Self, WithSlotsError> {
-        Ok(Self {
-            data: D::try_with_slots(len)?,
-            phantom: Default::default(),
-        })
+    /// Returns index of the first slot with at least one set bit.
+    ///
+    /// If container is a contiguous slice, zero regions are skipped by blocks of slots.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// assert_eq!(StaticBitmap::<_, LSB>::new([0u8, 0, 0b0000_0100]).first_nonzero_slot(), Some(2));
+    /// assert_eq!(StaticBitmap::<_, LSB>::new([0u8; 3]).first_nonzero_slot(), None);
+    /// ```
+    pub fn first_nonzero_slot(&self) -> Option<usize> {
+        if let Some(slots) = self.data.as_slot_slice() {
+            return N::first_nonzero_in(slots);
+        }
+        (0..self.data.slots_count()).find(|&i| self.data.get_slot(i) != N::ZERO)
     }
-}
 
-impl<D, N, B> Debug for StaticBitmap<D, B>
-where
-    D: ContainerRead<B, Slot = N>,
-    N: Number,
-    B: BitAccess,
-{
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut list = f.debug_list();
-        for i in 0..self.data.slots_count() {
-            let slot = self.data.get_slot(i);
-            for j in 0..N::BYTES_COUNT {
-                let byte = (slot >> (j * 8)) & N::BYTE_MASK;
-                list.entry(&format_args!("{:#010b}", byte));
-            }
+    /// Returns `true` if every bit up to [`bits_count`] is set. Stops at the first slot with zero bit.
+    ///
+    /// Padding bits of the last slot (if container has any) are not checked.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// assert!(StaticBitmap::<_, LSB>::new([0xFFu8; 2]).is_full());
+    /// assert!(!StaticBitmap::<_, LSB>::new([0xFFu8, 0b0111_1111]).is_full());
+    /// ```
+    ///
+    /// [`bits_count`]: crate::container::ContainerRead::bits_count
+    pub fn is_full(&self) -> bool {
+        let bits_count = self.data.bits_count();
+        let full_slots = bits_count / N::BITS_COUNT;
+        let rest_bits = bits_count % N::BITS_COUNT;
+
+        if !(0..full_slots).all(|i| self.data.get_slot(i) == N::MAX) {
+            return false;
+        }
+        if rest_bits == 0 {
+            return true;
         }
-        list.finish()
+        let mask = low_mask::<N, B>(rest_bits);
+        self.data.get_slot(full_slots) & mask == mask
     }
-}
 
-impl<D, N, B> From<D> for StaticBitmap<D, B>
-where
-    D: ContainerRead<B, Slot = N>,
-    N: Number,
-    B: BitAccess,
-{
-    fn from(f: D) -> Self {
-        Self {
-            data: f,
-            phantom: Default::default(),
+    /// Returns number of zeros in the bitmap.
+    pub fn count_zeros(&self) -> usize {
+        let mut res = 0;
+        for v in self.iter() {
+            res += v.count_zeros() as usize;
         }
+        res
     }
-}
 
-impl<D, B> IntoIterator for StaticBitmap<D, B>
-where
-    D: ContainerRead<B>,
-    B: BitAccess,
-{
-    type Item = <IntoIter<D, B> as Iterator>::Item;
-    type IntoIter = IntoIter<D, B>;
+    /// Compares sets of ones of the bitmap and `other` in a single pass.
+    ///
+    /// Containers may have different lengths, missing slots are treated as zeros.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{BitmapOrdering, StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8]);
+    /// assert_eq!(bitmap.compare(&[0b0000_1001u8, 0]), BitmapOrdering::Equal);
+    /// assert_eq!(bitmap.compare(&[0b0000_1011u8]), BitmapOrdering::Subset);
+    /// assert_eq!(bitmap.compare(&[0b0000_0001u8]), BitmapOrdering::Superset);
+    /// assert_eq!(bitmap.compare(&[0b0000_0001u8, 1]), BitmapOrdering::Incomparable);
+    /// ```
+    pub fn compare<Rhs>(&self, other: &Rhs) -> BitmapOrdering
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        compare_impl(&self.data, other)
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter::new(self.data)
+    /// Checks if the bitmap and `other` have the same ones, regardless of container lengths.
+    ///
+    /// Logical lengths (see [`bit_len`]) are compared first, then only slots up to the last
+    /// set bit, stopping at the first different slot. It is faster than comparing
+    /// whole containers for large mostly-zero bitmaps.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0, 0, 0]);
+    /// assert!(bitmap.bits_eq(&[0b0000_1001u8]));
+    /// assert!(!bitmap.bits_eq(&[0b0000_1001u8, 1]));
+    /// ```
+    ///
+    /// [`bit_len`]: crate::static_bitmap::StaticBitmap::bit_len
+    pub fn bits_eq<Rhs>(&self, other: &Rhs) -> bool
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        bits_eq_impl(&self.data, other)
     }
-}
 
-impl<'a, D, B> IntoIterator for &'a StaticBitmap<D, B>
-where
-    D: ContainerRead<B>,
-    B: BitAccess,
-{
-    type Item = <Iter<'a, D, B> as Iterator>::Item;
-    type IntoIter = Iter<'a, D, B>;
+    /// Returns `true` if the bitmap and `other` have no common ones. Unlike checking
+    /// `intersection_len() == 0`, it stops at the first common one and never allocates.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1111u8]);
+    /// assert!(bitmap.intersection_is_empty(&[0b1111_0000u8]));
+    /// assert!(!bitmap.intersection_is_empty(&[0b0000_1000u8, 0b1111_1111]));
+    /// ```
+    pub fn intersection_is_empty<Rhs>(&self, other: &Rhs) -> bool
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        intersection_is_empty_impl(&self.data, other)
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Calculates overlap coefficient: `intersection_len / min(count_ones, other_count_ones)`.
+    ///
+    /// Returns `0.0` if either bitmap has no ones.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1111u8]);
+    /// assert_eq!(bitmap.overlap_coefficient(&[0b0011_0011u8]), 0.5);
+    /// assert_eq!(bitmap.overlap_coefficient(&[0b0000_0011u8]), 1.0);
+    /// assert_eq!(bitmap.overlap_coefficient(&[0u8]), 0.0);
+    /// ```
+    pub fn overlap_coefficient<Rhs>(&self, other: &Rhs) -> f64
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        let denominator = usize::min(self.count_ones(), count_ones_impl(other));
+        if denominator == 0 {
+            return 0.0;
+        }
+        intersection_len_impl(&self.data, other) as f64 / denominator as f64
     }
-}
 
-impl<D, B, Rhs, N> Intersection<Rhs, N, B> for StaticBitmap<D, B>
-where
-    D: ContainerRead<B, Slot = N>,
-    B: BitAccess,
-    Rhs: ContainerRead<B, Slot = N>,
-    N: Number,
-{
-    fn intersection_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst)
+    /// Calculates containment of the bitmap in `other`: `intersection_len / count_ones`,
+    /// i.e. the fraction of ones of the bitmap that are set in `other`.
+    ///
+    /// Returns `0.0` if the bitmap has no ones.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1111u8]);
+    /// assert_eq!(bitmap.containment(&[0b0000_0011u8]), 0.5);
+    /// assert_eq!(bitmap.containment(&[0xFFu8]), 1.0);
+    /// ```
+    pub fn containment<Rhs>(&self, other: &Rhs) -> f64
     where
-        Dst: ContainerWrite<B, Slot = N>,
+        Rhs: ContainerRead<B, Slot = N>,
     {
-        try_intersection_in_impl(&self.data, rhs, dst).unwrap();
+        let denominator = self.count_ones();
+        if denominator == 0 {
+            return 0.0;
+        }
+        intersection_len_impl(&self.data, other) as f64 / denominator as f64
     }
 
-    fn try_intersection_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst) -> Result<(), IntersectionError>
+    /// Calculates difference length - number of ones of the bitmap that are not set in `other`
+    /// (`self & !other`). It doesn't allocate for storing difference result.
+    ///
+    /// Missing slots of `other` are treated as zeros, so all ones of the bitmap beyond
+    /// `other` are counted.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1011u8, 0b0000_0011]);
+    /// assert_eq!(bitmap.difference_len(&[0b0000_0001u8]), 4);
+    /// ```
+    pub fn difference_len<Rhs>(&self, other: &Rhs) -> usize
     where
-        Dst: ContainerWrite<B, Slot = N>,
+        Rhs: ContainerRead<B, Slot = N>,
     {
-        try_intersection_in_impl(&self.data, rhs, dst)
+        difference_len_impl(&self.data, other)
+    }
+
+    /// Copies slots of the bitmap into `dst`, resizing it to the same length.
+    ///
+    /// Allocation of `dst` is reused, so it is cheaper than `clone()` for repeated snapshots,
+    /// like [`Vec::clone_from`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0010]);
+    /// let mut snapshot = StaticBitmap::<Vec<u8>, LSB>::default();
+    /// bitmap.clone_into_bitmap(&mut snapshot);
+    /// assert_eq!(snapshot.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+    /// ```
+    pub fn clone_into_bitmap(&self, dst: &mut StaticBitmap<Vec<N>, B>) {
+        let len = self.data.slots_count();
+        let dst = dst.as_mut();
+        dst.resize(len, N::ZERO);
+        for (i, slot) in dst.iter_mut().enumerate() {
+            *slot = self.data.get_slot(i);
+        }
     }
 
-    fn intersection<Dst>(&self, rhs: &Rhs) -> Dst
+    /// Calculates intersection in-place. Result will be stored in `dst`, which is resized
+    /// to the exact length of result beforehand, so no stale slots remain.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_1000]);
+    /// let mut result = vec![0xFFu8; 4];
+    /// bitmap.intersection_into_resized(&[0b0000_0001u8], &mut result);
+    /// assert_eq!(result, vec![0b0000_0001]);
+    /// ```
+    pub fn intersection_into_resized<Rhs, Dst>(&self, rhs: &Rhs, dst: &mut Dst)
     where
-        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+        Rhs: ContainerRead<B, Slot = N>,
+        Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
     {
-        try_intersection_impl(&self.data, rhs).unwrap()
+        intersection_into_resized_impl(&self.data, rhs, dst);
     }
 
-    fn try_intersection<Dst>(&self, rhs: &Rhs) -> Result<Dst, IntersectionError>
+    /// Calculates intersection into `scratch` vector, which is resized to the exact length
+    /// of result. Capacity of `scratch` is kept, so reusing it across calls doesn't allocate
+    /// once it is large enough. It is [`intersection_into_resized`] specialized for `Vec`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_1000]);
+    /// let mut scratch = Vec::new();
+    /// bitmap.intersection_reuse(&[0b0000_0001u8], &mut scratch);
+    /// assert_eq!(scratch, vec![0b0000_0001]);
+    /// bitmap.intersection_reuse(&[0b0000_1000u8, 0b1111_1111], &mut scratch);
+    /// assert_eq!(scratch, vec![0b0000_1000, 0b0000_1000]);
+    /// ```
+    ///
+    /// [`intersection_into_resized`]: crate::static_bitmap::StaticBitmap::intersection_into_resized
+    pub fn intersection_reuse<Rhs>(&self, rhs: &Rhs, scratch: &mut Vec<N>)
     where
-        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+        Rhs: ContainerRead<B, Slot = N>,
     {
-        try_intersection_impl(&self.data, rhs)
+        intersection_into_resized_impl(&self.data, rhs, scratch);
     }
 
-    fn intersection_len(&self, rhs: &Rhs) -> usize {
-        intersection_len_impl(&self.data, rhs)
+    /// Calculates union in-place. Result will be stored in `dst`, which is resized
+    /// to the exact length of result beforehand, so it doesn't need to be pre-sized.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_1000]);
+    /// let mut result = Vec::new();
+    /// bitmap.union_into_resized(&[0b0000_0010u8, 0, 0b0000_0001], &mut result);
+    /// assert_eq!(result, vec![0b0000_1011, 0b0000_1000, 0b0000_0001]);
+    /// ```
+    pub fn union_into_resized<Rhs, Dst>(&self, rhs: &Rhs, dst: &mut Dst)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+        Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    {
+        union_into_resized_impl(&self.data, rhs, dst);
     }
-}
 
-impl<D, B, Rhs, N> Union<Rhs, N, B> for StaticBitmap<D, B>
-where
-    D: ContainerRead<B, Slot = N>,
-    B: BitAccess,
-    Rhs: ContainerRead<B, Slot = N>,
-    N: Number,
-{
-    fn union_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst)
+    /// Combines the bitmap and `rhs` slot by slot with `op` and stores result in `dst`,
+    /// which is resized to the exact length of result beforehand. Containers of the bitmap,
+    /// `rhs` and `dst` may be of different types.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{BitOp, StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_1000]);
+    /// let rhs = StaticBitmap::<_, LSB>::new(vec![0b0000_0011u8]);
+    /// let mut result = Vec::new();
+    /// bitmap.combine_into(&rhs, BitOp::And, &mut result);
+    /// assert_eq!(result, vec![0b0000_0001]);
+    /// bitmap.combine_into(&rhs, BitOp::Xor, &mut result);
+    /// assert_eq!(result, vec![0b0000_1010, 0b0000_1000]);
+    /// bitmap.combine_into(&rhs, BitOp::AndNot, &mut result);
+    /// assert_eq!(result, vec![0b0000_1000, 0b0000_1000]);
+    /// ```
+    pub fn combine_into<Rhs, Dst>(&self, rhs: &Rhs, op: BitOp, dst: &mut Dst)
     where
-        Dst: ContainerWrite<B, Slot = N>,
+        Rhs: ContainerRead<B, Slot = N>,
+        Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
     {
-        try_union_in_impl(&self.data, rhs, dst).unwrap();
+        combine_into_impl(&self.data, rhs, op, dst);
     }
 
-    fn try_union_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst) -> Result<(), UnionError>
+    /// Returns iterator over indexes of bits set in both the bitmap and `other`, in ascending
+    /// order. Slots are intersected on the fly, so nothing is allocated. Bits that exceed
+    /// the shorter container are never yielded.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1011u8, 0b0000_0001]);
+    /// let common = bitmap.common_ones(&[0b0000_1110u8]).collect::<Vec<_>>();
+    /// assert_eq!(common, vec![1, 3]);
+    /// ```
+    pub fn common_ones<'a, Rhs>(&'a self, other: &'a Rhs) -> CombinedOnes<'a, D, Rhs, N, B>
     where
-        Dst: ContainerWrite<B, Slot = N>,
+        Rhs: ContainerRead<B, Slot = N>,
     {
-        try_union_in_impl(&self.data, rhs, dst)
+        CombinedOnes::new(&self.data, other, BitOp::And)
     }
 
-    fn union<Dst>(&self, rhs: &Rhs) -> Dst
+    /// Returns iterator over indexes of bits set in exactly one of the bitmap and `other`
+    /// (symmetric difference), in ascending order. Slots are combined on the fly, so nothing
+    /// is allocated. Bits that exceed the shorter container are yielded as is.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1011u8]);
+    /// let changed = bitmap.symmetric_ones(&[0b0000_1110u8, 0b0000_0001]).collect::<Vec<_>>();
+    /// assert_eq!(changed, vec![0, 2, 8]);
+    /// ```
+    pub fn symmetric_ones<'a, Rhs>(&'a self, other: &'a Rhs) -> CombinedOnes<'a, D, Rhs, N, B>
     where
-        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+        Rhs: ContainerRead<B, Slot = N>,
     {
-        try_union_impl(&self.data, rhs).unwrap()
+        CombinedOnes::new(&self.data, other, BitOp::Xor)
     }
 
-    fn try_union<Dst>(&self, rhs: &Rhs) -> Result<Dst, UnionError>
+    /// Calculates intersection and wraps result into [`VarBitmap`] with default strategy,
+    /// so the result can be grown afterwards.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_1000]);
+    /// let mut result = bitmap.intersection_var::<_, MinimumRequiredStrategy>(&[0b0000_0001u8]);
+    /// assert_eq!(result.as_ref(), &vec![0b0000_0001]);
+    /// result.set(20, true);
+    /// assert_eq!(result.as_ref(), &vec![0b0000_0001, 0b0000_0000, 0b0001_0000]);
+    /// ```
+    ///
+    /// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+    pub fn intersection_var<Rhs, S>(&self, rhs: &Rhs) -> VarBitmap<Vec<N>, B, S>
     where
-        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+        Rhs: ContainerRead<B, Slot = N>,
+        S: GrowStrategy + Default,
     {
-        try_union_impl(&self.data, rhs)
+        // Result container is created with required size, so it cannot fail
+        VarBitmap::from_container(try_intersection_impl(&self.data, rhs).unwrap())
     }
 
-    fn union_len(&self, rhs: &Rhs) -> usize {
-        union_len_impl(&self.data, rhs)
+    /// Calculates union and wraps result into [`VarBitmap`] with default strategy,
+    /// so the result can be grown afterwards.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_1000]);
+    /// let mut result = bitmap.union_var::<_, MinimumRequiredStrategy>(&[0b0000_0010u8]);
+    /// assert_eq!(result.as_ref(), &vec![0b0000_1011, 0b0000_1000]);
+    /// result.set(20, true);
+    /// assert_eq!(result.as_ref(), &vec![0b0000_1011, 0b0000_1000, 0b0001_0000]);
+    /// ```
+    ///
+    /// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+    pub fn union_var<Rhs, S>(&self, rhs: &Rhs) -> VarBitmap<Vec<N>, B, S>
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+        S: GrowStrategy + Default,
+    {
+        // Result container is created with required size, so it cannot fail
+        VarBitmap::from_container(try_union_impl(&self.data, rhs).unwrap())
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::LSB;
+impl<N, B> StaticBitmap<Vec<N>, B>
+where
+    N: Number,
+    B: BitAccess,
+{
+    /// Creates bitmap from bytes in [`to_byte_vec`] layout. Number of slots is enough to hold
+    /// every byte, padding zero bytes are kept as zero slots.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<Vec<u16>, LSB>::from_bytes_padded(&[0b0000_1001, 0b0000_0001, 0]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001_0000_1001u16, 0]);
+    /// ```
+    ///
+    /// [`to_byte_vec`]: crate::static_bitmap::StaticBitmap::to_byte_vec
+    pub fn from_bytes_padded(bytes: &[u8]) -> Self {
+        Self::new(slots_from_bytes_impl::<N, B>(bytes))
+    }
 
-    #[test]
-    #[rustfmt::skip]
-    fn get_bit() {        
-        // Number
-        assert!(StaticBitmap::<u8, LSB>::new(1 << 0).get(0));
-        assert!(StaticBitmap::<u8, LSB>::new(1 << 1).get(1));
-        assert!(StaticBitmap::<u8, LSB>::new(1 << 2).get(2));
-        assert!(StaticBitmap::<u8, LSB>::new(1 << 3).get(3));
-        assert!(StaticBitmap::<u8, LSB>::new(1 << 4).get(4));
-        assert!(StaticBitmap::<u8, LSB>::new(1 << 5).get(5));
-        assert!(StaticBitmap::<u8, LSB>::new(1 << 6).get(6));
-        assert!(StaticBitmap::<u8, LSB>::new(1 << 7).get(7));
-        assert!(!StaticBitmap::<u8, LSB>::new(0b1111_1111).get(8));
-        
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 0).get(0));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 1).get(1));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 2).get(2));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 3).get(3));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 4).get(4));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 5).get(5));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 6).get(6));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 7).get(7));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 8).get(8));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 9).get(9));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 10).get(10));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 11).get(11));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 12).get(12));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 13).get(13));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 14).get(14));
-        assert!(StaticBitmap::<u16, LSB>::new(1 << 15).get(15));
-        assert!(!StaticBitmap::<u16, LSB>::new(0b1111_1111_1111_1111).get(16));
-        
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 0).get(0));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 1).get(1));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 2).get(2));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 3).get(3));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 4).get(4));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 5).get(5));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 6).get(6));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 7).get(7));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 8).get(8));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 9).get(9));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 10).get(10));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 11).get(11));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 12).get(12));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 13).get(13));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 14).get(14));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 15).get(15));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 16).get(16));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 17).get(17));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 18).get(18));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 19).get(19));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 20).get(20));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 21).get(21));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 22).get(22));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 23).get(23));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 24).get(24));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 25).get(25));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 26).get(26));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 27).get(27));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 28).get(28));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 29).get(29));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 30).get(30));
-        assert!(StaticBitmap::<u32, LSB>::new(1 << 31).get(31));
-        assert!(!StaticBitmap::<u32, LSB>::new(0b0000_0000_0000_0000_0000_0000_0000_0000).get(32));
-        
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 0).get(0));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 1).get(1));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 2).get(2));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 3).get(3));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 4).get(4));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 5).get(5));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 6).get(6));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 7).get(7));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 8).get(8));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 9).get(9));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 10).get(10));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 11).get(11));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 12).get(12));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 13).get(13));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 14).get(14));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 15).get(15));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 16).get(16));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 17).get(17));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 18).get(18));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 19).get(19));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 20).get(20));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 21).get(21));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 22).get(22));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 23).get(23));
-        assert!(StaticBitmap::<u64, LSB>::new(1 << 24).get(24));
+    /// Creates bitmap from the form produced by [`to_compressed`].
+    ///
+    /// Returns `Err(_)` if the form is malformed: its length is odd or some run is empty.
+    ///
+    /// [`to_compressed`]: crate::static_bitmap::StaticBitmap::to_compressed
+    pub fn from_compressed(compressed: &[u8]) -> Result<Self, DecompressError> {
+        Ok(Self::from_bytes_padded(&decompress_impl(compressed)?))
+    }
+}
+
+impl<N, B> StaticBitmap<[N; 1], B>
+where
+    N: Number,
+    B: BitAccess,
+{
+    /// Creates bitmap over one-element array from single number.
+    ///
+    /// A single number and a one-element array store the same bits, but a number is always
+    /// exactly one slot: [`TryWithSlots`] for `N` fails for any length except `1`, so using it as
+    /// a result of combinators (e.g. union with longer `rhs`) returns error. Prefer a number when
+    /// the bitmap never exceeds one slot, and an array (or other container) when it takes part
+    /// in combinators with containers of other lengths.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::from_single(0b0000_1001u8);
+    /// assert_eq!(bitmap.into_inner(), [0b0000_1001]);
+    /// ```
+    ///
+    /// [`TryWithSlots`]: crate::with_slots::TryWithSlots
+    pub fn from_single(n: N) -> Self {
+        Self::new([n])
+    }
+
+    /// Converts bitmap over one-element array into bitmap over single number.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8]);
+    /// assert_eq!(bitmap.into_single().into_inner(), 0b0000_1001);
+    /// ```
+    pub fn into_single(self) -> StaticBitmap<N, B> {
+        let [n] = self.data;
+        StaticBitmap {
+            data: n,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<N, B, const M: usize> StaticBitmap<[N; M], B>
+where
+    N: Number,
+    B: BitAccess,
+{
+    /// Creates bitmap over array from bits. Bit with index `i` gets `i`-th value of iterator.
+    /// If iterator yields fewer bits than array can store, the rest bits are `0`.
+    ///
+    /// Returns `Err(_)` if iterator yields more than `M * N::BITS_COUNT` bits.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<[u8; 2], LSB>::try_from_bits([true, false, false, true]).unwrap();
+    /// assert_eq!(bitmap.into_inner(), [0b0000_1001, 0b0000_0000]);
+    /// assert!(StaticBitmap::<[u8; 1], LSB>::try_from_bits([false; 9]).is_err());
+    /// ```
+    pub fn try_from_bits<I>(bits: I) -> Result<Self, WithSlotsError>
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let mut data = [N::ZERO; M];
+        let max_idx = M * N::BITS_COUNT;
+        for (idx, bit) in bits.into_iter().enumerate() {
+            if idx >= max_idx {
+                return Err(WithSlotsError::new(format!(
+                    "number of bits exceeds capacity of {} bits",
+                    max_idx
+                )));
+            }
+            if bit {
+                ContainerWrite::<B>::set_bit_unchecked(&mut data, idx, true);
+            }
+        }
+        Ok(Self::new(data))
+    }
+}
+
+impl<N, B, const M: usize> StaticBitmap<[N; M], B> {
+    /// Creates new bitmap from array in const context, e.g. for compile-time flag tables.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// const BITMAP: StaticBitmap<[u8; 4], LSB> = StaticBitmap::new_const([0, 0, 0, 1]);
+    /// assert!(BITMAP.get(24));
+    /// assert_eq!(BITMAP.count_ones(), 1);
+    /// ```
+    pub const fn new_const(data: [N; M]) -> Self {
+        Self {
+            data,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<D, B> StaticBitmap<D, B> {
+    /// Converts bitmap into inner container.
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+
+    /// Moves container into bitmap with another bit access. Container is not changed, so
+    /// **indexes of set bits change**: `i`-th bit of the result is not `i`-th bit of the
+    /// bitmap anymore, because slots are read according to `B2`.
+    ///
+    /// Use it only if container was written with `B2` in the first place. To keep indexes of
+    /// set bits, copy them into a new bitmap instead.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB, MSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001u8]);
+    /// assert!(bitmap.get(0));
+    /// let bitmap = bitmap.reinterpret_bits::<MSB>();
+    /// assert!(!bitmap.get(0));
+    /// assert!(bitmap.get(7));
+    /// ```
+    pub fn reinterpret_bits<B2>(self) -> StaticBitmap<D, B2>
+    where
+        B2: BitAccess,
+    {
+        StaticBitmap {
+            data: self.data,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B> StaticBitmap<D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    /// Gets single bit state.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001u8, 0b0000_1000]);
+    /// assert!(bitmap.get(0));
+    /// assert!(bitmap.get(11));
+    /// assert!(!bitmap.get(13));
+    /// // Out of bounds bits always return false
+    /// assert!(!bitmap.get(128));
+    /// ```
+    pub fn get(&self, idx: usize) -> bool {
+        self.data.get_bit(idx)
+    }
+
+    /// Returns view of the bitmap as a sequence of `bits_count` bits.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1000u8, 0b0000_0001]);
+    /// let bits = bitmap.bits();
+    /// assert_eq!(bits.len(), 16);
+    /// assert_eq!(bits.at(3), bitmap.get(3));
+    /// assert_eq!(bits.range(8..16).iter().filter(|&b| b).count(), 1);
+    /// ```
+    pub fn bits(&self) -> BitSlice<'_, D, B> {
+        BitSlice::new(&self.data)
+    }
+
+    /// Returns iterator over states of every `step`-th bit up to `bits_count`:
+    /// bits `0`, `step`, `2 * step` and so on.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0001_0101u8]);
+    /// assert_eq!(bitmap.step_bits(2).collect::<Vec<_>>(), vec![true, true, true, false]);
+    /// assert_eq!(bitmap.step_bits(3).collect::<Vec<_>>(), vec![true, false, false]);
+    /// ```
+    pub fn step_bits(&self, step: usize) -> impl Iterator<Item = bool> + '_ {
+        assert!(step != 0, "step must be non-zero");
+        (0..self.data.bits_count())
+            .step_by(step)
+            .map(move |idx| self.data.get_bit(idx))
+    }
+
+    /// Gets single bit state, returns `default` if `idx` is out of bounds.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001u8, 0b0000_1000]);
+    /// assert!(bitmap.get_or(0, false));
+    /// assert!(!bitmap.get_or(13, true));
+    /// // Out of bounds bits return default
+    /// assert!(bitmap.get_or(128, true));
+    /// assert!(!bitmap.get_or(128, false));
+    /// ```
+    pub fn get_or(&self, idx: usize, default: bool) -> bool {
+        if idx < self.data.bits_count() {
+            self.data.get_bit(idx)
+        } else {
+            default
+        }
+    }
+
+    /// Returns iterator over slots.
+    pub fn iter(&self) -> Iter<'_, D, B> {
+        Iter::new(&self.data)
+    }
+
+    /// Returns iterator over slots (copies). It is the same as [`iter`].
+    ///
+    /// [`iter`]: crate::static_bitmap::StaticBitmap::iter
+    pub fn slots(&self) -> Iter<'_, D, B> {
+        self.iter()
+    }
+
+    /// Converts bitmap into iterator over slots. It is the same as [`into_iter`].
+    ///
+    /// [`into_iter`]: std::iter::IntoIterator::into_iter
+    pub fn into_slots(self) -> IntoIter<D, B> {
+        IntoIter::new(self.data)
+    }
+
+    /// Returns iterator over references to slots. Unlike [`slots`], it doesn't copy them,
+    /// but requires container to be a contiguous slice.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new(vec![0b0000_1001u8, 0b0000_0010]);
+    /// assert!(bitmap.slots_ref().eq(bitmap.slots().collect::<Vec<_>>().iter()));
+    /// ```
+    ///
+    /// [`slots`]: crate::static_bitmap::StaticBitmap::slots
+    pub fn slots_ref<N>(&self) -> std::slice::Iter<'_, N>
+    where
+        D: AsRef<[N]>,
+    {
+        self.data.as_ref().iter()
+    }
+
+    /// Returns iterator over chunks of `chunk_len` slots, the last chunk may be shorter.
+    /// Requires container to be a contiguous slice, like [`slots_ref`].
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `chunk_len` is `0`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([1u8, 2, 3, 4, 5]);
+    /// let chunks = bitmap.slot_chunks(2).collect::<Vec<_>>();
+    /// assert_eq!(chunks, vec![&[1u8, 2][..], &[3, 4], &[5]]);
+    /// ```
+    ///
+    /// [`slots_ref`]: crate::static_bitmap::StaticBitmap::slots_ref
+    pub fn slot_chunks<N>(&self, chunk_len: usize) -> std::slice::Chunks<'_, N>
+    where
+        D: AsRef<[N]>,
+    {
+        self.data.as_ref().chunks(chunk_len)
+    }
+
+    /// Returns static bitmap that borrows slots of the container as a slice.
+    /// Nothing is copied, the view shares data with the bitmap.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new(vec![0b0000_1001u8, 0b0000_0010]);
+    /// let view = bitmap.as_slice_bitmap();
+    /// assert_eq!(view.ones().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    pub fn as_slice_bitmap<N>(&self) -> StaticBitmap<&[N], B>
+    where
+        D: AsRef<[N]>,
+        N: Number,
+    {
+        StaticBitmap::from(self.data.as_ref())
+    }
+
+    /// Returns static bitmap that mutably borrows slots of the container as a slice.
+    /// Changes made through the view are applied to the bitmap.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new(vec![0b0000_1001u8, 0b0000_0010]);
+    /// bitmap.as_mut_slice_bitmap().set(1, true);
+    /// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![0, 1, 3, 9]);
+    /// ```
+    pub fn as_mut_slice_bitmap<N>(&mut self) -> StaticBitmap<&mut [N], B>
+    where
+        D: AsMut<[N]>,
+        N: Number,
+    {
+        StaticBitmap::from(self.data.as_mut())
+    }
+
+    /// Returns iterator over indexes of set bits in ascending order.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0010]);
+    /// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    pub fn ones(&self) -> Ones<'_, D, B> {
+        Ones::new(&self.data)
+    }
+
+    /// Returns iterator over maximal runs of cleared bits (gaps) in ascending order,
+    /// including the leading and the trailing gaps.
+    ///
+    /// The trailing gap ends at [`bits_count`], not at [`bit_len`], so free bits after
+    /// the last set bit are reported as well.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0010_0100u8]);
+    /// assert_eq!(bitmap.gaps().collect::<Vec<_>>(), vec![0..2, 3..5, 6..8]);
+    /// ```
+    ///
+    /// [`bits_count`]: crate::container::ContainerRead::bits_count
+    /// [`bit_len`]: crate::static_bitmap::StaticBitmap::bit_len
+    pub fn gaps(&self) -> Gaps<'_, D, B> {
+        Gaps::new(&self.data)
+    }
+
+    /// Returns iterator over numbers of set bits in every window of `width` bits.
+    ///
+    /// Yields a count for each window start `i` in `0..=bits_count() - width`, that is
+    /// the number of ones in bits `i..i + width`. Nothing is yielded if `width` exceeds
+    /// the bitmap. The count is updated incrementally, the whole pass takes `O(bits_count())`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0011_1001u8]);
+    /// assert_eq!(bitmap.sliding_popcount(3).collect::<Vec<_>>(), vec![1, 1, 2, 3, 2, 1]);
+    /// ```
+    pub fn sliding_popcount(&self, width: usize) -> SlidingPopcount<'_, D, B> {
+        SlidingPopcount::new(&self.data, width)
+    }
+
+    /// Returns iterator over indexes of set bits, treating the bitmap as a set of integers.
+    /// It is the same as [`ones`].
+    ///
+    /// Note that iterating over `&bitmap` yields slots, not indexes.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0010]);
+    /// let mut ids = Vec::new();
+    /// for id in bitmap.ids() {
+    ///     ids.push(id);
+    /// }
+    /// assert_eq!(ids, vec![0, 3, 9]);
+    /// ```
+    ///
+    /// [`ones`]: crate::static_bitmap::StaticBitmap::ones
+    pub fn ids(&self) -> Ones<'_, D, B> {
+        self.ones()
+    }
+
+    /// Returns iterator over `(rank, index)` pairs of set bits: `rank` is the number of set bits
+    /// before `index`. It is the same as `ones().enumerate()`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b1000_1000u8]);
+    /// assert_eq!(bitmap.enumerate_ones().collect::<Vec<_>>(), vec![(0, 3), (1, 7)]);
+    /// ```
+    pub fn enumerate_ones(&self) -> Enumerate<Ones<'_, D, B>> {
+        self.ones().enumerate()
+    }
+
+    /// Returns iterator over `(index, state)` pairs of every bit up to `bits_count()`.
+    /// It is the same as `iter().by_bits().enumerate()`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new(0b0000_0101u8);
+    /// let bits = bitmap.indexed_bits().take(4).collect::<Vec<_>>();
+    /// assert_eq!(bits, vec![(0, true), (1, false), (2, true), (3, false)]);
+    /// ```
+    pub fn indexed_bits(&self) -> IndexedBits<'_, D, B> {
+        IndexedBits::new(&self.data)
+    }
+
+    /// Collects indexes of set bits into [`BTreeSet`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0010]);
+    /// assert_eq!(bitmap.to_btree_set().into_iter().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    pub fn to_btree_set(&self) -> BTreeSet<usize> {
+        self.ones().collect()
+    }
+
+    /// Returns formatter that prints raw bytes of every slot in specified byte order.
+    /// `Debug` implementation uses [`Endianness::Little`]. See [`DebugBytes`].
+    ///
+    /// [`Endianness::Little`]: crate::number::Endianness::Little
+    /// [`DebugBytes`]: crate::debug::DebugBytes
+    pub fn debug_bytes(&self, endianness: Endianness) -> DebugBytes<'_, D, B> {
+        DebugBytes::new(&self.data, endianness)
+    }
+
+    /// Returns formatter that prints bits in logical order (0th bit first).
+    ///
+    /// Unlike `Debug` implementation, which prints raw bytes of every slot,
+    /// it honors `BitAccess` and slot width. See [`DebugLogical`].
+    ///
+    /// [`DebugLogical`]: crate::debug::DebugLogical
+    pub fn debug_logical(&self) -> DebugLogical<'_, D, B> {
+        DebugLogical::new(&self.data)
+    }
+}
+
+impl<D, B> StaticBitmap<D, B>
+where
+    D: ContainerWrite<B>,
+    B: BitAccess,
+{
+    /// Sets new state for a single bit.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `idx` is out of bounds.
+    /// See non-panic function [`try_set`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0001_1000]);
+    /// bitmap.set(12, false);
+    /// assert!(!bitmap.get(12));
+    /// bitmap.set(13, true);
+    /// assert!(bitmap.get(13));
+    /// ```
+    ///
+    /// [`try_set`]: crate::static_bitmap::StaticBitmap::try_set
+    pub fn set(&mut self, idx: usize, val: bool) {
+        self.try_set(idx, val).unwrap();
+    }
+
+    /// Sets new state for a single bit.
+    ///
+    /// Returns `Err(_)` if `idx` is out of bounds.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0001_1000]);
+    /// assert!(bitmap.try_set(12, true).is_ok());
+    /// assert!(bitmap.get(12));
+    /// assert!(bitmap.try_set(12, false).is_ok());
+    /// assert!(!bitmap.get(12));
+    /// // Out of bounds bits return error
+    /// assert!(bitmap.try_set(128, true).is_err());
+    /// assert!(!bitmap.get(128));
+    /// ```
+    pub fn try_set(&mut self, idx: usize, val: bool) -> Result<(), OutOfBoundsError> {
+        self.data.try_set_bit(idx, val)
+    }
+
+    /// Consumes the bitmap and returns it with bit `idx` set.
+    ///
+    /// Returns `Err(_)` if `idx` is out of bounds.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0u8; 2]);
+    /// let bitmap = bitmap.try_with_bit(3).unwrap().try_with_bit(7).unwrap();
+    /// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![3, 7]);
+    /// assert!(bitmap.try_with_bit(16).is_err());
+    /// ```
+    pub fn try_with_bit(mut self, idx: usize) -> Result<Self, OutOfBoundsError> {
+        self.try_set(idx, true)?;
+        Ok(self)
+    }
+
+    /// Consumes the bitmap and returns it with bit `idx` cleared.
+    ///
+    /// Returns `Err(_)` if `idx` is out of bounds.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0xFFu8; 2]);
+    /// let bitmap = bitmap.try_without_bit(3).unwrap();
+    /// assert!(!bitmap.get(3));
+    /// ```
+    pub fn try_without_bit(mut self, idx: usize) -> Result<Self, OutOfBoundsError> {
+        self.try_set(idx, false)?;
+        Ok(self)
+    }
+
+    /// Sets every bit with probability `density` and clears otherwise, using a tiny internal
+    /// pseudo-random generator.
+    ///
+    /// The result depends only on `seed`, so it is useful for reproducible tests and benchmarks.
+    /// It is not suitable for anything that requires real randomness.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0u8; 4]);
+    /// bitmap.fill_pseudo_random(42, 1.0);
+    /// assert_eq!(bitmap.into_inner(), [0xFF; 4]);
+    /// ```
+    pub fn fill_pseudo_random(&mut self, seed: u64, density: f64) {
+        fill_pseudo_random_impl(&mut self.data, seed, density);
+    }
+
+    /// Sets a single bit to `1` only if it is `0` now. Returns `true` if the bit has changed.
+    /// The bit is not written at all if it is already set.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `idx` is out of bounds.
+    /// See non-panic function [`try_set_if_clear`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new(0b0000_0001u8);
+    /// assert!(!bitmap.set_if_clear(0));
+    /// assert!(bitmap.set_if_clear(1));
+    /// assert_eq!(bitmap.into_inner(), 0b0000_0011);
+    /// ```
+    ///
+    /// [`try_set_if_clear`]: crate::static_bitmap::StaticBitmap::try_set_if_clear
+    pub fn set_if_clear(&mut self, idx: usize) -> bool {
+        self.try_set_if_clear(idx).unwrap()
+    }
+
+    /// Sets a single bit to `1` only if it is `0` now. Returns `Ok(true)` if the bit has changed.
+    /// The bit is not written at all if it is already set.
+    ///
+    /// Returns `Err(_)` if `idx` is out of bounds.
+    pub fn try_set_if_clear(&mut self, idx: usize) -> Result<bool, OutOfBoundsError> {
+        if self.data.get_bit(idx) {
+            return Ok(false);
+        }
+        self.data.try_set_bit(idx, true)?;
+        Ok(true)
+    }
+
+    /// Sets new state for a single bit like [`try_set`]. Returns `Ok(true)` if the stored bit
+    /// differed from `val` and was updated, `Ok(false)` if it already was `val`.
+    /// The bit is not written at all if it is unchanged.
+    ///
+    /// Returns `Err(_)` if `idx` is out of bounds.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new(0b0000_0001u8);
+    /// assert_eq!(bitmap.set_changed(0, true).unwrap(), false);
+    /// assert_eq!(bitmap.set_changed(1, true).unwrap(), true);
+    /// assert_eq!(bitmap.set_changed(0, false).unwrap(), true);
+    /// assert!(bitmap.set_changed(8, false).is_err());
+    /// assert_eq!(bitmap.into_inner(), 0b0000_0010);
+    /// ```
+    ///
+    /// [`try_set`]: crate::static_bitmap::StaticBitmap::try_set
+    pub fn set_changed(&mut self, idx: usize, val: bool) -> Result<bool, OutOfBoundsError> {
+        if idx >= self.data.bits_count() {
+            return Err(OutOfBoundsError::new(
+                idx,
+                0..self.data.bits_count(),
+                <D::Slot as Number>::BITS_COUNT,
+            ));
+        }
+        if self.data.get_bit(idx) == val {
+            return Ok(false);
+        }
+        self.data.set_bit_unchecked(idx, val);
+        Ok(true)
+    }
+
+    /// Sets a single bit to `0` only if it is `1` now. Returns `true` if the bit has changed.
+    /// The bit is not written at all if it is already cleared. Out of bounds bits are always `0`.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new(0b0000_0001u8);
+    /// assert!(!bitmap.clear_if_set(1));
+    /// assert!(bitmap.clear_if_set(0));
+    /// assert!(!bitmap.clear_if_set(128));
+    /// assert_eq!(bitmap.into_inner(), 0b0000_0000);
+    /// ```
+    pub fn clear_if_set(&mut self, idx: usize) -> bool {
+        if !self.data.get_bit(idx) {
+            return false;
+        }
+        self.data.set_bit_unchecked(idx, false);
+        true
+    }
+
+    /// Removes a single bit and shifts all higher bits down by one, like [`Vec::remove`].
+    /// The last bit of the bitmap becomes `0`. Returns state of removed bit.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `idx` is out of bounds.
+    /// See non-panic function [`try_remove_bit`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new(0b0000_1101u8);
+    /// assert!(bitmap.remove_bit(2));
+    /// assert_eq!(bitmap.into_inner(), 0b0000_0101);
+    /// ```
+    ///
+    /// [`try_remove_bit`]: crate::static_bitmap::StaticBitmap::try_remove_bit
+    pub fn remove_bit(&mut self, idx: usize) -> bool {
+        self.try_remove_bit(idx).unwrap()
+    }
+
+    /// Removes a single bit and shifts all higher bits down by one, like [`Vec::remove`].
+    /// The last bit of the bitmap becomes `0`. Returns state of removed bit.
+    ///
+    /// Returns `Err(_)` if `idx` is out of bounds.
+    pub fn try_remove_bit(&mut self, idx: usize) -> Result<bool, OutOfBoundsError> {
+        if idx >= self.data.bits_count() {
+            return Err(OutOfBoundsError::new(
+                idx,
+                0..self.data.bits_count(),
+                <D::Slot as Number>::BITS_COUNT,
+            ));
+        }
+        Ok(remove_bit_impl(&mut self.data, idx))
+    }
+
+    /// Inserts a single bit and shifts all bits starting from `idx` up by one, like [`Vec::insert`].
+    /// The last bit of the bitmap is dropped.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `idx` is out of bounds.
+    /// See non-panic function [`try_insert_bit`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new(0b1000_0101u8);
+    /// bitmap.insert_bit(2, true);
+    /// assert_eq!(bitmap.into_inner(), 0b0000_1101);
+    /// ```
+    ///
+    /// [`try_insert_bit`]: crate::static_bitmap::StaticBitmap::try_insert_bit
+    pub fn insert_bit(&mut self, idx: usize, val: bool) {
+        self.try_insert_bit(idx, val).unwrap();
+    }
+
+    /// Inserts a single bit and shifts all bits starting from `idx` up by one, like [`Vec::insert`].
+    /// The last bit of the bitmap is dropped.
+    ///
+    /// Returns `Err(_)` if `idx` is out of bounds.
+    pub fn try_insert_bit(&mut self, idx: usize, val: bool) -> Result<(), OutOfBoundsError> {
+        if idx >= self.data.bits_count() {
+            return Err(OutOfBoundsError::new(
+                idx,
+                0..self.data.bits_count(),
+                <D::Slot as Number>::BITS_COUNT,
+            ));
+        }
+        insert_bit_impl(&mut self.data, idx, val);
+        Ok(())
+    }
+}
+
+impl<D, N, B> StaticBitmap<D, B>
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    /// Calls `f` with index and mutable reference of every slot, in order.
+    /// Useful for position dependent updates, e.g. masking the last partial slot.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0xFFu8; 2]);
+    /// let last = bitmap.as_ref().len() - 1;
+    /// bitmap.for_each_slot_mut(|idx, slot| {
+    ///     if idx == last {
+    ///         *slot &= 0b0000_1111;
+    ///     }
+    /// });
+    /// assert_eq!(bitmap.into_inner(), [0xFF, 0b0000_1111]);
+    /// ```
+    pub fn for_each_slot_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut N),
+    {
+        for idx in 0..self.data.slots_count() {
+            f(idx, self.data.get_mut_slot(idx));
+        }
+    }
+
+    /// Writes `bits[j]` to bit `start + j`.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if the range exceeds the bitmap.
+    /// See non-panic function [`try_set_bits_from`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0u8; 2]);
+    /// bitmap.set_bits_from(6, &[true, false, true]);
+    /// assert_eq!(bitmap.into_inner(), [0b0100_0000, 0b0000_0001]);
+    /// ```
+    ///
+    /// [`try_set_bits_from`]: crate::static_bitmap::StaticBitmap::try_set_bits_from
+    pub fn set_bits_from(&mut self, start: usize, bits: &[bool]) {
+        self.try_set_bits_from(start, bits).unwrap();
+    }
+
+    /// Writes `bits[j]` to bit `start + j`.
+    ///
+    /// Returns `Err(_)` if the range exceeds the bitmap, bitmap stays unchanged in this case.
+    pub fn try_set_bits_from(
+        &mut self,
+        start: usize,
+        bits: &[bool],
+    ) -> Result<(), OutOfBoundsError> {
+        let bits_count = self.data.bits_count();
+        if bits_count < bits.len() || start > bits_count - bits.len() {
+            return Err(OutOfBoundsError::new(
+                start.saturating_add(bits.len()).saturating_sub(1),
+                0..bits_count,
+                N::BITS_COUNT,
+            ));
+        }
+        set_bits_from_impl(&mut self.data, start, bits);
+        Ok(())
+    }
+
+    /// Sets every bit in `range` to `val`. Slots are updated as a whole, not bit by bit.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if the range exceeds the bitmap.
+    /// See non-panic function [`try_set_range`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0u8; 3]);
+    /// bitmap.set_range(4..20, true);
+    /// assert_eq!(bitmap.into_inner(), [0b1111_0000, 0b1111_1111, 0b0000_1111]);
+    /// ```
+    ///
+    /// [`try_set_range`]: crate::static_bitmap::StaticBitmap::try_set_range
+    pub fn set_range(&mut self, range: Range<usize>, val: bool) {
+        self.try_set_range(range, val).unwrap();
+    }
+
+    /// Sets every bit in `range` to `val`. Slots are updated as a whole, not bit by bit.
+    ///
+    /// Returns `Err(_)` if the range exceeds the bitmap, bitmap stays unchanged in this case.
+    pub fn try_set_range(
+        &mut self,
+        range: Range<usize>,
+        val: bool,
+    ) -> Result<(), OutOfBoundsError> {
+        let bits_count = self.data.bits_count();
+        if range.start < range.end && range.end > bits_count {
+            return Err(OutOfBoundsError::new(
+                range.end - 1,
+                0..bits_count,
+                N::BITS_COUNT,
+            ));
+        }
+        set_range_impl(&mut self.data, range, val);
+        Ok(())
+    }
+
+    /// Writes `value` to every slot in `range` of slot indexes.
+    ///
+    /// Unlike [`set_range`], the range addresses slots, not bits, so it is useful to fill
+    /// large regions with a whole-slot pattern.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if the range exceeds the container.
+    /// See non-panic function [`try_fill_slots`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0u8; 3]);
+    /// bitmap.fill_slots(1..3, 0xFF);
+    /// assert_eq!(bitmap.into_inner(), [0x00, 0xFF, 0xFF]);
+    /// ```
+    ///
+    /// [`set_range`]: crate::static_bitmap::StaticBitmap::set_range
+    /// [`try_fill_slots`]: crate::static_bitmap::StaticBitmap::try_fill_slots
+    pub fn fill_slots(&mut self, range: Range<usize>, value: N) {
+        self.try_fill_slots(range, value).unwrap();
+    }
+
+    /// Writes `value` to every slot in `range` of slot indexes.
+    ///
+    /// Returns `Err(_)` if the range exceeds the container, bitmap stays unchanged in this case.
+    pub fn try_fill_slots(
+        &mut self,
+        range: Range<usize>,
+        value: N,
+    ) -> Result<(), OutOfBoundsError> {
+        let slots_count = self.data.slots_count();
+        if range.start < range.end && range.end > slots_count {
+            // The last bit of a huge range may not fit `usize`
+            let last_bit = range
+                .end
+                .checked_mul(N::BITS_COUNT)
+                .map_or(usize::MAX, |bits| bits - 1);
+            return Err(OutOfBoundsError::new(
+                last_bit,
+                0..self.data.bits_count(),
+                N::BITS_COUNT,
+            ));
+        }
+        for i in range {
+            *self.data.get_mut_slot(i) = value;
+        }
+        Ok(())
+    }
+
+    /// Updates the bitmap with union of itself and `rhs` (`self | rhs`).
+    ///
+    /// The bitmap cannot grow, so bits of `rhs` that exceed the bitmap are ignored.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8]);
+    /// bitmap.union_update(&[0b0000_0110u8, 0b0000_0001]);
+    /// assert_eq!(bitmap.into_inner(), [0b0000_1111]);
+    /// ```
+    pub fn union_update<Rhs>(&mut self, rhs: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        union_update_impl(&mut self.data, rhs);
+    }
+
+    /// Updates the bitmap with union of itself and every source, one after another.
+    /// Nothing is allocated.
+    ///
+    /// The bitmap cannot grow, so bits of sources that exceed the bitmap are ignored.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001u8]);
+    /// bitmap.union_in_many([&[0b0000_0010u8], &[0b0000_0100], &[0b0000_1000]]);
+    /// assert_eq!(bitmap.into_inner(), [0b0000_1111]);
+    /// ```
+    pub fn union_in_many<'a, I, Rhs>(&mut self, sources: I)
+    where
+        I: IntoIterator<Item = &'a Rhs>,
+        Rhs: ContainerRead<B, Slot = N> + 'a,
+    {
+        for rhs in sources {
+            union_update_impl(&mut self.data, rhs);
+        }
+    }
+
+    /// Updates the bitmap with intersection of itself and `rhs` (`self & rhs`).
+    ///
+    /// Bits that exceed `rhs` are cleared.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0001]);
+    /// bitmap.intersect_update(&[0b0000_0011u8]);
+    /// assert_eq!(bitmap.into_inner(), [0b0000_0001, 0b0000_0000]);
+    /// ```
+    pub fn intersect_update<Rhs>(&mut self, rhs: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        intersect_update_impl(&mut self.data, rhs);
+    }
+
+    /// Returns intersection of the bitmap and `rhs` (`self & rhs`), consuming `rhs`.
+    ///
+    /// The result is written into `rhs` container, so no new container is allocated.
+    /// The result has as many slots as `rhs`, bits of `rhs` that exceed the bitmap are cleared.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new(vec![0b0000_1001u8]);
+    /// let rhs = vec![0b0000_0011u8, 0b0000_0001];
+    /// let res = bitmap.intersection_consuming(rhs);
+    /// assert_eq!(res.into_inner(), vec![0b0000_0001, 0b0000_0000]);
+    /// ```
+    pub fn intersection_consuming(self, rhs: D) -> StaticBitmap<D, B> {
+        let mut res = StaticBitmap::new(rhs);
+        res.intersect_update(&self.data);
+        res
+    }
+
+    /// Removes all bits of `rhs` from the bitmap (`self & !rhs`).
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0001]);
+    /// bitmap.difference_update(&[0b0000_0011u8]);
+    /// assert_eq!(bitmap.into_inner(), [0b0000_1000, 0b0000_0001]);
+    /// ```
+    pub fn difference_update<Rhs>(&mut self, rhs: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        difference_update_impl(&mut self.data, rhs);
+    }
+
+    /// Alias for [`difference_update`], clears every bit that is set in `mask`.
+    ///
+    /// [`difference_update`]: crate::static_bitmap::StaticBitmap::difference_update
+    pub fn clear_where<M>(&mut self, mask: &M)
+    where
+        M: ContainerRead<B, Slot = N>,
+    {
+        self.difference_update(mask);
+    }
+
+    /// Flips every bit of every slot, padding bits of the last slot are set too.
+    /// See [`invert_to_len`] to flip only a prefix of bits.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0000]);
+    /// bitmap.invert();
+    /// assert_eq!(bitmap.into_inner(), [0b1111_0110, 0b1111_1111]);
+    /// ```
+    ///
+    /// [`invert_to_len`]: crate::static_bitmap::StaticBitmap::invert_to_len
+    pub fn invert(&mut self) {
+        invert_impl(&mut self.data);
+    }
+
+    /// Flips bits `0..bit_len`, the rest of bits are cleared.
+    ///
+    /// Unlike [`invert`], padding bits past `bit_len` stay zero, so it is a complement
+    /// within a length.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `bit_len` exceeds the bitmap.
+    /// See non-panic function [`try_invert_to_len`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b1111_1100]);
+    /// bitmap.invert_to_len(10);
+    /// assert_eq!(bitmap.into_inner(), [0b1111_0110, 0b0000_0011]);
+    /// ```
+    ///
+    /// [`invert`]: crate::static_bitmap::StaticBitmap::invert
+    /// [`try_invert_to_len`]: crate::static_bitmap::StaticBitmap::try_invert_to_len
+    pub fn invert_to_len(&mut self, bit_len: usize) {
+        self.try_invert_to_len(bit_len).unwrap();
+    }
+
+    /// Flips bits `0..bit_len`, the rest of bits are cleared.
+    ///
+    /// Returns `Err(_)` if `bit_len` exceeds the bitmap, bitmap stays unchanged in this case.
+    pub fn try_invert_to_len(&mut self, bit_len: usize) -> Result<(), OutOfBoundsError> {
+        let bits_count = self.data.bits_count();
+        if bit_len > bits_count {
+            return Err(OutOfBoundsError::new(
+                bit_len - 1,
+                0..bits_count,
+                N::BITS_COUNT,
+            ));
+        }
+        invert_to_len_impl(&mut self.data, bit_len);
+        Ok(())
+    }
+
+    /// Updates the bitmap with symmetric difference of itself and `rhs` (`self ^ rhs`).
+    ///
+    /// The bitmap cannot grow, so bits of `rhs` that exceed the bitmap are ignored.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8]);
+    /// bitmap.symmetric_difference_update(&[0b0000_0011u8]);
+    /// assert_eq!(bitmap.into_inner(), [0b0000_1010]);
+    /// ```
+    pub fn symmetric_difference_update<Rhs>(&mut self, rhs: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        symmetric_difference_update_impl(&mut self.data, rhs);
+    }
+}
+
+#[cfg(feature = "roaring")]
+impl<D, N, B> StaticBitmap<D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    /// Collects indexes of set bits into [`RoaringBitmap`].
+    ///
+    /// ## Panic
+    ///
+    /// Panics if index of any set bit doesn't fit `u32`.
+    /// See non-panic function [`try_to_roaring`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0010]);
+    /// assert_eq!(bitmap.to_roaring().iter().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    ///
+    /// [`RoaringBitmap`]: roaring::RoaringBitmap
+    /// [`try_to_roaring`]: crate::static_bitmap::StaticBitmap::try_to_roaring
+    pub fn to_roaring(&self) -> roaring::RoaringBitmap {
+        self.try_to_roaring().unwrap()
+    }
+
+    /// Collects indexes of set bits into [`RoaringBitmap`].
+    ///
+    /// Returns `Err(_)` if index of any set bit doesn't fit `u32`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0010]);
+    /// assert_eq!(bitmap.try_to_roaring().unwrap().iter().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    ///
+    /// [`RoaringBitmap`]: roaring::RoaringBitmap
+    pub fn try_to_roaring(&self) -> Result<roaring::RoaringBitmap, OutOfBoundsError> {
+        let bit_len = self.bit_len();
+        if bit_len > 0 && u32::try_from(bit_len - 1).is_err() {
+            let bounds = 0..(u32::MAX as usize).saturating_add(1);
+            return Err(OutOfBoundsError::new(bit_len - 1, bounds, 0));
+        }
+
+        let mut res = roaring::RoaringBitmap::new();
+        for idx in self.ones() {
+            // Indexes are sorted, so every index is appended to the end
+            res.push(idx as u32);
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<D, N, B> StaticBitmap<D, B>
+where
+    D: ContainerRead<B, Slot = N> + AsRef<[N]> + Sync,
+    N: Number + Sync,
+    B: BitAccess,
+{
+    /// Returns number of ones in the bitmap, slots are split between threads of `rayon` pool.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new(vec![0b0000_1111u8; 10_000]);
+    /// assert_eq!(bitmap.par_count_ones(), bitmap.count_ones());
+    /// ```
+    pub fn par_count_ones(&self) -> usize {
+        par_count_ones_impl(self.data.as_ref())
+    }
+
+    /// Calculates intersection length, slots are split between threads of `rayon` pool.
+    ///
+    /// Falls back to sequential calculation if `other` is not a contiguous slice of slots.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new(vec![0b0000_1111u8; 10_000]);
+    /// assert_eq!(bitmap.par_intersection_len(&vec![0b0011_1100u8; 20_000]), 20_000);
+    /// ```
+    pub fn par_intersection_len<Rhs>(&self, other: &Rhs) -> usize
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        match other.as_slot_slice() {
+            Some(other) => par_intersection_len_impl(self.data.as_ref(), other),
+            None => intersection_len_impl(&self.data, other),
+        }
+    }
+}
+
+impl<D, B> AsRef<D> for StaticBitmap<D, B> {
+    fn as_ref(&self) -> &D {
+        &self.data
+    }
+}
+
+impl<D, B> AsMut<D> for StaticBitmap<D, B> {
+    fn as_mut(&mut self) -> &mut D {
+        &mut self.data
+    }
+}
+
+/// Indexes **slots**, not bits: `bitmap[i]` is `i`-th slot of the container.
+/// Use [`get`] to read a single bit.
+///
+/// ## Panic
+///
+/// Panics if `idx` is out of bounds of the slice of slots.
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{StaticBitmap, LSB};
+///
+/// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001u8, 0b0000_0010]);
+/// assert_eq!(bitmap[1], 0b0000_0010);
+/// bitmap[1] |= 0b1000_0000;
+/// assert!(bitmap.get(15));
+/// ```
+///
+/// [`get`]: crate::static_bitmap::StaticBitmap::get
+impl<D, N, B> Index<usize> for StaticBitmap<D, B>
+where
+    D: ContainerRead<B, Slot = N> + AsRef<[N]>,
+    N: Number,
+    B: BitAccess,
+{
+    type Output = N;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.data.as_ref()[idx]
+    }
+}
+
+/// Indexes **slots**, not bits, the same way as `Index` implementation does.
+impl<D, N, B> IndexMut<usize> for StaticBitmap<D, B>
+where
+    D: ContainerRead<B, Slot = N> + AsRef<[N]> + AsMut<[N]>,
+    N: Number,
+    B: BitAccess,
+{
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.data.as_mut()[idx]
+    }
+}
+
+impl<D, B> ContainerRead<B> for StaticBitmap<D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    type Slot = D::Slot;
+
+    fn get_slot(&self, idx: usize) -> Self::Slot {
+        self.data.get_slot(idx)
+    }
+
+    fn slots_count(&self) -> usize {
+        self.data.slots_count()
+    }
+
+    fn get_bit(&self, idx: usize) -> bool {
+        self.data.get_bit(idx)
+    }
+
+    fn bits_count(&self) -> usize {
+        self.data.bits_count()
+    }
+
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        self.data.as_slot_slice()
+    }
+}
+
+impl<D, B> ContainerWrite<B> for StaticBitmap<D, B>
+where
+    D: ContainerWrite<B>,
+    B: BitAccess,
+{
+    fn get_mut_slot(&mut self, idx: usize) -> &mut Self::Slot {
+        self.data.get_mut_slot(idx)
+    }
+
+    fn try_set_bit(&mut self, idx: usize, val: bool) -> Result<(), OutOfBoundsError> {
+        self.data.try_set_bit(idx, val)
+    }
+
+    fn set_bit_unchecked(&mut self, idx: usize, val: bool) {
+        self.data.set_bit_unchecked(idx, val)
+    }
+}
+
+impl<D, B> TryWithSlots for StaticBitmap<D, B>
+where
+    D: TryWithSlots,
+    B: BitAccess,
+{
+    fn try_with_slots(len: usize) -> Result<Self, WithSlotsError> {
+        Ok(Self {
+            data: D::try_with_slots(len)?,
+            phantom: Default::default(),
+        })
+    }
+
+    fn try_with_slots_exact(len: usize) -> Result<Self, WithSlotsError> {
+        Ok(Self {
+            data: D::try_with_slots_exact(len)?,
+            phantom: Default::default(),
+        })
+    }
+}
+
+/// Prints raw bytes of every slot, bytes of a slot are in little-endian order
+/// (the least significant byte first). See [`debug_bytes`] to choose the order.
+///
+/// [`debug_bytes`]: crate::static_bitmap::StaticBitmap::debug_bytes
+impl<D, N, B> Debug for StaticBitmap<D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&DebugBytes::new(&self.data, Endianness::Little), f)
+    }
+}
+
+/// Returns the bitmap with bit set, same as [`try_with_bit`] but panics on error.
+///
+/// ## Panic
+///
+/// Panics if `idx` is out of bounds.
+/// See non-panic function [`try_with_bit`].
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{StaticBitmap, LSB};
+///
+/// let bitmap = StaticBitmap::<_, LSB>::new([0u8; 2]) | 3 | 7;
+/// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![3, 7]);
+/// ```
+///
+/// [`try_with_bit`]: crate::static_bitmap::StaticBitmap::try_with_bit
+impl<D, N, B> BitOr<usize> for StaticBitmap<D, B>
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    type Output = Self;
+
+    fn bitor(self, idx: usize) -> Self::Output {
+        self.try_with_bit(idx).unwrap()
+    }
+}
+
+/// Returns the bitmap with bit cleared, same as [`try_without_bit`] but panics on error.
+///
+/// ## Panic
+///
+/// Panics if `idx` is out of bounds.
+/// See non-panic function [`try_without_bit`].
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{StaticBitmap, LSB};
+///
+/// let bitmap = StaticBitmap::<_, LSB>::new([0u8; 2]) | 3 | 7;
+/// assert_eq!((bitmap - 3).ones().collect::<Vec<_>>(), vec![7]);
+/// ```
+///
+/// [`try_without_bit`]: crate::static_bitmap::StaticBitmap::try_without_bit
+impl<D, N, B> Sub<usize> for StaticBitmap<D, B>
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    type Output = Self;
+
+    fn sub(self, idx: usize) -> Self::Output {
+        self.try_without_bit(idx).unwrap()
+    }
+}
+
+/// Updates the bitmap with intersection of itself and `rhs`, same as [`intersect_update`].
+///
+/// Slots of the bitmap that exceed `rhs` are intersected with implicit zeros, so they are cleared.
+/// Slots of `rhs` that exceed the bitmap are ignored. Container is never resized.
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{StaticBitmap, LSB};
+///
+/// let mut bitmap = StaticBitmap::<_, LSB>::new([0b0000_1001u8, 0b0000_0001]);
+/// bitmap &= &[0b0000_0011u8];
+/// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![0]);
+/// ```
+///
+/// [`intersect_update`]: crate::static_bitmap::StaticBitmap::intersect_update
+impl<D, N, B, Rhs> BitAndAssign<&Rhs> for StaticBitmap<D, B>
+where
+    D: ContainerWrite<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    fn bitand_assign(&mut self, rhs: &Rhs) {
+        intersect_update_impl(&mut self.data, rhs);
+    }
+}
+
+impl<D, N, B> From<D> for StaticBitmap<D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    fn from(f: D) -> Self {
+        Self {
+            data: f,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B> IntoIterator for StaticBitmap<D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    type Item = <IntoIter<D, B> as Iterator>::Item;
+    type IntoIter = IntoIter<D, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.data)
+    }
+}
+
+impl<'a, D, B> IntoIterator for &'a StaticBitmap<D, B>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    type Item = <Iter<'a, D, B> as Iterator>::Item;
+    type IntoIter = Iter<'a, D, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<D, B, Rhs, N> BitmapOps<Rhs, N, B> for StaticBitmap<D, B>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+{
+    fn combine_in<Dst>(&self, rhs: &Rhs, op: BitOp, dst: &mut Dst)
+    where
+        Dst: ContainerWrite<B, Slot = N>,
+    {
+        try_combine_in_impl(&self.data, rhs, op, dst).unwrap();
+    }
+
+    fn try_combine_in<Dst>(&self, rhs: &Rhs, op: BitOp, dst: &mut Dst) -> Result<(), CombineError>
+    where
+        Dst: ContainerWrite<B, Slot = N>,
+    {
+        try_combine_in_impl(&self.data, rhs, op, dst)
+    }
+
+    fn combine<Dst>(&self, rhs: &Rhs, op: BitOp) -> Dst
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    {
+        try_combine_impl(&self.data, rhs, op).unwrap()
+    }
+
+    fn try_combine<Dst>(&self, rhs: &Rhs, op: BitOp) -> Result<Dst, CombineError>
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    {
+        try_combine_impl(&self.data, rhs, op)
+    }
+
+    fn combine_len(&self, rhs: &Rhs, op: BitOp) -> usize {
+        combine_len_impl(&self.data, rhs, op)
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+    use crate::{Intersection, Union};
+    use crate::{MinimumRequiredStrategy, LSB, MSB};
+
+    #[test]
+    #[rustfmt::skip]
+    fn get_bit() {        
+        // Number
+        assert!(StaticBitmap::<u8, LSB>::new(1 << 0).get(0));
+        assert!(StaticBitmap::<u8, LSB>::new(1 << 1).get(1));
+        assert!(StaticBitmap::<u8, LSB>::new(1 << 2).get(2));
+        assert!(StaticBitmap::<u8, LSB>::new(1 << 3).get(3));
+        assert!(StaticBitmap::<u8, LSB>::new(1 << 4).get(4));
+        assert!(StaticBitmap::<u8, LSB>::new(1 << 5).get(5));
+        assert!(StaticBitmap::<u8, LSB>::new(1 << 6).get(6));
+        assert!(StaticBitmap::<u8, LSB>::new(1 << 7).get(7));
+        assert!(!StaticBitmap::<u8, LSB>::new(0b1111_1111).get(8));
+        
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 0).get(0));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 1).get(1));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 2).get(2));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 3).get(3));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 4).get(4));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 5).get(5));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 6).get(6));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 7).get(7));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 8).get(8));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 9).get(9));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 10).get(10));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 11).get(11));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 12).get(12));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 13).get(13));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 14).get(14));
+        assert!(StaticBitmap::<u16, LSB>::new(1 << 15).get(15));
+        assert!(!StaticBitmap::<u16, LSB>::new(0b1111_1111_1111_1111).get(16));
+        
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 0).get(0));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 1).get(1));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 2).get(2));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 3).get(3));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 4).get(4));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 5).get(5));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 6).get(6));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 7).get(7));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 8).get(8));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 9).get(9));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 10).get(10));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 11).get(11));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 12).get(12));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 13).get(13));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 14).get(14));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 15).get(15));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 16).get(16));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 17).get(17));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 18).get(18));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 19).get(19));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 20).get(20));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 21).get(21));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 22).get(22));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 23).get(23));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 24).get(24));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 25).get(25));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 26).get(26));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 27).get(27));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 28).get(28));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 29).get(29));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 30).get(30));
+        assert!(StaticBitmap::<u32, LSB>::new(1 << 31).get(31));
+        assert!(!StaticBitmap::<u32, LSB>::new(0b0000_0000_0000_0000_0000_0000_0000_0000).get(32));
+        
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 0).get(0));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 1).get(1));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 2).get(2));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 3).get(3));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 4).get(4));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 5).get(5));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 6).get(6));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 7).get(7));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 8).get(8));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 9).get(9));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 10).get(10));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 11).get(11));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 12).get(12));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 13).get(13));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 14).get(14));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 15).get(15));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 16).get(16));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 17).get(17));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 18).get(18));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 19).get(19));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 20).get(20));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 21).get(21));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 22).get(22));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 23).get(23));
+        assert!(StaticBitmap::<u64, LSB>::new(1 << 24).get(24));
         assert!(StaticBitmap::<u64, LSB>::new(1 << 25).get(25));
         assert!(StaticBitmap::<u64, LSB>::new(1 << 26).get(26));
         assert!(StaticBitmap::<u64, LSB>::new(1 << 27).get(27));
@@ -525,239 +2439,1210 @@ mod tests {
         assert!(StaticBitmap::<u64, LSB>::new(1 << 63).get(63));
         assert!(!StaticBitmap::<u64, LSB>::new(0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111).get(64));
         
-        // Slice
-        assert!(StaticBitmap::<&'static [u8], LSB>::new(&[1u8][..]).get(0));
-        assert!(StaticBitmap::<&'static [u8], LSB>::new(&[1u8, 1][..]).get(8));
-        assert!(!StaticBitmap::<&'static [u8], LSB>::new(&[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..]).get(999));
-        assert!(StaticBitmap::<&'static [u16], LSB>::new(&[1u16][..]).get(0));
-        assert!(StaticBitmap::<&'static [u16], LSB>::new(&[1u16, 1u16][..]).get(16));
-        assert!(!StaticBitmap::<&'static [u16], LSB>::new(&[0b1111_1111_1111_1111u16, 0b1111_1111_1111_1111, 0b1111_1111_1111_1111][..]).get(999));
-        assert!(StaticBitmap::<&'static [u32], LSB>::new(&[1u32][..]).get(0));
-        assert!(StaticBitmap::<&'static [u32], LSB>::new(&[1u32, 1][..]).get(32));
-        assert!(!StaticBitmap::<&'static [u32], LSB>::new(&[0b1111_1111_1111_1111_1111_1111_1111_1111u32, 0b1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111][..]).get(999));
-        assert!(StaticBitmap::<&'static [u64], LSB>::new(&[1u64][..]).get(0));
-        assert!(StaticBitmap::<&'static [u64], LSB>::new(&[1u64, 1][..]).get(64));
-        assert!(!StaticBitmap::<&'static [u64], LSB>::new(&[0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111][..]).get(999));
+        // Slice
+        assert!(StaticBitmap::<&'static [u8], LSB>::new(&[1u8][..]).get(0));
+        assert!(StaticBitmap::<&'static [u8], LSB>::new(&[1u8, 1][..]).get(8));
+        assert!(!StaticBitmap::<&'static [u8], LSB>::new(&[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..]).get(999));
+        assert!(StaticBitmap::<&'static [u16], LSB>::new(&[1u16][..]).get(0));
+        assert!(StaticBitmap::<&'static [u16], LSB>::new(&[1u16, 1u16][..]).get(16));
+        assert!(!StaticBitmap::<&'static [u16], LSB>::new(&[0b1111_1111_1111_1111u16, 0b1111_1111_1111_1111, 0b1111_1111_1111_1111][..]).get(999));
+        assert!(StaticBitmap::<&'static [u32], LSB>::new(&[1u32][..]).get(0));
+        assert!(StaticBitmap::<&'static [u32], LSB>::new(&[1u32, 1][..]).get(32));
+        assert!(!StaticBitmap::<&'static [u32], LSB>::new(&[0b1111_1111_1111_1111_1111_1111_1111_1111u32, 0b1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111][..]).get(999));
+        assert!(StaticBitmap::<&'static [u64], LSB>::new(&[1u64][..]).get(0));
+        assert!(StaticBitmap::<&'static [u64], LSB>::new(&[1u64, 1][..]).get(64));
+        assert!(!StaticBitmap::<&'static [u64], LSB>::new(&[0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111][..]).get(999));
+
+        let v = &[1u8][..];
+        assert!(StaticBitmap::<&[u8], LSB>::new(v).get(0));
+        let v = &[1u8, 1][..];
+        assert!(StaticBitmap::<&[u8], LSB>::new(v).get(8));
+        let v = &[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..];
+        assert!(!StaticBitmap::<&[u8], LSB>::new(v).get(999));
+        let v = &[1u16][..];
+        assert!(StaticBitmap::<&[u16], LSB>::new(v).get(0));
+        let v = &[1u16, 1u16][..];
+        assert!(StaticBitmap::<&[u16], LSB>::new(v).get(16));
+        let v = &[0b1111_1111_1111_1111u16, 0b1111_1111_1111_1111, 0b1111_1111_1111_1111][..];
+        assert!(!StaticBitmap::<&[u16], LSB>::new(v).get(999));
+        let v = &[1u32][..];
+        assert!(StaticBitmap::<&[u32], LSB>::new(v).get(0));
+        let v = &[1u32, 1][..];
+        assert!(StaticBitmap::<&[u32], LSB>::new(v).get(32));
+        let v = &[0b1111_1111_1111_1111_1111_1111_1111_1111u32, 0b1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111][..];
+        assert!(!StaticBitmap::<&[u32], LSB>::new(v).get(999));
+        let v = &[1u64][..];
+        assert!(StaticBitmap::<&[u64], LSB>::new(v).get(0));
+        let v = &[1u64, 1][..];
+        assert!(StaticBitmap::<&[u64], LSB>::new(v).get(64));
+        let v = &[0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111][..];
+        assert!(!StaticBitmap::<&[u64], LSB>::new(v).get(999));
+
+        // Array
+        assert!(StaticBitmap::<[u8; 1], LSB>::new([1; 1]).get(0));
+        assert!(StaticBitmap::<[u8; 2], LSB>::new([1; 2]).get(8));
+        assert!(!StaticBitmap::<[u8; 3], LSB>::new([0b1111_1111; 3]).get(999));
+        assert!(StaticBitmap::<[u16; 1], LSB>::new([1; 1]).get(0));
+        assert!(StaticBitmap::<[u16; 2], LSB>::new([1; 2]).get(16));
+        assert!(!StaticBitmap::<[u16; 3], LSB>::new([0b1111_1111_1111_1111; 3]).get(999));
+        assert!(StaticBitmap::<[u32; 1], LSB>::new([1; 1]).get(0));
+        assert!(StaticBitmap::<[u32; 2], LSB>::new([1; 2]).get(32));
+        assert!(!StaticBitmap::<[u32; 3], LSB>::new([0b1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+        assert!(StaticBitmap::<[u64; 1], LSB>::new([1; 1]).get(0));
+        assert!(StaticBitmap::<[u64; 2], LSB>::new([1; 2]).get(64));
+        assert!(!StaticBitmap::<[u64; 3], LSB>::new([0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+
+        // Vec
+        assert!(StaticBitmap::<Vec<u8>, LSB>::new(vec![1; 1]).get(0));
+        assert!(StaticBitmap::<Vec<u8>, LSB>::new(vec![1; 2]).get(8));
+        assert!(!StaticBitmap::<Vec<u8>, LSB>::new(vec![0b1111_1111; 3]).get(999));
+        assert!(StaticBitmap::<Vec<u16>, LSB>::new(vec![1; 1]).get(0));
+        assert!(StaticBitmap::<Vec<u16>, LSB>::new(vec![1; 2]).get(16));
+        assert!(!StaticBitmap::<Vec<u16>, LSB>::new(vec![0b1111_1111_1111_1111; 3]).get(999));
+        assert!(StaticBitmap::<Vec<u32>, LSB>::new(vec![1; 1]).get(0));
+        assert!(StaticBitmap::<Vec<u32>, LSB>::new(vec![1; 2]).get(32));
+        assert!(!StaticBitmap::<Vec<u32>, LSB>::new(vec![0b1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+        assert!(StaticBitmap::<Vec<u64>, LSB>::new(vec![1; 1]).get(0));
+        assert!(StaticBitmap::<Vec<u64>, LSB>::new(vec![1; 2]).get(64));
+        assert!(!StaticBitmap::<Vec<u64>, LSB>::new(vec![0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+
+        // Bytes
+        #[cfg(feature = "bytes")]
+        {
+            use bytes::{Bytes, BytesMut};
+            assert!(StaticBitmap::<Bytes, LSB>::new(Bytes::from_static(&[1])).get(0));
+            assert!(StaticBitmap::<Bytes, LSB>::new(Bytes::from_static(&[1, 1])).get(8));
+            assert!(!StaticBitmap::<Bytes, LSB>::new(Bytes::from_static(&[0b1111_1111, 0b1111_1111, 0b1111_1111])).get(999));
+            assert!(StaticBitmap::<BytesMut, LSB>::new(BytesMut::from(&[1u8][..])).get(0));
+            assert!(StaticBitmap::<BytesMut, LSB>::new(BytesMut::from(&[1u8, 1][..])).get(8));
+            assert!(!StaticBitmap::<BytesMut, LSB>::new(BytesMut::from(&[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..])).get(999));
+        }
+
+        // SmallVec
+        #[cfg(feature = "smallvec")]
+        {
+            use smallvec::SmallVec;
+            assert!(StaticBitmap::<SmallVec<[u8; 1]>, LSB>::new(SmallVec::from([1u8])).get(0));
+            assert!(StaticBitmap::<SmallVec<[u8; 2]>, LSB>::new(SmallVec::from([1u8, 1])).get(8));
+            assert!(!StaticBitmap::<SmallVec<[u8; 3]>, LSB>::new(SmallVec::from([0b1111_1111u8, 0b1111_1111, 0b1111_1111])).get(999));
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn set_bit() {
+        // Number
+        let mut v = StaticBitmap::<u8, LSB>::default();
+        v.set(0, true);
+        v.set(7, true);
+        assert!(v.try_set(8, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(7));
+
+        let mut v = StaticBitmap::<u16, LSB>::default();
+        v.set(0, true);
+        v.set(15, true);
+        assert!(v.try_set(16, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(15));
+
+        let mut v = StaticBitmap::<u32, LSB>::default();
+        v.set(0, true);
+        v.set(31, true);
+        assert!(v.try_set(32, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(31));
+        
+        let mut v = StaticBitmap::<u64, LSB>::default();
+        v.set(0, true);
+        v.set(63, true);
+        assert!(v.try_set(64, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(63));
+        
+        // Slice
+        let mut inner = vec![0, 0];
+        let mut v = StaticBitmap::<&mut [u8], LSB>::new(inner.as_mut_slice());
+        v.set(0, true);
+        v.set(15, true);
+        assert!(v.try_set(16, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(15));
+
+        let mut inner = vec![0, 0];
+        let mut v = StaticBitmap::<&mut [u16], LSB>::new(inner.as_mut_slice());
+        v.set(0, true);
+        v.set(31, true);
+        assert!(v.try_set(32, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(31));
+
+        let mut inner = vec![0, 0];
+        let mut v = StaticBitmap::<&mut [u32], LSB>::new(inner.as_mut_slice());
+        v.set(0, true);
+        v.set(63, true);
+        assert!(v.try_set(64, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(63));
+
+        let mut inner = vec![0, 0];
+        let mut v = StaticBitmap::<&mut [u64], LSB>::new(inner.as_mut_slice());
+        v.set(0, true);
+        v.set(127, true);
+        assert!(v.try_set(128, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(127));
+
+        // Array
+        let mut v = StaticBitmap::<[u8; 2], LSB>::default();
+        v.set(0, true);
+        v.set(15, true);
+        assert!(v.try_set(16, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(15));
+
+        let mut v = StaticBitmap::<[u16; 2], LSB>::default();
+        v.set(0, true);
+        v.set(31, true);
+        assert!(v.try_set(32, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(31));
+
+        let mut v = StaticBitmap::<[u32; 2], LSB>::default();
+        v.set(0, true);
+        v.set(63, true);
+        assert!(v.try_set(64, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(63));
+
+        let mut v = StaticBitmap::<[u64; 2], LSB>::default();
+        v.set(0, true);
+        v.set(127, true);
+        assert!(v.try_set(128, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(127));
+        
+        // Vec
+        let mut v = StaticBitmap::<Vec<u8>, LSB>::new(vec![0, 0]);
+        v.set(0, true);
+        v.set(15, true);
+        assert!(v.try_set(16, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(15));
+
+        let mut v = StaticBitmap::<Vec<u16>, LSB>::new(vec![0, 0]);
+        v.set(0, true);
+        v.set(31, true);
+        assert!(v.try_set(32, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(31));
+
+        let mut v = StaticBitmap::<Vec<u32>, LSB>::new(vec![0, 0]);
+        v.set(0, true);
+        v.set(63, true);
+        assert!(v.try_set(64, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(63));
+
+        let mut v = StaticBitmap::<Vec<u64>, LSB>::new(vec![0, 0]);
+        v.set(0, true);
+        v.set(127, true);
+        assert!(v.try_set(128, true).is_err());
+        assert!(v.get(0));
+        assert!(v.get(127));
+
+        // Bytes
+        #[cfg(feature = "bytes")]
+        {
+            use bytes::{BytesMut};
+            let mut v = StaticBitmap::<BytesMut, LSB>::new(BytesMut::zeroed(2));
+            v.set(0, true);
+            v.set(15, true);
+            assert!(v.try_set(16, true).is_err());
+            assert!(v.get(0));
+            assert!(v.get(15));
+        }
+        
+        #[cfg(feature = "smallvec")]
+        {
+            use smallvec::{SmallVec, smallvec};
+            let mut v = StaticBitmap::<SmallVec<[u8; 2]>, LSB>::new(smallvec![0, 0]);
+            v.set(0, true);
+            v.set(15, true);
+            assert!(v.try_set(16, true).is_err());
+            assert!(v.get(0));
+            assert!(v.get(15));
+        }
+    }
+
+    #[test]
+    fn debug_logical() {
+        use crate::MSB;
+
+        let mut v = StaticBitmap::<u16, MSB>::default();
+        v.set(0, true);
+        let s = format!("{:?}", v.debug_logical());
+        assert!(s.starts_with('1'));
+        assert_eq!(s.len(), 16);
+        assert_eq!(s.matches('1').count(), 1);
+        // Byte-wise representation doesn't start with 0th bit
+        assert_eq!(format!("{:?}", v), "[0b00000000, 0b10000000]");
+    }
+
+    #[test]
+    fn remove_insert_bit() {
+        let mut v = StaticBitmap::<u8, LSB>::new(0b1101);
+        assert!(v.remove_bit(2));
+        assert_eq!(v.into_inner(), 0b0101);
+
+        let mut v =
+            StaticBitmap::<[u16; 2], LSB>::new([0b1000_0000_0000_0001, 0b0000_0000_0000_0001]);
+        assert!(!v.remove_bit(1));
+        assert_eq!(
+            v.into_inner(),
+            [0b1100_0000_0000_0001, 0b0000_0000_0000_0000]
+        );
+
+        let mut v = StaticBitmap::<[u8; 2], LSB>::new([0b1000_0001, 0b1000_0000]);
+        v.insert_bit(0, true);
+        assert_eq!(v.as_ref(), &[0b0000_0011, 0b0000_0001]);
+        assert!(v.try_insert_bit(16, true).is_err());
+        assert!(v.try_remove_bit(16).is_err());
+        assert_eq!(v.into_inner(), [0b0000_0011, 0b0000_0001]);
+    }
+
+    #[test]
+    fn combine_var() {
+        use crate::MinimumRequiredStrategy;
+
+        let v = StaticBitmap::<[u8; 2], LSB>::new([0b0000_1001, 0b0000_1000]);
+        let mut r = v.intersection_var::<_, MinimumRequiredStrategy>(&vec![0b0000_1111u8]);
+        assert_eq!(r.as_ref(), &vec![0b0000_1001]);
+        r.set(9, true);
+        assert_eq!(r.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+
+        let mut r = v.union_var::<_, MinimumRequiredStrategy>(&vec![0b0000_0110u8]);
+        assert_eq!(r.as_ref(), &vec![0b0000_1111, 0b0000_1000]);
+        r.set(16, true);
+        assert_eq!(r.as_ref(), &vec![0b0000_1111, 0b0000_1000, 0b0000_0001]);
+    }
+
+    #[test]
+    fn update_in_place() {
+        let lhs = [0b0000_1001u8, 0b0000_1000];
+        let rhs = [0b0000_0011u8, 0b0100_0000];
+
+        let mut v = StaticBitmap::<_, LSB>::new(lhs);
+        v.union_update(&rhs);
+        assert_eq!(
+            v.into_inner(),
+            StaticBitmap::<_, LSB>::new(lhs).union::<[u8; 2]>(&rhs)
+        );
+
+        let mut v = StaticBitmap::<_, LSB>::new(lhs);
+        v.intersect_update(&rhs);
+        assert_eq!(
+            v.into_inner(),
+            StaticBitmap::<_, LSB>::new(lhs).intersection::<[u8; 2]>(&rhs)
+        );
+
+        let mut v = StaticBitmap::<_, LSB>::new(lhs);
+        v.difference_update(&rhs);
+        assert_eq!(v.into_inner(), [0b0000_1000, 0b0000_1000]);
+
+        let mut v = StaticBitmap::<_, LSB>::new(lhs);
+        v.symmetric_difference_update(&rhs);
+        assert_eq!(v.into_inner(), [0b0000_1010, 0b0100_1000]);
+
+        // Tail of rhs is ignored, tail of self is cleared by intersection
+        let mut v = StaticBitmap::<_, LSB>::new([0b0000_0001u8]);
+        v.union_update(&rhs);
+        assert_eq!(v.into_inner(), [0b0000_0011]);
+        let mut v = StaticBitmap::<_, LSB>::new([0b0000_0001u8, 0b0000_0001]);
+        v.intersect_update(&[0b0000_0001u8]);
+        assert_eq!(v.into_inner(), [0b0000_0001, 0b0000_0000]);
+    }
+
+    #[test]
+    fn empty_full() {
+        assert!(StaticBitmap::<_, LSB>::new([0u8; 2]).is_empty());
+        assert!(!StaticBitmap::<_, LSB>::new([0u8; 2]).is_full());
+        assert!(StaticBitmap::<_, LSB>::new([0xFFu8; 2]).is_full());
+        assert!(!StaticBitmap::<_, LSB>::new([0xFFu8; 2]).is_empty());
+        assert!(!StaticBitmap::<_, MSB>::new([0xFFu8, 0xFE]).is_full());
+        assert!(!StaticBitmap::<_, LSB>::new([0u8, 0b1000_0000]).is_empty());
+
+        // Padding bits of the last slot are ignored
+        let bools = crate::bool_vec::BoolVecContainer::<LSB>::from(vec![true; 11]);
+        assert!(StaticBitmap::<_, LSB>::new(bools).is_full());
+        let bools = crate::bool_vec::BoolVecContainer::<MSB>::from(vec![true; 11]);
+        assert!(StaticBitmap::<_, MSB>::new(bools).is_full());
+        let mut bools = vec![true; 11];
+        bools[10] = false;
+        let bools = crate::bool_vec::BoolVecContainer::<LSB>::from(bools);
+        assert!(!StaticBitmap::<_, LSB>::new(bools).is_full());
+    }
+
+    #[test]
+    fn slots_iterators() {
+        let v = StaticBitmap::<_, LSB>::new([0b0000_1001u16, 0, 0b1000_0000_0000_0000]);
+        assert!(v.slots_ref().copied().eq(v.slots()));
+        assert_eq!(
+            v.slots().collect::<Vec<_>>(),
+            vec![0b0000_1001, 0, 0b1000_0000_0000_0000]
+        );
+        assert_eq!(
+            v.into_slots().collect::<Vec<_>>(),
+            vec![0b0000_1001, 0, 0b1000_0000_0000_0000]
+        );
+    }
+
+    #[test]
+    fn try_from_bits() {
+        let v = StaticBitmap::<[u8; 2], LSB>::try_from_bits((0..16).map(|i| i % 3 == 0)).unwrap();
+        assert_eq!(v.into_inner(), [0b0100_1001, 0b1001_0010]);
+
+        let v = StaticBitmap::<[u16; 1], MSB>::try_from_bits([true, true]).unwrap();
+        assert_eq!(v.into_inner(), [0b1100_0000_0000_0000]);
+
+        let v = StaticBitmap::<[u8; 2], LSB>::try_from_bits(std::iter::empty()).unwrap();
+        assert_eq!(v.into_inner(), [0, 0]);
+
+        assert!(StaticBitmap::<[u8; 2], LSB>::try_from_bits([false; 17]).is_err());
+        assert!(StaticBitmap::<[u8; 0], LSB>::try_from_bits([true]).is_err());
+    }
+
+    #[test]
+    fn slot_rank_prefix() {
+        let v = StaticBitmap::<_, LSB>::new([0xFFFFu16, 0, 0b0000_0111, 0x8000, 1]);
+        let prefix = v.slot_rank_prefix();
+        assert_eq!(prefix, vec![0, 16, 16, 19, 20]);
+        for (i, rank) in prefix.into_iter().enumerate() {
+            let expected = StaticBitmap::<_, LSB>::new(&v.as_ref()[..i]).count_ones();
+            assert_eq!(rank, expected);
+        }
+
+        assert!(StaticBitmap::<_, LSB>::new([0u8; 0])
+            .slot_rank_prefix()
+            .is_empty());
+    }
+
+    #[test]
+    fn single_number_and_array() {
+        let number = StaticBitmap::<_, LSB>::new(0b1000_1001u8);
+        let array = StaticBitmap::<_, LSB>::from_single(0b1000_1001u8);
+        assert_eq!(array.as_ref(), &[0b1000_1001]);
+
+        // Both store the same bits
+        assert_eq!(number.slots_count(), array.slots_count());
+        assert_eq!(number.bits_count(), array.bits_count());
+        assert!(number.iter().by_bits().eq(array.iter().by_bits()));
+        assert_eq!(number.compare(&array), crate::BitmapOrdering::Equal);
+
+        // Number can be the result of combinators only if the result takes one slot
+        let rhs = [0b0000_0001u8, 0b0000_0001];
+        assert_eq!(number.intersection::<u8>(&rhs), 0b0000_0001);
+        assert_eq!(array.intersection::<[u8; 1]>(&rhs), [0b0000_0001]);
+        assert!(number.try_union::<u8>(&rhs).is_err());
+        assert_eq!(array.union::<[u8; 2]>(&rhs), [0b1000_1001, 0b0000_0001]);
+
+        assert_eq!(array.into_single().into_inner(), number.into_inner());
+    }
+
+    #[test]
+    fn fill_pseudo_random() {
+        let mut v = StaticBitmap::<_, LSB>::new([0b1010_1010u8; 16]);
+        v.fill_pseudo_random(7, 1.0);
+        assert!(v.is_full());
+        v.fill_pseudo_random(7, 0.0);
+        assert!(v.is_empty());
+
+        let mut v = StaticBitmap::<_, MSB>::new([0u64; 64]);
+        v.fill_pseudo_random(7, 0.25);
+        let ones = v.count_ones();
+        assert!((900..1150).contains(&ones), "{}", ones);
+    }
+
+    #[test]
+    fn difference_len() {
+        let lhs = [0b1111_0000u8, 0b0000_0011, 0b1000_0001];
+        for rhs in [
+            &[][..],
+            &[0xFFu8][..],
+            &[0b0011_1100, 0b0000_0001][..],
+            &[0, 0, 0, 0xFF][..],
+        ] {
+            let mut diff = StaticBitmap::<_, LSB>::new(lhs);
+            diff.difference_update(&rhs);
+            assert_eq!(
+                StaticBitmap::<_, LSB>::new(lhs).difference_len(&rhs),
+                diff.count_ones()
+            );
+        }
+    }
+
+    #[test]
+    fn ids() {
+        let v = StaticBitmap::<_, MSB>::new([0b1000_0001u8, 0, 0b0010_0000]);
+        assert_eq!(v.ids().collect::<Vec<_>>(), vec![0, 7, 18]);
+        assert!(v.ids().eq(v
+            .iter()
+            .by_bits()
+            .enumerate()
+            .filter(|(_, b)| *b)
+            .map(|(i, _)| i)));
+        assert_eq!(StaticBitmap::<_, LSB>::new([0u16; 4]).ids().next(), None);
+    }
+
+    #[test]
+    fn introspection() {
+        let v = StaticBitmap::<_, LSB>::new([0u8; 2]);
+        assert_eq!(v.slot_bits(), 8);
+        assert_eq!(v.bit_order(), crate::BitOrder::LSB);
+
+        let v = StaticBitmap::<_, MSB>::new(0u128);
+        assert_eq!(v.slot_bits(), 128);
+        assert_eq!(v.bit_order(), crate::BitOrder::MSB);
+    }
+
+    #[test]
+    fn set_bits_from() {
+        let bits = [
+            true, false, true, true, false, false, true, true, true, false,
+        ];
+
+        let mut v = StaticBitmap::<_, MSB>::new([0u16]);
+        v.set_bits_from(5, &bits);
+        assert_eq!(v.into_inner(), [0b0000_0101_1001_1100]);
+
+        let mut v = StaticBitmap::<_, LSB>::new([0u8]);
+        assert!(v.try_set_bits_from(5, &bits).is_err());
+        assert!(v.try_set_bits_from(usize::MAX, &bits).is_err());
+        assert!(v.try_set_bits_from(8, &[]).is_ok());
+        assert_eq!(v.into_inner(), [0]);
+    }
+
+    #[test]
+    fn get_bits_into() {
+        let v = StaticBitmap::<_, MSB>::new([0b0000_0101_1001_1100u16]);
+        let mut out = [true; 12];
+        v.get_bits_into(5, &mut out);
+        assert_eq!(
+            out,
+            [true, false, true, true, false, false, true, true, true, false, false, false]
+        );
+
+        let mut out = [true; 3];
+        v.get_bits_into(usize::MAX, &mut out);
+        assert_eq!(out, [false; 3]);
+    }
+
+    #[test]
+    fn bytes_padded() {
+        let v = StaticBitmap::<_, MSB>::new([0b1000_0000u8, 0b0000_0011, 0b0100_0000]);
+        let bytes = v.to_bytes_padded(8);
+        assert_eq!(
+            bytes,
+            vec![0b1000_0000, 0b0000_0011, 0b0100_0000, 0, 0, 0, 0, 0]
+        );
+
+        let restored = StaticBitmap::<Vec<u32>, MSB>::from_bytes_padded(&bytes);
+        assert_eq!(restored.as_ref().len(), 2);
+        assert_eq!(restored.to_btree_set(), v.to_btree_set());
+        assert_eq!(restored.to_bytes_padded(3), v.to_byte_vec());
+    }
+
+    /// Container that counts slot reads, to check that methods stop early.
+    struct CountingReads {
+        slots: Vec<u8>,
+        reads: std::cell::Cell<usize>,
+    }
+
+    impl CountingReads {
+        fn new(slots: Vec<u8>) -> Self {
+            Self {
+                slots,
+                reads: Default::default(),
+            }
+        }
+    }
+
+    impl<B: BitAccess> ContainerRead<B> for CountingReads {
+        type Slot = u8;
+
+        fn get_slot(&self, idx: usize) -> Self::Slot {
+            self.reads.set(self.reads.get() + 1);
+            self.slots[idx]
+        }
+
+        fn slots_count(&self) -> usize {
+            self.slots.len()
+        }
+    }
+
+    #[test]
+    fn has_at_least_ones_stops_early() {
+        let v = StaticBitmap::<_, LSB>::new(CountingReads::new(vec![0xFF; 100]));
+        assert!(v.has_at_least_ones(12));
+        assert_eq!(v.data.reads.replace(0), 2);
+        assert!(!v.has_at_most_ones(20));
+        assert_eq!(v.data.reads.replace(0), 3);
+
+        assert!(v.has_at_least_ones(800));
+        assert!(!v.has_at_least_ones(801));
+        assert!(v.has_at_most_ones(800));
+        assert!(v.has_at_least_ones(0));
+    }
+
+    #[test]
+    fn has_at_least_ones_over_slice() {
+        let mut slots = vec![0u8; 200];
+        slots[0] = 0b0000_0001;
+        slots[63] = 0b0000_0011;
+        slots[64] = 0b1111_1111;
+        slots[199] = 0b1000_0000;
+        let v = StaticBitmap::<_, LSB>::new(slots);
+
+        for n in 0..=12 {
+            assert!(v.has_at_least_ones(n), "{}", n);
+            assert!(v.has_at_most_ones(n + 12), "{}", n);
+        }
+        assert!(!v.has_at_least_ones(13));
+        assert!(!v.has_at_most_ones(11));
+        assert!(!v.has_at_most_ones(0));
+        assert!(v.has_at_most_ones(usize::MAX));
+        assert!(StaticBitmap::<_, LSB>::new(Vec::<u8>::new()).has_at_most_ones(0));
+    }
+
+    #[test]
+    fn intersection_is_empty() {
+        let mut slots = vec![0b0101_0101u8; 100];
+        slots[1] = 0b1010_1010;
+        let v = StaticBitmap::<_, LSB>::new(CountingReads::new(slots));
+        let rhs = [0b1010_1010u8; 100];
+        assert!(!v.intersection_is_empty(&rhs));
+        assert_eq!(v.data.reads.replace(0), 2);
+        assert!(Intersection::<_, _, LSB>::intersection_len(&v, &rhs) > 0);
+
+        let v = StaticBitmap::<_, LSB>::new([0b0101_0101u8, 0b0000_0000]);
+        for rhs in [
+            [0b1010_1010u8, 0xFF],
+            [0b0000_0001, 0],
+            [0, 0],
+            [0xFF, 0xFF],
+        ] {
+            assert_eq!(
+                v.intersection_is_empty(&rhs),
+                v.intersection_len(&rhs) == 0,
+                "{:?}",
+                rhs
+            );
+        }
+    }
+
+    #[test]
+    fn first_nonzero_slot() {
+        for len in [1, 15, 16, 17, 100] {
+            let mut slots = vec![0u8; len];
+            slots[len - 1] = 0b0100_0000;
+            let v = StaticBitmap::<_, LSB>::new(slots.clone());
+            assert_eq!(v.first_nonzero_slot(), Some(len - 1));
+            assert!(!v.is_empty());
+
+            // Same through container without contiguous slice
+            let v = StaticBitmap::<_, LSB>::new(CountingReads::new(slots));
+            assert_eq!(v.first_nonzero_slot(), Some(len - 1));
+        }
+
+        let v = StaticBitmap::<_, LSB>::new(vec![0u64; 40]);
+        assert_eq!(v.first_nonzero_slot(), None);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn bit_ops() {
+        let v = StaticBitmap::<_, MSB>::new([0u8; 2]) | 3 | 7;
+        assert_eq!(v.to_btree_set(), [3, 7].into_iter().collect());
+        let v = v - 3 - 10;
+        assert_eq!(v.to_btree_set(), [7].into_iter().collect());
+
+        assert!(v.try_with_bit(16).is_err());
+        assert!(StaticBitmap::<_, MSB>::new(0u8).try_without_bit(8).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn bit_or_out_of_bounds() {
+        let _ = StaticBitmap::<_, LSB>::new([0u8; 2]) | 16;
+    }
+
+    #[test]
+    fn slot_chunks() {
+        let v = StaticBitmap::<_, LSB>::new((0..10u32).collect::<Vec<_>>());
+        let chunks = v.slot_chunks(4).collect::<Vec<_>>();
+        assert_eq!(chunks, vec![&[0, 1, 2, 3][..], &[4, 5, 6, 7], &[8, 9]]);
+
+        assert_eq!(
+            v.slot_chunks(5).map(<[u32]>::len).collect::<Vec<_>>(),
+            vec![5, 5]
+        );
+        assert_eq!(v.slot_chunks(20).count(), 1);
+        assert_eq!(
+            StaticBitmap::<_, LSB>::new(Vec::<u8>::new())
+                .slot_chunks(3)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn to_slot_bytes() {
+        let v = StaticBitmap::<_, LSB>::new(0xAABBu16);
+        assert_eq!(v.to_slot_bytes(Endianness::Big), vec![0xAA, 0xBB]);
+        assert_eq!(v.to_slot_bytes(Endianness::Little), vec![0xBB, 0xAA]);
+        assert_eq!(v.to_slot_bytes(Endianness::default()), vec![0xBB, 0xAA]);
+
+        let v = StaticBitmap::<_, MSB>::new([0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10u128]);
+        assert_eq!(
+            v.to_slot_bytes(Endianness::Big),
+            (1..=16u8).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            format!("{:?}", v),
+            format!("{:?}", v.debug_bytes(Endianness::Little))
+        );
+    }
+
+    #[test]
+    fn reinterpret_bits() {
+        let v = StaticBitmap::<_, LSB>::new([0b0000_0011u8, 0b1000_0000]);
+        assert_eq!(v.to_btree_set(), [0, 1, 15].into_iter().collect());
+
+        let v = v.reinterpret_bits::<MSB>();
+        assert_eq!(v.to_btree_set(), [6, 7, 8].into_iter().collect());
+        assert_eq!(v.as_ref(), &[0b0000_0011u8, 0b1000_0000]);
+
+        let v = v.reinterpret_bits::<LSB>();
+        assert_eq!(v.to_btree_set(), [0, 1, 15].into_iter().collect());
+    }
+
+    #[test]
+    fn step_bits() {
+        let v = StaticBitmap::<_, MSB>::new([0b1010_0110u8]);
+        assert_eq!(
+            v.step_bits(2).collect::<Vec<_>>(),
+            vec![true, true, false, true]
+        );
+        assert_eq!(v.step_bits(1).count(), 8);
+        assert_eq!(v.step_bits(8).collect::<Vec<_>>(), vec![true]);
+        assert_eq!(v.step_bits(100).collect::<Vec<_>>(), vec![true]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_bits_zero() {
+        let _ = StaticBitmap::<_, MSB>::new([0u8]).step_bits(0);
+    }
+
+    #[test]
+    fn index_slots() {
+        let mut v = StaticBitmap::<_, LSB>::new(vec![0u16, 0b0000_0000_0000_0010, 0]);
+        assert_eq!(v[1], 0b0000_0000_0000_0010);
+        assert!(v.get(17));
+
+        v[2] = 0b1000_0000_0000_0000;
+        assert!(v.get(47));
+        assert_eq!(v.ones().collect::<Vec<_>>(), vec![17, 47]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_slots_out_of_bounds() {
+        let v = StaticBitmap::<_, LSB>::new([0u8; 2]);
+        let _ = v[2];
+    }
+
+    #[test]
+    fn intersection_reuse() {
+        let v = StaticBitmap::<_, LSB>::new([0b1111_0000u16, 0b0000_1111, 0xFFFF]);
+        let mut scratch = Vec::new();
+
+        v.intersection_reuse(
+            &[0b1010_1010u16, 0xFFFF, 0b1000_0000_0000_0000, 0xFFFF],
+            &mut scratch,
+        );
+        assert_eq!(
+            scratch,
+            vec![0b1010_0000, 0b0000_1111, 0b1000_0000_0000_0000]
+        );
+        let capacity = scratch.capacity();
+
+        v.intersection_reuse(&[0b0001_0001u16], &mut scratch);
+        assert_eq!(scratch, vec![0b0001_0000]);
+        assert_eq!(scratch.capacity(), capacity);
+
+        v.intersection_reuse(&Vec::<u16>::new(), &mut scratch);
+        assert!(scratch.is_empty());
+    }
+
+    #[test]
+    fn set_changed() {
+        let mut bitmap = StaticBitmap::<_, LSB>::new([0u8; 2]);
+        assert!(bitmap.set_changed(9, true).unwrap());
+        assert!(!bitmap.set_changed(9, true).unwrap());
+        assert!(!bitmap.set_changed(8, false).unwrap());
+        assert!(bitmap.set_changed(9, false).unwrap());
+        assert!(bitmap.set_changed(16, false).is_err());
+        assert_eq!(bitmap.into_inner(), [0, 0]);
+    }
+
+    #[test]
+    fn for_each_slot_mut() {
+        let mut bitmap = StaticBitmap::<_, LSB>::new(vec![0xFFu16; 3]);
+        let bit_len = 36;
+        let mut visited = Vec::new();
+        bitmap.for_each_slot_mut(|idx, slot| {
+            visited.push(idx);
+            let start = idx * 16;
+            if start + 16 > bit_len {
+                *slot &= (1 << (bit_len - start)) - 1;
+            }
+        });
+        assert_eq!(visited, vec![0, 1, 2]);
+        assert_eq!(bitmap.into_inner(), vec![0xFF, 0xFF, 0b0000_1111]);
+    }
+
+    #[test]
+    fn set_range() {
+        let mut bitmap = StaticBitmap::<_, LSB>::new([0u16; 2]);
+        bitmap.set_range(3..29, true);
+        assert_eq!(
+            bitmap.ones().collect::<Vec<_>>(),
+            (3..29).collect::<Vec<_>>()
+        );
+        bitmap.set_range(5..16, false);
+        assert_eq!(
+            bitmap.into_inner(),
+            [0b0000_0000_0001_1000, 0b0001_1111_1111_1111]
+        );
+
+        let mut bitmap = StaticBitmap::<_, LSB>::new([0u8; 2]);
+        assert!(bitmap.try_set_range(10..17, true).is_err());
+        assert_eq!(bitmap.count_ones(), 0);
+        assert!(bitmap.try_set_range(20..20, true).is_ok());
+    }
+
+    #[test]
+    fn intersection_consuming() {
+        let bitmap = StaticBitmap::<_, LSB>::new(vec![0b1010_1010u8, 0b1111_0000, 0b0000_0001]);
+        let rhs = vec![0b0110_0110u8, 0b1000_0001];
+        let ptr = rhs.as_ptr();
+        let res = bitmap.intersection_consuming(rhs);
+        assert_eq!(res.as_ref().as_ptr(), ptr);
+        assert_eq!(res.into_inner(), vec![0b0010_0010, 0b1000_0000]);
+
+        let bitmap = StaticBitmap::<_, LSB>::new(vec![0b1010_1010u8]);
+        let rhs = vec![0b0110_0110u8, 0b1000_0001];
+        let ptr = rhs.as_ptr();
+        let res = bitmap.intersection_consuming(rhs);
+        assert_eq!(res.as_ref().as_ptr(), ptr);
+        assert_eq!(res.into_inner(), vec![0b0010_0010, 0b0000_0000]);
+
+        let lhs = StaticBitmap::<_, LSB>::new(vec![0b1100_1010u8, 0b0101_1111]);
+        let rhs = vec![0b0110_0110u8, 0b1000_0001];
+        let expected = lhs.intersection::<Vec<u8>>(&rhs);
+        assert_eq!(lhs.intersection_consuming(rhs).into_inner(), expected);
+    }
+
+    #[test]
+    fn new_const() {
+        const FLAGS: StaticBitmap<[u16; 2], MSB> =
+            StaticBitmap::new_const([0b1000_0000_0000_0001, 0]);
+        static TABLE: StaticBitmap<[u8; 3], LSB> = StaticBitmap::new_const([0, 0b0000_0100, 0]);
+
+        assert!(FLAGS.get(0));
+        assert!(FLAGS.get(15));
+        assert!(!FLAGS.get(16));
+        assert_eq!(FLAGS.ones().collect::<Vec<_>>(), vec![0, 15]);
+        assert!(TABLE.get(10));
+        assert_eq!(TABLE.count_ones(), 1);
+    }
+
+    #[test]
+    fn bits_eq_agrees_with_content() {
+        let cases: &[&[u8]] = &[
+            &[],
+            &[0],
+            &[0, 0, 0],
+            &[1],
+            &[1, 0],
+            &[0, 1],
+            &[0b1000_0000, 0b0000_0001],
+            &[0b1000_0000, 0b0000_0011, 0],
+        ];
+        for lhs in cases {
+            for rhs in cases {
+                let lhs_bitmap = StaticBitmap::<_, LSB>::new(*lhs);
+                let rhs_bitmap = StaticBitmap::<_, LSB>::new(*rhs);
+                let expected = lhs_bitmap.ones().eq(rhs_bitmap.ones());
+                assert_eq!(lhs_bitmap.bits_eq(rhs), expected, "{:?} {:?}", lhs, rhs);
+                assert_eq!(
+                    lhs_bitmap.bits_eq(rhs),
+                    lhs_bitmap.compare(rhs) == BitmapOrdering::Equal
+                );
+            }
+        }
+    }
 
-        let v = &[1u8][..];
-        assert!(StaticBitmap::<&[u8], LSB>::new(v).get(0));
-        let v = &[1u8, 1][..];
-        assert!(StaticBitmap::<&[u8], LSB>::new(v).get(8));
-        let v = &[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..];
-        assert!(!StaticBitmap::<&[u8], LSB>::new(v).get(999));
-        let v = &[1u16][..];
-        assert!(StaticBitmap::<&[u16], LSB>::new(v).get(0));
-        let v = &[1u16, 1u16][..];
-        assert!(StaticBitmap::<&[u16], LSB>::new(v).get(16));
-        let v = &[0b1111_1111_1111_1111u16, 0b1111_1111_1111_1111, 0b1111_1111_1111_1111][..];
-        assert!(!StaticBitmap::<&[u16], LSB>::new(v).get(999));
-        let v = &[1u32][..];
-        assert!(StaticBitmap::<&[u32], LSB>::new(v).get(0));
-        let v = &[1u32, 1][..];
-        assert!(StaticBitmap::<&[u32], LSB>::new(v).get(32));
-        let v = &[0b1111_1111_1111_1111_1111_1111_1111_1111u32, 0b1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111][..];
-        assert!(!StaticBitmap::<&[u32], LSB>::new(v).get(999));
-        let v = &[1u64][..];
-        assert!(StaticBitmap::<&[u64], LSB>::new(v).get(0));
-        let v = &[1u64, 1][..];
-        assert!(StaticBitmap::<&[u64], LSB>::new(v).get(64));
-        let v = &[0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111][..];
-        assert!(!StaticBitmap::<&[u64], LSB>::new(v).get(999));
+    #[test]
+    fn bits_eq_stops_early() {
+        let mut slots = vec![0xAAu8; 100];
+        let lhs = StaticBitmap::<_, LSB>::new(CountingReads::new(slots.clone()));
+        slots[0] = 0x55;
+        // Last slot gives the length, the first slot differs
+        assert!(!lhs.bits_eq(&slots));
+        assert_eq!(lhs.as_ref().reads.get(), 2);
 
-        // Array
-        assert!(StaticBitmap::<[u8; 1], LSB>::new([1; 1]).get(0));
-        assert!(StaticBitmap::<[u8; 2], LSB>::new([1; 2]).get(8));
-        assert!(!StaticBitmap::<[u8; 3], LSB>::new([0b1111_1111; 3]).get(999));
-        assert!(StaticBitmap::<[u16; 1], LSB>::new([1; 1]).get(0));
-        assert!(StaticBitmap::<[u16; 2], LSB>::new([1; 2]).get(16));
-        assert!(!StaticBitmap::<[u16; 3], LSB>::new([0b1111_1111_1111_1111; 3]).get(999));
-        assert!(StaticBitmap::<[u32; 1], LSB>::new([1; 1]).get(0));
-        assert!(StaticBitmap::<[u32; 2], LSB>::new([1; 2]).get(32));
-        assert!(!StaticBitmap::<[u32; 3], LSB>::new([0b1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
-        assert!(StaticBitmap::<[u64; 1], LSB>::new([1; 1]).get(0));
-        assert!(StaticBitmap::<[u64; 2], LSB>::new([1; 2]).get(64));
-        assert!(!StaticBitmap::<[u64; 3], LSB>::new([0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+        let lhs = StaticBitmap::<_, LSB>::new(CountingReads::new(vec![0xFF; 100]));
+        assert!(!lhs.bits_eq(&[0xFFu8; 50]));
+        assert_eq!(lhs.as_ref().reads.get(), 1);
+    }
 
-        // Vec
-        assert!(StaticBitmap::<Vec<u8>, LSB>::new(vec![1; 1]).get(0));
-        assert!(StaticBitmap::<Vec<u8>, LSB>::new(vec![1; 2]).get(8));
-        assert!(!StaticBitmap::<Vec<u8>, LSB>::new(vec![0b1111_1111; 3]).get(999));
-        assert!(StaticBitmap::<Vec<u16>, LSB>::new(vec![1; 1]).get(0));
-        assert!(StaticBitmap::<Vec<u16>, LSB>::new(vec![1; 2]).get(16));
-        assert!(!StaticBitmap::<Vec<u16>, LSB>::new(vec![0b1111_1111_1111_1111; 3]).get(999));
-        assert!(StaticBitmap::<Vec<u32>, LSB>::new(vec![1; 1]).get(0));
-        assert!(StaticBitmap::<Vec<u32>, LSB>::new(vec![1; 2]).get(32));
-        assert!(!StaticBitmap::<Vec<u32>, LSB>::new(vec![0b1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
-        assert!(StaticBitmap::<Vec<u64>, LSB>::new(vec![1; 1]).get(0));
-        assert!(StaticBitmap::<Vec<u64>, LSB>::new(vec![1; 2]).get(64));
-        assert!(!StaticBitmap::<Vec<u64>, LSB>::new(vec![0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+    #[test]
+    fn combine_into_mixed_containers() {
+        let lhs = StaticBitmap::<_, LSB>::new([0b1100_1100u8, 0b0000_1111, 0, 0]);
+        let rhs = StaticBitmap::<_, LSB>::new(vec![0b1010_1010u8, 0b1111_0000]);
+        let mut dst = Vec::new();
 
-        // Bytes
-        #[cfg(feature = "bytes")]
-        {
-            use bytes::{Bytes, BytesMut};
-            assert!(StaticBitmap::<Bytes, LSB>::new(Bytes::from_static(&[1])).get(0));
-            assert!(StaticBitmap::<Bytes, LSB>::new(Bytes::from_static(&[1, 1])).get(8));
-            assert!(!StaticBitmap::<Bytes, LSB>::new(Bytes::from_static(&[0b1111_1111, 0b1111_1111, 0b1111_1111])).get(999));
-            assert!(StaticBitmap::<BytesMut, LSB>::new(BytesMut::from(&[1u8][..])).get(0));
-            assert!(StaticBitmap::<BytesMut, LSB>::new(BytesMut::from(&[1u8, 1][..])).get(8));
-            assert!(!StaticBitmap::<BytesMut, LSB>::new(BytesMut::from(&[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..])).get(999));
+        lhs.combine_into(&rhs, BitOp::And, &mut dst);
+        assert_eq!(dst, lhs.intersection::<Vec<u8>>(&rhs));
+        lhs.combine_into(&rhs, BitOp::Or, &mut dst);
+        assert_eq!(dst, lhs.union::<Vec<u8>>(&rhs));
+        lhs.combine_into(&rhs, BitOp::Xor, &mut dst);
+        assert_eq!(dst, vec![0b0110_0110, 0b1111_1111, 0, 0]);
+        lhs.combine_into(&rhs, BitOp::AndNot, &mut dst);
+        assert_eq!(dst, vec![0b0100_0100, 0b0000_1111, 0, 0]);
+        rhs.combine_into(&lhs, BitOp::AndNot, &mut dst);
+        assert_eq!(dst, vec![0b0010_0010, 0b1111_0000]);
+    }
+
+    #[test]
+    fn common_ones() {
+        let lhs = StaticBitmap::<_, MSB>::new(vec![0b1100_1100u8, 0, 0b0000_1111, 0b1000_0000]);
+        let cases: &[&[u8]] = &[
+            &[],
+            &[0xFF],
+            &[0b1010_1010, 0xFF, 0xFF],
+            &[0, 0, 0b0101_0101, 0xFF, 0xFF],
+        ];
+        for rhs in cases {
+            let expected = StaticBitmap::<_, MSB>::new(lhs.intersection::<Vec<u8>>(rhs))
+                .ones()
+                .collect::<Vec<_>>();
+            assert_eq!(lhs.common_ones(rhs).collect::<Vec<_>>(), expected);
         }
+    }
 
-        // SmallVec
-        #[cfg(feature = "smallvec")]
-        {
-            use smallvec::SmallVec;
-            assert!(StaticBitmap::<SmallVec<[u8; 1]>, LSB>::new(SmallVec::from([1u8])).get(0));
-            assert!(StaticBitmap::<SmallVec<[u8; 2]>, LSB>::new(SmallVec::from([1u8, 1])).get(8));
-            assert!(!StaticBitmap::<SmallVec<[u8; 3]>, LSB>::new(SmallVec::from([0b1111_1111u8, 0b1111_1111, 0b1111_1111])).get(999));
+    #[test]
+    fn symmetric_ones() {
+        let lhs = StaticBitmap::<_, LSB>::new(vec![0b1100_1100u8, 0, 0b0000_1111]);
+        let cases: &[&[u8]] = &[
+            &[],
+            &[0xFF],
+            &[0b1100_1100, 0, 0b0000_1111],
+            &[0b1010_1010, 0xFF, 0xFF, 0, 0b1000_0001],
+        ];
+        for rhs in cases {
+            let mut expected =
+                VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(lhs.as_ref().clone());
+            expected.symmetric_difference_update(rhs);
+            assert_eq!(
+                lhs.symmetric_ones(rhs).collect::<Vec<_>>(),
+                expected.ones().collect::<Vec<_>>()
+            );
         }
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn set_bit() {
-        // Number
-        let mut v = StaticBitmap::<u8, LSB>::default();
-        v.set(0, true);
-        v.set(7, true);
-        assert!(v.try_set(8, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(7));
+    fn out_of_bounds_error_suggests_slots() {
+        let mut bitmap = StaticBitmap::<_, LSB>::new([0u8; 2]);
+        let err = bitmap.try_set(130, true).unwrap_err();
+        assert_eq!(err.required_slots(), Some(17));
+        let message = err.to_string();
+        assert!(
+            message.contains("at least 17 slots of 8 bits"),
+            "{}",
+            message
+        );
 
-        let mut v = StaticBitmap::<u16, LSB>::default();
-        v.set(0, true);
-        v.set(15, true);
-        assert!(v.try_set(16, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(15));
+        let mut bitmap = StaticBitmap::<_, LSB>::new(0u32);
+        let err = bitmap.try_set(32, true).unwrap_err();
+        assert_eq!(err.required_slots(), Some(2));
 
-        let mut v = StaticBitmap::<u32, LSB>::default();
-        v.set(0, true);
-        v.set(31, true);
-        assert!(v.try_set(32, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(31));
-        
-        let mut v = StaticBitmap::<u64, LSB>::default();
-        v.set(0, true);
-        v.set(63, true);
-        assert!(v.try_set(64, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(63));
-        
-        // Slice
-        let mut inner = vec![0, 0];
-        let mut v = StaticBitmap::<&mut [u8], LSB>::new(inner.as_mut_slice());
-        v.set(0, true);
-        v.set(15, true);
-        assert!(v.try_set(16, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(15));
+        let err = bitmap.try_set_range(10..64, true).unwrap_err();
+        assert!(err.to_string().contains("at least 2 slots of 32 bits"));
+    }
 
-        let mut inner = vec![0, 0];
-        let mut v = StaticBitmap::<&mut [u16], LSB>::new(inner.as_mut_slice());
-        v.set(0, true);
-        v.set(31, true);
-        assert!(v.try_set(32, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(31));
+    #[test]
+    fn indexed_bits() {
+        let bitmap = StaticBitmap::<_, MSB>::new([0b1010_0110u16, 0b0000_1111_0000_0001]);
+        assert_eq!(
+            bitmap.indexed_bits().collect::<Vec<_>>(),
+            bitmap.iter().by_bits().enumerate().collect::<Vec<_>>()
+        );
+        assert_eq!(bitmap.indexed_bits().count(), 32);
+        assert_eq!(
+            StaticBitmap::<_, LSB>::new(Vec::<u8>::new())
+                .indexed_bits()
+                .next(),
+            None
+        );
+    }
 
-        let mut inner = vec![0, 0];
-        let mut v = StaticBitmap::<&mut [u32], LSB>::new(inner.as_mut_slice());
-        v.set(0, true);
-        v.set(63, true);
-        assert!(v.try_set(64, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(63));
+    #[test]
+    fn clear_where() {
+        let ones = [0usize, 3, 7, 8, 12, 15, 20, 23];
+        let masked = [3usize, 8, 9, 15];
+        let mut bitmap = StaticBitmap::<_, LSB>::new([0u8; 3]);
+        for &idx in &ones {
+            bitmap.set(idx, true);
+        }
+        let mut mask = StaticBitmap::<_, LSB>::new([0u8; 2]);
+        for &idx in &masked {
+            mask.set(idx, true);
+        }
 
-        let mut inner = vec![0, 0];
-        let mut v = StaticBitmap::<&mut [u64], LSB>::new(inner.as_mut_slice());
-        v.set(0, true);
-        v.set(127, true);
-        assert!(v.try_set(128, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(127));
+        bitmap.clear_where(mask.as_ref());
+        let expected = ones
+            .iter()
+            .copied()
+            .filter(|idx| !masked.contains(idx))
+            .collect::<Vec<_>>();
+        assert_eq!(bitmap.ones().collect::<Vec<_>>(), expected);
+    }
 
-        // Array
-        let mut v = StaticBitmap::<[u8; 2], LSB>::default();
-        v.set(0, true);
-        v.set(15, true);
-        assert!(v.try_set(16, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(15));
+    #[test]
+    fn ones_summary() {
+        fn check<B: BitAccess>(slots: &[u16]) {
+            let bitmap = StaticBitmap::<_, B>::new(slots);
+            let summary = bitmap.ones_summary();
+            let ones = bitmap.ones().collect::<Vec<_>>();
+            assert_eq!(summary.count, bitmap.count_ones());
+            assert_eq!(summary.first, ones.first().copied());
+            assert_eq!(summary.last, ones.last().copied());
+        }
 
-        let mut v = StaticBitmap::<[u16; 2], LSB>::default();
-        v.set(0, true);
-        v.set(31, true);
-        assert!(v.try_set(32, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(31));
+        let cases: &[&[u16]] = &[
+            &[],
+            &[0, 0],
+            &[1],
+            &[0, 0x8000],
+            &[0x0F0F, 0, 0xF000, 0],
+            &[0xFFFF; 3],
+        ];
+        for slots in cases {
+            check::<LSB>(slots);
+            check::<MSB>(slots);
+        }
+    }
 
-        let mut v = StaticBitmap::<[u32; 2], LSB>::default();
-        v.set(0, true);
-        v.set(63, true);
-        assert!(v.try_set(64, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(63));
+    #[test]
+    fn by_bytes_endian() {
+        let v = StaticBitmap::<_, LSB>::new([0x0A0B0C0Du32]);
+        assert_eq!(
+            v.by_bytes_endian(Endianness::Big).collect::<Vec<_>>(),
+            0x0A0B0C0Du32.to_be_bytes()
+        );
 
-        let mut v = StaticBitmap::<[u64; 2], LSB>::default();
-        v.set(0, true);
-        v.set(127, true);
-        assert!(v.try_set(128, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(127));
-        
-        // Vec
-        let mut v = StaticBitmap::<Vec<u8>, LSB>::new(vec![0, 0]);
-        v.set(0, true);
-        v.set(15, true);
-        assert!(v.try_set(16, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(15));
+        let v = StaticBitmap::<_, LSB>::new([0xAABBu16, 0x0102, 0xFF00]);
+        for endianness in [Endianness::Big, Endianness::Little] {
+            assert_eq!(
+                v.by_bytes_endian(endianness).collect::<Vec<_>>(),
+                v.to_slot_bytes(endianness)
+            );
+        }
+        assert_eq!(
+            StaticBitmap::<[u32; 0], LSB>::new([])
+                .by_bytes_endian(Endianness::Big)
+                .count(),
+            0
+        );
+    }
 
-        let mut v = StaticBitmap::<Vec<u16>, LSB>::new(vec![0, 0]);
-        v.set(0, true);
-        v.set(31, true);
-        assert!(v.try_set(32, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(31));
+    #[test]
+    fn bit_and_assign() {
+        // Longer target: excess slots are ANDed with implicit zeros
+        let mut v = StaticBitmap::<_, LSB>::new([0b1111_0101u8, 0b1111_1111, 0b1000_0001]);
+        v &= &[0b0101_1111u8];
+        assert_eq!(v.into_inner(), [0b0101_0101, 0, 0]);
 
-        let mut v = StaticBitmap::<Vec<u32>, LSB>::new(vec![0, 0]);
-        v.set(0, true);
-        v.set(63, true);
-        assert!(v.try_set(64, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(63));
+        // Shorter target: bits of rhs beyond the target are dropped
+        let mut v = StaticBitmap::<_, LSB>::new([0b1111_0101u8]);
+        v &= &[0b0101_1111u8, 0b1111_1111];
+        assert_eq!(v.into_inner(), [0b0101_0101]);
 
-        let mut v = StaticBitmap::<Vec<u64>, LSB>::new(vec![0, 0]);
-        v.set(0, true);
-        v.set(127, true);
-        assert!(v.try_set(128, true).is_err());
-        assert!(v.get(0));
-        assert!(v.get(127));
+        let mut v = StaticBitmap::<_, LSB>::new(vec![0b0000_0011u8, 0b0000_0001]);
+        let rhs = StaticBitmap::<_, LSB>::new(vec![0b0000_0010u8]);
+        v &= &rhs;
+        assert_eq!(v.into_inner(), vec![0b0000_0010, 0]);
+    }
 
-        // Bytes
-        #[cfg(feature = "bytes")]
+    #[test]
+    fn fill_slots() {
+        let mut v = StaticBitmap::<_, LSB>::new([0u8; 3]);
+        v.fill_slots(0..2, 0xAA);
+        assert_eq!(v.as_ref(), &[0xAA, 0xAA, 0x00]);
+        v.fill_slots(2..2, 0xFF);
+        assert_eq!(v.as_ref(), &[0xAA, 0xAA, 0x00]);
+        assert!(v.try_fill_slots(2..4, 0xFF).is_err());
+        assert!(v.try_fill_slots(0..usize::MAX, 0xFF).is_err());
+        assert_eq!(v.into_inner(), [0xAA, 0xAA, 0x00]);
+    }
+
+    #[test]
+    fn slice_bitmap_views() {
+        let mut v = StaticBitmap::<_, LSB>::new(vec![0b0000_1001u8, 0b0000_0010]);
         {
-            use bytes::{BytesMut};
-            let mut v = StaticBitmap::<BytesMut, LSB>::new(BytesMut::zeroed(2));
-            v.set(0, true);
-            v.set(15, true);
-            assert!(v.try_set(16, true).is_err());
-            assert!(v.get(0));
-            assert!(v.get(15));
+            let view = v.as_slice_bitmap();
+            assert_eq!(view.as_ref().as_ptr(), v.as_ref().as_ptr());
+            assert_eq!(
+                view.ones().collect::<Vec<_>>(),
+                v.ones().collect::<Vec<_>>()
+            );
         }
-        
-        #[cfg(feature = "smallvec")]
-        {
-            use smallvec::{SmallVec, smallvec};
-            let mut v = StaticBitmap::<SmallVec<[u8; 2]>, LSB>::new(smallvec![0, 0]);
-            v.set(0, true);
-            v.set(15, true);
-            assert!(v.try_set(16, true).is_err());
-            assert!(v.get(0));
-            assert!(v.get(15));
+
+        let mut view = v.as_mut_slice_bitmap();
+        view.set(15, true);
+        view.set(0, false);
+        assert_eq!(v.into_inner(), vec![0b0000_1000, 0b1000_0010]);
+    }
+
+    #[test]
+    fn invert_to_len() {
+        for bit_len in 0..=24 {
+            let mut v = StaticBitmap::<_, MSB>::new([0b1010_0110u8, 0b0000_1111, 0b1000_0001]);
+            let expected = (0..24)
+                .map(|i| i < bit_len && !v.get(i))
+                .collect::<Vec<_>>();
+            v.invert_to_len(bit_len);
+            assert_eq!(v.to_bool_vec(), expected, "bit_len: {}", bit_len);
+        }
+
+        let mut v = StaticBitmap::<_, LSB>::new([0b0000_1001u8]);
+        assert!(v.try_invert_to_len(9).is_err());
+        assert_eq!(v.as_ref(), &[0b0000_1001]);
+        v.invert();
+        assert_eq!(v.into_inner(), [0b1111_0110]);
+    }
+
+    #[test]
+    fn bitmap_ops_matches_intersection_and_union() {
+        use crate::BitmapOps;
+
+        let lhs = StaticBitmap::<_, LSB>::new(vec![0b1100_1100u8, 0b0000_1111, 0b1000_0001]);
+        let rhs = vec![0b1010_1010u8, 0b1111_0000];
+
+        assert_eq!(
+            lhs.combine::<Vec<u8>>(&rhs, BitOp::And),
+            lhs.intersection::<Vec<u8>>(&rhs)
+        );
+        assert_eq!(
+            lhs.combine::<Vec<u8>>(&rhs, BitOp::Or),
+            lhs.union::<Vec<u8>>(&rhs)
+        );
+        assert_eq!(
+            lhs.combine_len(&rhs, BitOp::And),
+            lhs.intersection_len(&rhs)
+        );
+        assert_eq!(lhs.combine_len(&rhs, BitOp::Or), lhs.union_len(&rhs));
+
+        let mut dst = [0u8; 3];
+        lhs.combine_in(&rhs, BitOp::Or, &mut dst);
+        let mut expected = [0u8; 3];
+        lhs.union_in(&rhs, &mut expected);
+        assert_eq!(dst, expected);
+
+        assert_eq!(
+            lhs.combine::<Vec<u8>>(&rhs, BitOp::Xor),
+            vec![0b0110_0110, 0b1111_1111, 0b1000_0001]
+        );
+        assert_eq!(
+            lhs.combine::<Vec<u8>>(&rhs, BitOp::AndNot),
+            vec![0b0100_0100, 0b0000_1111, 0b1000_0001]
+        );
+        assert_eq!(lhs.combine_len(&rhs, BitOp::Xor), 14);
+
+        let mut small = [0u8; 2];
+        assert!(lhs.try_combine_in(&rhs, BitOp::Xor, &mut small).is_err());
+        assert!(lhs.try_combine_in(&rhs, BitOp::And, &mut small).is_ok());
+    }
+
+    #[test]
+    fn sliding_popcount() {
+        let v = StaticBitmap::<_, MSB>::new([0b1010_0110u8, 0b0000_1111, 0b1000_0001]);
+        let bits = v.to_bool_vec();
+        for width in 0..=25 {
+            let expected = if width <= bits.len() {
+                (0..=bits.len() - width)
+                    .map(|i| bits[i..i + width].iter().filter(|&&b| b).count())
+                    .collect::<Vec<_>>()
+            } else {
+                vec![]
+            };
+            let iter = v.sliding_popcount(width);
+            assert_eq!(iter.size_hint().0, expected.len(), "width: {}", width);
+            assert_eq!(iter.collect::<Vec<_>>(), expected, "width: {}", width);
+        }
+    }
+
+    #[test]
+    fn compressed_round_trip() {
+        let mut v = StaticBitmap::<_, LSB>::new(vec![0u32; 256]);
+        for idx in [3, 700, 701, 4000, 8191] {
+            v.set(idx, true);
+        }
+        let compressed = v.to_compressed();
+        assert!(compressed.len() < v.to_byte_vec().len() / 10);
+
+        let restored = StaticBitmap::<Vec<u32>, LSB>::from_compressed(&compressed).unwrap();
+        assert_eq!(restored, v);
+        // The form doesn't depend on slot width
+        let restored = StaticBitmap::<Vec<u8>, LSB>::from_compressed(&compressed).unwrap();
+        assert_eq!(
+            restored.ones().collect::<Vec<_>>(),
+            v.ones().collect::<Vec<_>>()
+        );
+
+        assert!(StaticBitmap::<Vec<u32>, LSB>::from_compressed(&[0, 1]).is_err());
+    }
+
+    #[test]
+    fn gaps() {
+        let v = StaticBitmap::<_, LSB>::new([0b0010_0100u8]);
+        assert_eq!(v.gaps().collect::<Vec<_>>(), vec![0..2, 3..5, 6..8]);
+
+        let v = StaticBitmap::<_, LSB>::new([0b1000_0001u8, 0b0000_0011, 0]);
+        assert_eq!(v.gaps().collect::<Vec<_>>(), vec![1..7, 10..24]);
+        assert_eq!(
+            StaticBitmap::<_, LSB>::new([0u8; 2])
+                .gaps()
+                .collect::<Vec<_>>(),
+            vec![0..16]
+        );
+        assert_eq!(StaticBitmap::<_, LSB>::new([0xFFu8]).gaps().count(), 0);
+
+        // Gaps and ones cover every bit exactly once
+        let v = StaticBitmap::<_, MSB>::new([0b1010_0110u8, 0b0000_1111, 0b1000_0001]);
+        let mut bits = vec![false; 24];
+        for gap in v.gaps() {
+            for idx in gap {
+                assert!(!v.get(idx));
+                bits[idx] = true;
+            }
+        }
+        for idx in v.ones() {
+            bits[idx] = true;
         }
+        assert!(bits.into_iter().all(|b| b));
     }
 }
@@ -0,0 +1,174 @@
+//! Transposition of bit matrices, where every row is a bitmap.
+
+use crate::{
+    container::{ContainerRead, ContainerWrite},
+    iter::Ones,
+    number::Number,
+    BitAccess, StaticBitmap,
+};
+
+/// Transposes 64x64 bit matrix: bit `c` of row `r` becomes bit `r` of row `c`.
+///
+/// Bits are indexed from the least significant one (as in [`LSB`]). It uses delta-swaps of
+/// blocks with halving size, so it takes `6 * 32` swaps instead of `64 * 64` bit moves.
+///
+/// Usage example:
+/// ```
+/// use bitmac::transpose::transpose_square;
+///
+/// let mut rows = [0u64; 64];
+/// rows[0] = 0b0110;
+/// let res = transpose_square(&rows);
+/// assert_eq!(res[0], 0);
+/// assert_eq!(res[1], 1);
+/// assert_eq!(res[2], 1);
+/// assert_eq!(res[3], 0);
+/// ```
+///
+/// [`LSB`]: crate::bit_access::LSB
+pub fn transpose_square(rows: &[u64; 64]) -> [u64; 64] {
+    let mut res = *rows;
+
+    let mut j = 32;
+    let mut mask = 0x0000_0000_ffff_ffffu64;
+    while j != 0 {
+        let mut k = 0;
+        while k < 64 {
+            // Swap high block of row `k` with low block of row `k + j`
+            let t = ((res[k] >> j) ^ res[k + j]) & mask;
+            res[k] ^= t << j;
+            res[k + j] ^= t;
+            k = (k + j + 1) & !j;
+        }
+        j >>= 1;
+        mask ^= mask << j;
+    }
+
+    res
+}
+
+/// Transposes bit matrix of equal-length rows: bit `c` of row `r` becomes bit `r` of row `c`.
+///
+/// The result has as many rows as there are bits in every row of `rows`. Each result row
+/// consists of the minimum number of slots that fits `rows.len()` bits.
+///
+/// ## Panic
+///
+/// Panics if rows have different [`bits_count`].
+///
+/// Usage example:
+/// ```
+/// use bitmac::{transpose::transpose, StaticBitmap, LSB};
+///
+/// let rows = [
+///     StaticBitmap::<_, LSB>::new(0b0000_0011u8),
+///     StaticBitmap::<_, LSB>::new(0b0000_0010u8),
+/// ];
+/// let res = transpose(&rows);
+/// assert_eq!(res.len(), 8);
+/// assert_eq!(res[0].as_ref(), &vec![0b0000_0001]);
+/// assert_eq!(res[1].as_ref(), &vec![0b0000_0011]);
+/// assert_eq!(res[2].as_ref(), &vec![0b0000_0000]);
+/// ```
+///
+/// [`bits_count`]: crate::container::ContainerRead::bits_count
+pub fn transpose<D, N, B>(rows: &[D]) -> Vec<StaticBitmap<Vec<N>, B>>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let cols_count = rows.first().map_or(0, |row| row.bits_count());
+    let slots_count = (rows.len() + N::BITS_COUNT - 1) / N::BITS_COUNT;
+    let mut res = vec![vec![N::ZERO; slots_count]; cols_count];
+
+    for (r, row) in rows.iter().enumerate() {
+        assert_eq!(
+            row.bits_count(),
+            cols_count,
+            "row {} has {} bits, but expected {}",
+            r,
+            row.bits_count(),
+            cols_count
+        );
+        for c in Ones::<_, B>::new(row) {
+            ContainerWrite::<B>::set_bit_unchecked(&mut res[c], r, true);
+        }
+    }
+
+    res.into_iter().map(StaticBitmap::new).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LSB, MSB};
+
+    fn naive_transpose(rows: &[u64; 64]) -> [u64; 64] {
+        let mut res = [0u64; 64];
+        for (r, row) in rows.iter().enumerate() {
+            for (c, res_row) in res.iter_mut().enumerate() {
+                *res_row |= ((row >> c) & 1) << r;
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn square_same_as_naive() {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut rows = [0u64; 64];
+        for row in rows.iter_mut() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *row = state;
+        }
+
+        let res = transpose_square(&rows);
+        assert_eq!(res, naive_transpose(&rows));
+        assert_eq!(transpose_square(&res), rows);
+
+        let mut identity = [0u64; 64];
+        for (i, row) in identity.iter_mut().enumerate() {
+            *row = 1 << i;
+        }
+        assert_eq!(transpose_square(&identity), identity);
+    }
+
+    #[test]
+    fn general() {
+        let rows = [
+            [0b0000_0001u8, 0b1000_0000],
+            [0b0000_0011, 0b0000_0000],
+            [0, 0b1000_0000],
+        ];
+        let res = transpose::<_, _, LSB>(&rows);
+        assert_eq!(res.len(), 16);
+        assert_eq!(res[0].as_ref(), &vec![0b0000_0011]);
+        assert_eq!(res[1].as_ref(), &vec![0b0000_0010]);
+        assert_eq!(res[15].as_ref(), &vec![0b0000_0101]);
+        for r in &res[2..15] {
+            assert!(r.is_empty());
+        }
+
+        let rows = vec![0b1000_0000u8; 9];
+        let res = transpose::<_, _, MSB>(&rows);
+        assert_eq!(res[0].as_ref(), &vec![0b1111_1111, 0b1000_0000]);
+
+        let rows = [0u64; 64].map(|_| 0x8000_0000_0000_0001u64);
+        let res = transpose::<_, _, LSB>(&rows);
+        let square = transpose_square(&rows);
+        for (r, s) in res.iter().zip(square.iter()) {
+            assert_eq!(r.as_ref(), &vec![*s]);
+        }
+
+        assert!(transpose::<[u8; 1], _, LSB>(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_lengths() {
+        transpose::<_, _, LSB>(&[vec![0u8], vec![0u8, 0]]);
+    }
+}
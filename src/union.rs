@@ -1,11 +1,19 @@
 use crate::{
+    combine::{combine_len_impl, BitOp, BitmapOps},
     container::{ContainerRead, ContainerWrite},
     number::Number,
+    resizable::Resizable,
     with_slots::TryWithSlots,
-    BitAccess, SmallContainerSizeError, UnionError,
+    BitAccess, CombineError, SmallContainerSizeError, UnionError,
 };
 
 /// Union operator (a | b).
+///
+/// Implemented for every [`BitmapOps`] type as a shorthand for [`BitOp::Or`].
+///
+/// [`BitmapOps`]: crate::combine::BitmapOps
+/// [`BitOp::Or`]: crate::combine::BitOp::Or
+#[deprecated(note = "use `BitmapOps` with `BitOp::Or` instead")]
 pub trait Union<Rhs, N, B>
 where
     Rhs: ContainerRead<B, Slot = N>,
@@ -31,7 +39,7 @@ where
     where
         Dst: ContainerWrite<B, Slot = N>;
 
-    /// Calculates union. Result container will be created with [`try_with_slots`] function.
+    /// Calculates union. Result container will be created with [`try_with_slots_exact`] function.
     ///
     /// ## Panic
     ///
@@ -39,16 +47,16 @@ where
     /// See non-panic function [`try_union`].
     ///
     /// [`try_union`]: crate::union::Union::try_union
-    /// [`try_with_slots`]: crate::with_slots::TryWithSlots::try_with_slots
+    /// [`try_with_slots_exact`]: crate::with_slots::TryWithSlots::try_with_slots_exact
     fn union<Dst>(&self, rhs: &Rhs) -> Dst
     where
         Dst: ContainerWrite<B, Slot = N> + TryWithSlots;
 
-    /// Calculates union. Result container will be created with [`try_with_slots`] function.
+    /// Calculates union. Result container will be created with [`try_with_slots_exact`] function.
     ///
     /// Returns `Err(_)` if `Dst` cannot fit the entire result.
     ///
-    /// [`try_with_slots`]: crate::with_slots::TryWithSlots::try_with_slots
+    /// [`try_with_slots_exact`]: crate::with_slots::TryWithSlots::try_with_slots_exact
     fn try_union<Dst>(&self, rhs: &Rhs) -> Result<Dst, UnionError>
     where
         Dst: ContainerWrite<B, Slot = N> + TryWithSlots;
@@ -59,6 +67,55 @@ where
     fn union_len(&self, rhs: &Rhs) -> usize;
 }
 
+#[allow(deprecated)]
+impl<T, Rhs, N, B> Union<Rhs, N, B> for T
+where
+    T: BitmapOps<Rhs, N, B>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    fn union_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst)
+    where
+        Dst: ContainerWrite<B, Slot = N>,
+    {
+        self.combine_in(rhs, BitOp::Or, dst)
+    }
+
+    fn try_union_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst) -> Result<(), UnionError>
+    where
+        Dst: ContainerWrite<B, Slot = N>,
+    {
+        self.try_combine_in(rhs, BitOp::Or, dst)
+            .map_err(from_combine_error)
+    }
+
+    fn union<Dst>(&self, rhs: &Rhs) -> Dst
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    {
+        self.combine(rhs, BitOp::Or)
+    }
+
+    fn try_union<Dst>(&self, rhs: &Rhs) -> Result<Dst, UnionError>
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    {
+        self.try_combine(rhs, BitOp::Or).map_err(from_combine_error)
+    }
+
+    fn union_len(&self, rhs: &Rhs) -> usize {
+        self.combine_len(rhs, BitOp::Or)
+    }
+}
+
+fn from_combine_error(e: CombineError) -> UnionError {
+    match e {
+        CombineError::SmallContainerSizeError(e) => e.into(),
+        CombineError::WithSlotsError(e) => e.into(),
+    }
+}
+
 pub(crate) fn try_union_in_impl<Lhs, Rhs, Dst, N, B>(
     lhs: &Lhs,
     rhs: &Rhs,
@@ -75,9 +132,12 @@ where
     let required_dst_len = usize::max(lhs.slots_count(), rhs.slots_count());
     if dst.slots_count() < required_dst_len {
         return Err(SmallContainerSizeError::new(format!(
-            "size of container should be >= {}, but handled {}",
+            "size of container should be >= {} slots of {} bits ({} bits), but handled {} slots ({} bits)",
             required_dst_len,
-            dst.slots_count()
+            N::BITS_COUNT,
+            required_dst_len * N::BITS_COUNT,
+            dst.slots_count(),
+            dst.slots_count() * N::BITS_COUNT,
         ))
         .into());
     }
@@ -117,12 +177,13 @@ where
 {
     // TODO: shrink size
     let slots_count = usize::max(lhs.slots_count(), rhs.slots_count());
-    let mut dst = Dst::try_with_slots(slots_count)?;
+    let mut dst = Dst::try_with_slots_exact(slots_count)?;
 
     try_union_in_impl(lhs, rhs, &mut dst)?;
     Ok(dst)
 }
 
+#[allow(dead_code)]
 pub(crate) fn union_len_impl<Lhs, Rhs, N, B>(lhs: &Lhs, rhs: &Rhs) -> usize
 where
     Lhs: ContainerRead<B, Slot = N>,
@@ -130,28 +191,22 @@ where
     N: Number,
     B: BitAccess,
 {
-    let head_max_idx = usize::min(lhs.slots_count(), rhs.slots_count());
-
-    let mut len = 0;
-    for i in 0..head_max_idx {
-        let lhs_slot = lhs.get_slot(i);
-        let rhs_slot = rhs.get_slot(i);
-        let intersect = lhs_slot | rhs_slot;
-        len += intersect.count_ones() as usize;
-    }
+    combine_len_impl(lhs, rhs, BitOp::Or)
+}
 
-    // Counting rest tail
-    let tail_max_idx = usize::max(lhs.slots_count(), rhs.slots_count());
-    for i in head_max_idx..tail_max_idx {
-        let rest_slot = if lhs.slots_count() >= rhs.slots_count() {
-            lhs.get_slot(i)
-        } else {
-            rhs.get_slot(i)
-        };
+pub(crate) fn union_into_resized_impl<Lhs, Rhs, Dst, N, B>(lhs: &Lhs, rhs: &Rhs, dst: &mut Dst)
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let slots_count = usize::max(lhs.slots_count(), rhs.slots_count());
+    dst.resize(slots_count, N::ZERO);
 
-        len += rest_slot.count_ones() as usize;
-    }
-    len
+    // Destination has exactly required size, so it cannot fail
+    try_union_in_impl(lhs, rhs, dst).unwrap();
 }
 
 #[cfg(test)]
@@ -405,4 +460,39 @@ mod tests {
         let rhs: [u8; 2] = [0b0010_0100, 0b0101_0000];
         assert_eq!(union_len_impl::<_, _, _, LSB>(&lhs, &rhs), 5);
     }
+
+    #[test]
+    fn union_into_resized() {
+        let lhs = [0b0000_1111u8];
+        let rhs = [0b0011_0000u8, 0b0000_0001, 0b1000_0000];
+
+        let mut dst = Vec::new();
+        union_into_resized_impl::<_, _, _, _, LSB>(&lhs, &rhs, &mut dst);
+        assert_eq!(dst, vec![0b0011_1111, 0b0000_0001, 0b1000_0000]);
+
+        let mut dst = vec![0xFFu8; 5];
+        union_into_resized_impl::<_, _, _, _, LSB>(&rhs, &lhs, &mut dst);
+        assert_eq!(dst, vec![0b0011_1111, 0b0000_0001, 0b1000_0000]);
+    }
+
+    #[test]
+    fn small_number_dst_message() {
+        let lhs = 0b0000_0001u8;
+        let rhs = [0b0000_0010u8, 0, 0];
+
+        let mut dst = 0u8;
+        let err = try_union_in_impl::<_, _, _, _, LSB>(&lhs, &rhs, &mut dst).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "container size is small: size of container should be >= 3 slots of 8 bits (24 bits), \
+             but handled 1 slots (8 bits)"
+        );
+
+        let err = try_union_impl::<_, _, u8, _, LSB>(&lhs, &rhs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "creation of a container with the specified number of slots failed: \
+             number `u8` can only store 1 slot (8 bits), but handled 3 slots"
+        );
+    }
 }
@@ -0,0 +1,190 @@
+use crate::{
+    container::{ContainerRead, ContainerWrite},
+    number::Number,
+    shift::low_mask,
+    BitAccess,
+};
+
+/// Returns `a ^ b`. `Number` has no `BitXor` bound, so it is built from `|`, `&` and `!`.
+#[inline]
+pub(crate) fn xor<N>(a: N, b: N) -> N
+where
+    N: Number,
+{
+    (a | b) & !(a & b)
+}
+
+/// Applies `f` to every slot of `dst` that has a pair in `rhs`.
+fn update_head<D, Rhs, N, B, F>(dst: &mut D, rhs: &Rhs, f: F)
+where
+    D: ContainerWrite<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+    F: Fn(N, N) -> N,
+{
+    let max_idx = usize::min(dst.slots_count(), rhs.slots_count());
+    for i in 0..max_idx {
+        let rhs_slot = rhs.get_slot(i);
+        let dst_slot = dst.get_mut_slot(i);
+        *dst_slot = f(*dst_slot, rhs_slot);
+    }
+}
+
+/// `dst = dst | rhs`. Slots of `rhs` that exceed `dst` are ignored.
+pub(crate) fn union_update_impl<D, Rhs, N, B>(dst: &mut D, rhs: &Rhs)
+where
+    D: ContainerWrite<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    update_head(dst, rhs, |a, b| a | b);
+}
+
+/// `dst = dst & rhs`. Slots of `dst` that exceed `rhs` are cleared.
+pub(crate) fn intersect_update_impl<D, Rhs, N, B>(dst: &mut D, rhs: &Rhs)
+where
+    D: ContainerWrite<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    update_head(dst, rhs, |a, b| a & b);
+    for i in rhs.slots_count()..dst.slots_count() {
+        *dst.get_mut_slot(i) = N::ZERO;
+    }
+}
+
+/// `dst = dst & !rhs`. Slots of `rhs` that exceed `dst` are ignored.
+pub(crate) fn difference_update_impl<D, Rhs, N, B>(dst: &mut D, rhs: &Rhs)
+where
+    D: ContainerWrite<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    update_head(dst, rhs, |a, b| a & !b);
+}
+
+/// `dst = dst ^ rhs`. Slots of `rhs` that exceed `dst` are ignored.
+pub(crate) fn symmetric_difference_update_impl<D, Rhs, N, B>(dst: &mut D, rhs: &Rhs)
+where
+    D: ContainerWrite<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    update_head(dst, rhs, xor);
+}
+
+/// Counts ones of `lhs & !rhs`. Missing slots of `rhs` are zeros, so the whole tail of `lhs` counts.
+pub(crate) fn difference_len_impl<Lhs, Rhs, N, B>(lhs: &Lhs, rhs: &Rhs) -> usize
+where
+    Lhs: ContainerRead<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let head_max_idx = usize::min(lhs.slots_count(), rhs.slots_count());
+
+    let mut len = 0;
+    for i in 0..head_max_idx {
+        let diff = lhs.get_slot(i) & !rhs.get_slot(i);
+        len += diff.count_ones() as usize;
+    }
+    for i in head_max_idx..lhs.slots_count() {
+        len += lhs.get_slot(i).count_ones() as usize;
+    }
+    len
+}
+
+/// Returns index of the last non-zero slot of `rhs` that exceeds `len` slots.
+pub(crate) fn last_tail_slot<Rhs, N, B>(rhs: &Rhs, len: usize) -> Option<usize>
+where
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    (len..rhs.slots_count())
+        .rev()
+        .find(|&i| rhs.get_slot(i) != N::ZERO)
+}
+
+/// `dst = !dst`. Every bit of every slot is flipped, padding bits of the last slot too.
+pub(crate) fn invert_impl<D, N, B>(dst: &mut D)
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    for i in 0..dst.slots_count() {
+        let slot = dst.get_mut_slot(i);
+        *slot = !*slot;
+    }
+}
+
+/// Flips bits `0..bit_len` of `dst`, the rest of bits are cleared.
+///
+/// `bit_len` must not exceed `bits_count()`.
+pub(crate) fn invert_to_len_impl<D, N, B>(dst: &mut D, bit_len: usize)
+where
+    D: ContainerWrite<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    let full_slots = bit_len / N::BITS_COUNT;
+    let rest_bits = bit_len % N::BITS_COUNT;
+
+    for i in 0..dst.slots_count() {
+        let slot = dst.get_mut_slot(i);
+        *slot = if i < full_slots {
+            !*slot
+        } else if i == full_slots {
+            !*slot & low_mask::<N, B>(rest_bits)
+        } else {
+            N::ZERO
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LSB;
+
+    #[test]
+    fn update_different_lengths() {
+        let rhs = [0b0000_1100u8, 0b0000_0001];
+
+        let mut dst = [0b0000_1010u8];
+        union_update_impl::<_, _, _, LSB>(&mut dst, &rhs);
+        assert_eq!(dst, [0b0000_1110]);
+
+        let mut dst = [0b0000_1010u8, 0b1000_0000, 0b0000_0001];
+        intersect_update_impl::<_, _, _, LSB>(&mut dst, &rhs);
+        assert_eq!(dst, [0b0000_1000, 0b0000_0000, 0b0000_0000]);
+
+        let mut dst = [0b0000_1010u8];
+        difference_update_impl::<_, _, _, LSB>(&mut dst, &rhs);
+        assert_eq!(dst, [0b0000_0010]);
+
+        let mut dst = [0b0000_1010u8];
+        symmetric_difference_update_impl::<_, _, _, LSB>(&mut dst, &rhs);
+        assert_eq!(dst, [0b0000_0110]);
+
+        assert_eq!(last_tail_slot::<_, _, LSB>(&rhs, 1), Some(1));
+        assert_eq!(last_tail_slot::<_, _, LSB>(&[1u8, 0], 1), None);
+    }
+
+    #[test]
+    fn invert() {
+        let mut dst = [0b0000_1010u8, 0b1000_0000];
+        invert_impl::<_, _, LSB>(&mut dst);
+        assert_eq!(dst, [0b1111_0101, 0b0111_1111]);
+
+        let mut dst = [0b0000_1010u8, 0b1000_0000, 0b1111_1111];
+        invert_to_len_impl::<_, _, LSB>(&mut dst, 12);
+        assert_eq!(dst, [0b1111_0101, 0b0000_1111, 0]);
+    }
+}
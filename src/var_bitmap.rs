@@ -1,20 +1,48 @@
 use std::{
+    collections::{BTreeSet, HashSet},
     fmt::{Debug, Formatter},
+    iter::Enumerate,
     marker::PhantomData,
+    ops::{BitAndAssign, BitOr, Index, IndexMut, Range, Sub},
 };
 
+#[cfg(feature = "rayon")]
+use crate::par::{par_count_ones_impl, par_intersection_len_impl};
 use crate::{
-    container::{ContainerRead, ContainerWrite},
+    bit_slice::BitSlice,
+    combine::{
+        combine_into_impl, combine_len_impl, try_combine_impl, try_combine_in_impl, BitOp,
+        BitmapOps, CombinedOnes,
+    },
+    compress::{compress_impl, decompress_impl},
+    container::{validate_impl, ContainerRead, ContainerWrite},
+    debug::{DebugBytes, DebugLogical},
     grow_strategy::{FinalLength, GrowStrategy, MinimumRequiredLength},
     intersection::{
-        intersection_len_impl, try_intersection_impl, try_intersection_in_impl, Intersection,
+        intersection_into_resized_impl, intersection_is_empty_impl, intersection_len_impl,
+        try_intersection_impl,
+    },
+    iter::{Gaps, IndexedBits, IntoIter, Iter, Ones, SlidingPopcount, SlotBytes},
+    number::{Endianness, Number},
+    ordering::{
+        bit_len_impl, bits_eq_impl, compare_impl, count_ones_impl, count_ones_up_to_impl,
+        ones_summary_impl, BitmapOrdering, OnesSummary,
+    },
+    random::fill_pseudo_random_impl,
+    range::{
+        get_bits_into_impl, mask_slots_impl, set_bits_from_impl, set_range_impl,
+        slots_from_bytes_impl,
     },
-    iter::{IntoIter, Iter},
-    number::Number,
     resizable::Resizable,
-    union::{try_union_impl, try_union_in_impl, union_len_impl, Union},
+    shift::{insert_bit_impl, low_mask, remove_bit_impl},
+    union::{try_union_impl, union_into_resized_impl},
+    update::{
+        difference_len_impl, difference_update_impl, intersect_update_impl, invert_impl,
+        invert_to_len_impl, last_tail_slot, symmetric_difference_update_impl, union_update_impl,
+    },
     with_slots::TryWithSlots,
-    BitAccess, IntersectionError, ResizeError, StaticBitmap, UnionError,
+    BitAccess, BitOrder, CombineError, DecompressError, MinimumRequiredStrategy, OutOfBoundsError,
+    ResizeError, StaticBitmap, LSB,
 };
 
 /// A bitmap that can be resized by custom resizing strategy.
@@ -46,19 +74,19 @@ use crate::{
 /// assert_eq!(iter.next(), Some((4, false)));
 ///
 /// // You can check multiple bits at the same time through the intersection
-/// use bitmac::Intersection;
+/// use bitmac::{BitOp, BitmapOps};
 /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_1000]);
 /// // .. by creating specific new container for result
 /// let test = [0b0000_1001u8, 0b0000_0000];
-/// assert_eq!(bitmap.intersection::<[u8; 2]>(&test), test);
+/// assert_eq!(bitmap.combine::<[u8; 2]>(&test, BitOp::And), test);
 /// // .. by using preallocated container for result
 /// let test = [0b0000_1001u8, 0b0000_0000];
 /// let mut result = [0u8; 2];
-/// bitmap.intersection_in(&test, &mut result);
+/// bitmap.combine_in(&test, BitOp::And, &mut result);
 /// assert_eq!(result, test);
 /// // .. by comparing length of difference that is equivalent to count of ones (bits) in result
 /// let test = [0b0000_1001u8, 0b0000_0000];
-/// assert_eq!(bitmap.intersection_len(&test), test.iter().fold(0, |acc, &v| acc + v.count_ones() as usize));
+/// assert_eq!(bitmap.combine_len(&test, BitOp::And), test.iter().fold(0, |acc, &v| acc + v.count_ones() as usize));
 ///
 /// // You can directly change every bit
 /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0001_1000]);
@@ -79,13 +107,29 @@ use crate::{
 /// assert_eq!(bitmap.as_ref().len(), 16);
 /// # }
 /// ```
-#[derive(Default, Clone, Eq, PartialEq)]
+#[derive(Default, Clone)]
 pub struct VarBitmap<D, B, S> {
     data: D,
     resizing_strategy: S,
+    auto_shrink: bool,
     phantom: PhantomData<B>,
 }
 
+/// Result of [`set_reporting`].
+///
+/// [`set_reporting`]: crate::var_bitmap::VarBitmap::set_reporting
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SetOutcome {
+    /// Whether the container has grown.
+    pub grew: bool,
+    /// Length of container (in slots) before the call.
+    pub old_len: usize,
+    /// Length of container (in slots) after the call.
+    pub new_len: usize,
+    /// Whether the bit state has changed.
+    pub changed_bit: bool,
+}
+
 impl<D, B, S, N> VarBitmap<D, B, S>
 where
     D: ContainerRead<B, Slot = N>,
@@ -98,665 +142,4160 @@ where
         Self {
             data,
             resizing_strategy,
+            auto_shrink: false,
             phantom: Default::default(),
         }
     }
 
+    /// Returns number of bits in a single slot.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{BitOrder, VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u16; 2]);
+    /// assert_eq!(bitmap.slot_bits(), 16);
+    /// assert_eq!(bitmap.bit_order(), BitOrder::LSB);
+    /// ```
+    pub fn slot_bits(&self) -> usize {
+        N::BITS_COUNT
+    }
+
+    /// Returns order of bits in slots, see [`BitAccess::ORDER`].
+    ///
+    /// [`BitAccess::ORDER`]: crate::bit_access::BitAccess::ORDER
+    pub fn bit_order(&self) -> BitOrder {
+        B::ORDER
+    }
+
     /// Returns number of ones in the bitmap.
     pub fn count_ones(&self) -> usize {
-        let mut res = 0;
+        count_ones_impl(&self.data)
+    }
+
+    /// Returns `true` if the bitmap has at least `n` ones. Stops counting as soon as `n` is reached.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_0000u8, 0b0000_0011]);
+    /// assert!(bitmap.has_at_least_ones(6));
+    /// assert!(!bitmap.has_at_least_ones(7));
+    /// ```
+    pub fn has_at_least_ones(&self, n: usize) -> bool {
+        count_ones_up_to_impl(&self.data, n) >= n
+    }
+
+    /// Returns `true` if the bitmap has at most `n` ones. Stops counting as soon as `n` is exceeded.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_0000u8, 0b0000_0011]);
+    /// assert!(bitmap.has_at_most_ones(6));
+    /// assert!(!bitmap.has_at_most_ones(5));
+    /// ```
+    pub fn has_at_most_ones(&self, n: usize) -> bool {
+        count_ones_up_to_impl(&self.data, n.saturating_add(1)) <= n
+    }
+
+    /// Returns number of ones in slots `0..i` for every slot index `i` (prefix sums of ones).
+    ///
+    /// Rank of any bit can be computed from it by counting ones only in the bit's own slot.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0001, 0b1111_0000]);
+    /// assert_eq!(bitmap.slot_rank_prefix(), vec![0, 2, 3]);
+    /// ```
+    pub fn slot_rank_prefix(&self) -> Vec<usize> {
+        let mut res = Vec::with_capacity(self.data.slots_count());
+        let mut rank = 0;
         for v in self.iter() {
-            res += v.count_ones() as usize;
+            res.push(rank);
+            rank += v.count_ones() as usize;
         }
         res
     }
 
-    /// Returns number of zeros in the bitmap.
-    pub fn count_zeros(&self) -> usize {
-        let mut res = 0;
-        for v in self.iter() {
-            res += v.count_zeros() as usize;
+    /// Fills `out[j]` with state of bit `start + j`. Out of bounds bits are `false`.
+    ///
+    /// Unlike [`to_bool_vec`], it doesn't allocate, so the buffer can be reused.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0100_0000u8, 0b0000_0001]);
+    /// let mut out = [false; 4];
+    /// bitmap.get_bits_into(6, &mut out);
+    /// assert_eq!(out, [true, false, true, false]);
+    /// bitmap.get_bits_into(14, &mut out);
+    /// assert_eq!(out, [false; 4]);
+    /// ```
+    ///
+    /// [`to_bool_vec`]: crate::var_bitmap::VarBitmap::to_bool_vec
+    pub fn get_bits_into(&self, start: usize, out: &mut [bool]) {
+        get_bits_into_impl(&self.data, start, out);
+    }
+
+    /// Collects every bit up to [`bits_count`] into a vector, `i`-th element is state of `i`-th bit.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001_0000_1001u16]);
+    /// let bits = bitmap.to_bool_vec();
+    /// assert_eq!(bits.len(), 16);
+    /// assert_eq!(&bits[..9], &[true, false, false, true, false, false, false, false, true]);
+    /// ```
+    ///
+    /// [`bits_count`]: crate::container::ContainerRead::bits_count
+    pub fn to_bool_vec(&self) -> Vec<bool> {
+        (0..self.data.bits_count())
+            .map(|idx| self.data.get_bit(idx))
+            .collect()
+    }
+
+    /// Collects bits into bytes regardless of slot width: `k`-th byte contains bits `8k..8k + 8`
+    /// placed according to `BitAccess`. So the result is the same bitmap over `u8` slots.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001_0000_1001u16]);
+    /// assert_eq!(bitmap.to_byte_vec(), vec![0b0000_1001, 0b0000_0001]);
+    /// ```
+    pub fn to_byte_vec(&self) -> Vec<u8> {
+        let bits_count = self.data.bits_count();
+        let mut res = vec![0u8; (bits_count + 7) / 8];
+        for idx in self.ones() {
+            if idx >= bits_count {
+                break;
+            }
+            let byte = &mut res[idx / 8];
+            *byte = B::set(*byte, idx % 8, true);
         }
         res
     }
-}
 
-impl<D, B, S, N> VarBitmap<D, B, S>
-where
-    D: ContainerRead<B, Slot = N> + Default,
-    B: BitAccess,
-    S: GrowStrategy,
-    N: Number,
-{
-    /// Creates default bitmap with specified strategy.
-    pub fn with_resizing_strategy(resizing_strategy: S) -> Self {
-        Self {
-            data: Default::default(),
-            resizing_strategy,
-            phantom: Default::default(),
-        }
+    /// Compresses bytes in [`to_byte_vec`] layout with run-length encoding.
+    ///
+    /// The result is a sequence of two-byte runs: the number of repeats (`1..=255`)
+    /// and the repeated byte. It doesn't depend on slot width and is much smaller than raw bytes
+    /// for mostly-zero bitmaps. Use [`from_compressed`] to restore the bitmap.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8; 64]) | 100;
+    /// let compressed = bitmap.to_compressed();
+    /// assert_eq!(compressed, vec![12, 0, 1, 0b0001_0000, 51, 0]);
+    /// assert_eq!(VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_compressed(&compressed).unwrap(), bitmap);
+    /// ```
+    ///
+    /// [`to_byte_vec`]: crate::var_bitmap::VarBitmap::to_byte_vec
+    /// [`from_compressed`]: crate::var_bitmap::VarBitmap::from_compressed
+    pub fn to_compressed(&self) -> Vec<u8> {
+        compress_impl(&self.to_byte_vec())
     }
-}
 
-impl<D, B, S, N> VarBitmap<D, B, S>
-where
-    D: ContainerRead<B, Slot = N>,
-    B: BitAccess,
-    S: GrowStrategy + Default,
-    N: Number,
-{
-    /// Creates new bitmap from container with default strategy.
-    pub fn from_container(data: D) -> Self {
-        Self {
-            data,
-            resizing_strategy: Default::default(),
-            phantom: Default::default(),
-        }
+    /// Same as [`to_byte_vec`], but the result has exactly `byte_len` bytes: it is zero-padded
+    /// if the bitmap is shorter and truncated if it is longer. Bits beyond `byte_len * 8` are
+    /// dropped even if they are set, check [`bit_len`] beforehand if they matter.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001_0000_1001u16]);
+    /// assert_eq!(bitmap.to_bytes_padded(3), vec![0b0000_1001, 0b0000_0001, 0]);
+    /// assert_eq!(bitmap.to_bytes_padded(1), vec![0b0000_1001]);
+    /// ```
+    ///
+    /// [`to_byte_vec`]: crate::var_bitmap::VarBitmap::to_byte_vec
+    /// [`bit_len`]: crate::var_bitmap::VarBitmap::bit_len
+    pub fn to_bytes_padded(&self, byte_len: usize) -> Vec<u8> {
+        let mut res = self.to_byte_vec();
+        res.resize(byte_len, 0);
+        res
     }
-}
 
-impl<D, B, S> VarBitmap<D, B, S> {
-    /// Converts bitmap into inner container.
-    pub fn into_inner(self) -> D {
-        self.data
+    /// Collects raw bytes of every slot, bytes of a slot are in specified order.
+    ///
+    /// Unlike [`to_byte_vec`], it doesn't reorder bits, so the result depends on slot width.
+    /// [`Endianness::Little`] matches `Debug` output, [`Endianness::Big`] matches big-endian
+    /// wire formats.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, Endianness, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xAABBu16, 0x0102]);
+    /// assert_eq!(bitmap.to_slot_bytes(Endianness::Big), vec![0xAA, 0xBB, 0x01, 0x02]);
+    /// assert_eq!(bitmap.to_slot_bytes(Endianness::Little), vec![0xBB, 0xAA, 0x02, 0x01]);
+    /// ```
+    ///
+    /// [`to_byte_vec`]: crate::var_bitmap::VarBitmap::to_byte_vec
+    /// [`Endianness::Little`]: crate::number::Endianness::Little
+    /// [`Endianness::Big`]: crate::number::Endianness::Big
+    pub fn to_slot_bytes(&self, endianness: Endianness) -> Vec<u8> {
+        let mut res = Vec::with_capacity(self.data.slots_count().saturating_mul(N::BYTES_COUNT));
+        for slot in self.iter() {
+            res.extend(endianness.slot_bytes(slot));
+        }
+        res
     }
-}
 
-impl<D, B, S, N> VarBitmap<D, B, S>
-where
-    D: ContainerRead<B, Slot = N>,
-    N: Number,
-    B: BitAccess,
-{
-    /// Represents bitmap as static bitmap over `&D` container.
-    pub fn as_static<'a>(&'a self) -> StaticBitmap<&'a D, B>
-    where
-        &'a D: ContainerRead<B>,
-    {
-        StaticBitmap::from(&self.data)
+    /// Returns iterator over raw bytes of every slot, bytes of a slot are in specified order.
+    ///
+    /// It is a lazy version of [`to_slot_bytes`], useful to stream the bitmap into a writer
+    /// without collecting bytes first.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, Endianness, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xAABBCCDDu32]);
+    /// let bytes = bitmap.by_bytes_endian(Endianness::Big).collect::<Vec<_>>();
+    /// assert_eq!(bytes, 0xAABBCCDDu32.to_be_bytes());
+    /// ```
+    ///
+    /// [`to_slot_bytes`]: crate::var_bitmap::VarBitmap::to_slot_bytes
+    pub fn by_bytes_endian(&self, endianness: Endianness) -> SlotBytes<'_, D, B> {
+        SlotBytes::new(&self.data, endianness)
     }
 
-    /// Converts bitmap into static bitmap.
-    pub fn into_static(self) -> StaticBitmap<D, B> {
-        StaticBitmap::from(self.data)
+    /// Returns logical length of the bitmap: index of the last set bit plus one,
+    /// or `0` if no bit is set. Trailing zero slots are not counted.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// assert_eq!(VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0]).bit_len(), 4);
+    /// assert_eq!(VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8, 0]).bit_len(), 0);
+    /// ```
+    pub fn bit_len(&self) -> usize {
+        bit_len_impl(&self.data)
     }
-}
 
-impl<D, B, S> VarBitmap<D, B, S>
-where
-    D: ContainerRead<B>,
-    B: BitAccess,
-{
-    /// Gets single bit state.
+    /// Returns number of set bits with indexes of the first and the last of them,
+    /// computed in a single pass over slots.
     ///
     /// Usage example:
     /// ```
-    /// use bitmac::{StaticBitmap, LSB};
+    /// use bitmac::{OnesSummary, VarBitmap, LSB, MinimumRequiredStrategy};
     ///
-    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001u8, 0b0000_1000]);
-    /// assert!(bitmap.get(0));
-    /// assert!(bitmap.get(11));
-    /// assert!(!bitmap.get(13));
-    /// // Out of bounds bits always returns false
-    /// assert!(!bitmap.get(128));
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1010u8, 0, 0b0000_0001]);
+    /// assert_eq!(
+    ///     bitmap.ones_summary(),
+    ///     OnesSummary { count: 3, first: Some(1), last: Some(16) }
+    /// );
     /// ```
-    pub fn get(&self, idx: usize) -> bool {
-        self.data.get_bit(idx)
+    pub fn ones_summary(&self) -> OnesSummary {
+        ones_summary_impl(&self.data)
     }
 
-    /// Returns iterator over slots.
-    pub fn iter(&self) -> Iter<'_, D, B> {
-        Iter::new(&self.data)
+    /// Returns `true` if no bit is set. Stops at the first non-zero slot.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// assert!(VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8; 2]).is_empty());
+    /// assert!(!VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8, 0b0000_0100]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.first_nonzero_slot().is_none()
     }
-}
 
-impl<D, B, S, N> VarBitmap<D, B, S>
-where
-    D: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
-    N: Number,
-    S: GrowStrategy,
-    B: BitAccess,
-{
-    /// Sets new state for a single bit.
+    /// Checks that the container is consistent: `bits_count()` matches `slots_count()`,
+    /// `as_slot_slice` (if any) holds the same slots and every bit read by `get_bit` matches
+    /// its slot. Returns description of the first inconsistency.
+    ///
+    /// It reads every bit of the container, so it is meant as a debugging aid for implementors
+    /// of custom containers, not for regular use.
     ///
     /// ## Panic
     ///
-    /// Panics if resizing fails.
-    /// See non-panic function [`try_set`].
+    /// Panics if the container panics on reading a slot in `0..slots_count()`, e.g. if it
+    /// reports more slots than it stores and doesn't provide `as_slot_slice`.
     ///
-    /// ## Usage example:
+    /// Usage example:
     /// ```
-    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy, LimitStrategy};
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
     ///
-    /// let mut bitmap = VarBitmap::<_, LSB, LimitStrategy<MinimumRequiredStrategy>>::new(
-    ///     vec![0u8; 1], LimitStrategy{ strategy: Default::default(), limit: 3 },
-    /// );
-    /// bitmap.set(6, true);
-    /// assert!(bitmap.get(6));
-    /// bitmap.set(13, true);
-    /// assert!(bitmap.get(13));
-    /// bitmap.set(13, false);
-    /// assert!(!bitmap.get(13));
-    /// // bitmap.set(128, false); <-- Panics
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8, 0b0000_0100]);
+    /// assert!(bitmap.validate().is_ok());
     /// ```
-    ///
-    /// [`try_set`]: crate::var_bitmap::VarBitmap::try_set
-    pub fn set(&mut self, idx: usize, val: bool) {
-        self.try_set(idx, val).unwrap();
+    pub fn validate(&self) -> Result<(), String> {
+        validate_impl(&self.data)
     }
 
-    /// Sets new state for a single bit.
+    /// Returns index of the first slot with at least one set bit.
     ///
-    /// Returns `Err(_)` if resizing fails.
+    /// If container is a contiguous slice, zero regions are skipped by blocks of slots.
     ///
-    /// ## Usage example:
+    /// Usage example:
     /// ```
-    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy, LimitStrategy};
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
     ///
-    /// let mut bitmap = VarBitmap::<_, LSB, LimitStrategy<MinimumRequiredStrategy>>::new(
-    ///     vec![0u8; 1], LimitStrategy{ strategy: Default::default(), limit: 3 },
-    /// );
-    /// assert!(bitmap.try_set(12, true).is_ok());
-    /// assert!(bitmap.get(12));
-    /// assert_eq!(bitmap.as_ref().len(), 2);
-    /// assert!(bitmap.try_set(12, false).is_ok());
-    /// assert!(!bitmap.get(12));
-    /// assert_eq!(bitmap.as_ref().len(), 2);
-    /// // Grow strategy returns error
-    /// assert!(bitmap.try_set(128, true).is_err());
-    /// assert!(!bitmap.get(128));
-    /// assert_eq!(bitmap.as_ref().len(), 2);
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8, 0, 0b0000_0100]);
+    /// assert_eq!(bitmap.first_nonzero_slot(), Some(2));
     /// ```
-    pub fn try_set(&mut self, idx: usize, val: bool) -> Result<(), ResizeError> {
-        let max_idx = self.data.bits_count();
-        if idx < max_idx {
-            self.data.set_bit_unchecked(idx, val);
-        } else {
-            // Try to resize container
-            let old_len = self.data.slots_count();
-            let min_req_len = old_len + (idx - max_idx) / N::BITS_COUNT + 1;
-            let min_req_len = MinimumRequiredLength(min_req_len);
+    pub fn first_nonzero_slot(&self) -> Option<usize> {
+        if let Some(slots) = self.data.as_slot_slice() {
+            return N::first_nonzero_in(slots);
+        }
+        (0..self.data.slots_count()).find(|&i| self.data.get_slot(i) != N::ZERO)
+    }
 
-            // Call .try_resize() if new value is `1` or if strategy supports force resizing
-            if val || self.resizing_strategy.is_force_grow() {
-                let FinalLength(new_len) =
-                    self.resizing_strategy.try_grow(min_req_len, old_len, idx)?;
+    /// Returns `true` if every bit up to [`bits_count`] is set. Stops at the first slot with zero bit.
+    ///
+    /// Padding bits of the last slot (if container has any) are not checked.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// assert!(VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFu8; 2]).is_full());
+    /// assert!(!VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFu8, 0b0111_1111]).is_full());
+    /// ```
+    ///
+    /// [`bits_count`]: crate::container::ContainerRead::bits_count
+    pub fn is_full(&self) -> bool {
+        let bits_count = self.data.bits_count();
+        let full_slots = bits_count / N::BITS_COUNT;
+        let rest_bits = bits_count % N::BITS_COUNT;
 
-                // Resize container if new length doesn't match old length
-                if new_len != old_len {
-                    self.data.resize(new_len, N::ZERO);
-                }
-                self.data.set_bit_unchecked(idx, val);
-            }
+        if !(0..full_slots).all(|i| self.data.get_slot(i) == N::MAX) {
+            return false;
+        }
+        if rest_bits == 0 {
+            return true;
         }
+        let mask = low_mask::<N, B>(rest_bits);
+        self.data.get_slot(full_slots) & mask == mask
+    }
 
-        Ok(())
+    /// Returns number of zeros in the bitmap.
+    pub fn count_zeros(&self) -> usize {
+        let mut res = 0;
+        for v in self.iter() {
+            res += v.count_zeros() as usize;
+        }
+        res
     }
-}
 
-impl<D, N, B, S> From<D> for VarBitmap<D, B, S>
-where
-    D: ContainerRead<B, Slot = N>,
-    N: Number,
-    B: BitAccess,
-    S: Default,
-{
-    fn from(f: D) -> Self {
-        Self {
-            data: f,
-            resizing_strategy: Default::default(),
-            phantom: Default::default(),
-        }
+    /// Compares sets of ones of the bitmap and `other` in a single pass.
+    ///
+    /// Containers may have different lengths, missing slots are treated as zeros.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{BitmapOrdering, VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+    /// assert_eq!(bitmap.compare(&[0b0000_1001u8, 0]), BitmapOrdering::Equal);
+    /// assert_eq!(bitmap.compare(&[0b0000_1011u8]), BitmapOrdering::Subset);
+    /// assert_eq!(bitmap.compare(&[0b0000_0001u8]), BitmapOrdering::Superset);
+    /// assert_eq!(bitmap.compare(&[0b0000_0001u8, 1]), BitmapOrdering::Incomparable);
+    /// ```
+    pub fn compare<Rhs>(&self, other: &Rhs) -> BitmapOrdering
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        compare_impl(&self.data, other)
     }
-}
 
-impl<D, B, S> AsRef<D> for VarBitmap<D, B, S> {
-    fn as_ref(&self) -> &D {
-        &self.data
+    /// Checks if the bitmap and `other` have the same ones, regardless of container lengths.
+    ///
+    /// Logical lengths (see [`bit_len`]) are compared first, then only slots up to the last
+    /// set bit, stopping at the first different slot. It is faster than comparing
+    /// whole containers for large mostly-zero bitmaps.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0, 0, 0]);
+    /// assert!(bitmap.bits_eq(&[0b0000_1001u8]));
+    /// assert!(!bitmap.bits_eq(&[0b0000_1001u8, 1]));
+    /// ```
+    ///
+    /// [`bit_len`]: crate::var_bitmap::VarBitmap::bit_len
+    pub fn bits_eq<Rhs>(&self, other: &Rhs) -> bool
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        bits_eq_impl(&self.data, other)
     }
-}
 
-impl<D, B, S> AsMut<D> for VarBitmap<D, B, S> {
-    fn as_mut(&mut self) -> &mut D {
-        &mut self.data
+    /// Returns `true` if the bitmap and `other` have no common ones. Unlike checking
+    /// `intersection_len() == 0`, it stops at the first common one and never allocates.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1111u8]);
+    /// assert!(bitmap.intersection_is_empty(&[0b1111_0000u8]));
+    /// assert!(!bitmap.intersection_is_empty(&[0b0000_1000u8, 0b1111_1111]));
+    /// ```
+    pub fn intersection_is_empty<Rhs>(&self, other: &Rhs) -> bool
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        intersection_is_empty_impl(&self.data, other)
     }
-}
-impl<D, B, S> ContainerRead<B> for VarBitmap<D, B, S>
-where
-    D: ContainerRead<B>,
-    B: BitAccess,
-{
-    type Slot = D::Slot;
 
-    fn get_slot(&self, idx: usize) -> Self::Slot {
-        self.data.get_slot(idx)
+    /// Calculates overlap coefficient: `intersection_len / min(count_ones, other_count_ones)`.
+    ///
+    /// Returns `0.0` if either bitmap has no ones.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1111u8]);
+    /// assert_eq!(bitmap.overlap_coefficient(&[0b0011_0011u8]), 0.5);
+    /// assert_eq!(bitmap.overlap_coefficient(&[0b0000_0011u8]), 1.0);
+    /// assert_eq!(bitmap.overlap_coefficient(&[0u8]), 0.0);
+    /// ```
+    pub fn overlap_coefficient<Rhs>(&self, other: &Rhs) -> f64
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        let denominator = usize::min(self.count_ones(), count_ones_impl(other));
+        if denominator == 0 {
+            return 0.0;
+        }
+        intersection_len_impl(&self.data, other) as f64 / denominator as f64
     }
 
-    fn slots_count(&self) -> usize {
-        self.data.slots_count()
+    /// Calculates containment of the bitmap in `other`: `intersection_len / count_ones`,
+    /// i.e. the fraction of ones of the bitmap that are set in `other`.
+    ///
+    /// Returns `0.0` if the bitmap has no ones.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1111u8]);
+    /// assert_eq!(bitmap.containment(&[0b0000_0011u8]), 0.5);
+    /// assert_eq!(bitmap.containment(&[0xFFu8]), 1.0);
+    /// ```
+    pub fn containment<Rhs>(&self, other: &Rhs) -> f64
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        let denominator = self.count_ones();
+        if denominator == 0 {
+            return 0.0;
+        }
+        intersection_len_impl(&self.data, other) as f64 / denominator as f64
     }
-}
 
-impl<D, B, S> ContainerWrite<B> for VarBitmap<D, B, S>
-where
-    D: ContainerWrite<B>,
-    B: BitAccess,
-{
-    fn get_mut_slot(&mut self, idx: usize) -> &mut Self::Slot {
-        self.data.get_mut_slot(idx)
+    /// Calculates difference length - number of ones of the bitmap that are not set in `other`
+    /// (`self & !other`). It doesn't allocate for storing difference result.
+    ///
+    /// Missing slots of `other` are treated as zeros, so all ones of the bitmap beyond
+    /// `other` are counted.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1011u8, 0b0000_0011]);
+    /// assert_eq!(bitmap.difference_len(&[0b0000_0001u8]), 4);
+    /// ```
+    pub fn difference_len<Rhs>(&self, other: &Rhs) -> usize
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        difference_len_impl(&self.data, other)
     }
-}
 
-impl<D, B, S, N> Debug for VarBitmap<D, B, S>
-where
-    D: ContainerRead<B, Slot = N>,
-    N: Number,
-    B: BitAccess,
-{
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut list = f.debug_list();
-        for i in 0..self.data.slots_count() {
-            let slot = self.data.get_slot(i);
-            for j in 0..N::BYTES_COUNT {
-                let byte = (slot >> (j * 8)) & N::BYTE_MASK;
-                list.entry(&format_args!("{:#010b}", byte));
-            }
+    /// Copies slots of the bitmap into `dst`, resizing it to the same length.
+    ///
+    /// Allocation of `dst` is reused, so it is cheaper than `clone()` for repeated snapshots,
+    /// like [`Vec::clone_from`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// let mut snapshot = StaticBitmap::<Vec<u8>, LSB>::default();
+    /// bitmap.clone_into_bitmap(&mut snapshot);
+    /// assert_eq!(snapshot.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+    /// ```
+    pub fn clone_into_bitmap(&self, dst: &mut StaticBitmap<Vec<N>, B>) {
+        let len = self.data.slots_count();
+        let dst = dst.as_mut();
+        dst.resize(len, N::ZERO);
+        for (i, slot) in dst.iter_mut().enumerate() {
+            *slot = self.data.get_slot(i);
         }
-        list.finish()
     }
-}
 
-impl<D, B, S> IntoIterator for VarBitmap<D, B, S>
-where
-    D: ContainerRead<B>,
-    B: BitAccess,
-{
-    type Item = <IntoIter<D, B> as Iterator>::Item;
-    type IntoIter = IntoIter<D, B>;
+    /// Calculates intersection in-place. Result will be stored in `dst`, which is resized
+    /// to the exact length of result beforehand, so no stale slots remain.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_1000]);
+    /// let mut result = vec![0xFFu8; 4];
+    /// bitmap.intersection_into_resized(&[0b0000_0001u8], &mut result);
+    /// assert_eq!(result, vec![0b0000_0001]);
+    /// ```
+    pub fn intersection_into_resized<Rhs, Dst>(&self, rhs: &Rhs, dst: &mut Dst)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+        Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    {
+        intersection_into_resized_impl(&self.data, rhs, dst);
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter::new(self.data)
+    /// Calculates intersection into `scratch` vector, which is resized to the exact length
+    /// of result. Capacity of `scratch` is kept, so reusing it across calls doesn't allocate
+    /// once it is large enough. It is [`intersection_into_resized`] specialized for `Vec`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_1000]);
+    /// let mut scratch = Vec::new();
+    /// bitmap.intersection_reuse(&[0b0000_0001u8], &mut scratch);
+    /// assert_eq!(scratch, vec![0b0000_0001]);
+    /// bitmap.intersection_reuse(&[0b0000_1000u8, 0b1111_1111], &mut scratch);
+    /// assert_eq!(scratch, vec![0b0000_1000, 0b0000_1000]);
+    /// ```
+    ///
+    /// [`intersection_into_resized`]: crate::var_bitmap::VarBitmap::intersection_into_resized
+    pub fn intersection_reuse<Rhs>(&self, rhs: &Rhs, scratch: &mut Vec<N>)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        intersection_into_resized_impl(&self.data, rhs, scratch);
     }
-}
 
-impl<'a, D, B, S> IntoIterator for &'a VarBitmap<D, B, S>
-where
-    D: ContainerRead<B>,
-    B: BitAccess,
-{
-    type Item = <Iter<'a, D, B> as Iterator>::Item;
-    type IntoIter = Iter<'a, D, B>;
+    /// Calculates union in-place. Result will be stored in `dst`, which is resized
+    /// to the exact length of result beforehand, so it doesn't need to be pre-sized.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_1000]);
+    /// let mut result = Vec::new();
+    /// bitmap.union_into_resized(&[0b0000_0010u8, 0, 0b0000_0001], &mut result);
+    /// assert_eq!(result, vec![0b0000_1011, 0b0000_1000, 0b0000_0001]);
+    /// ```
+    pub fn union_into_resized<Rhs, Dst>(&self, rhs: &Rhs, dst: &mut Dst)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+        Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    {
+        union_into_resized_impl(&self.data, rhs, dst);
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Combines the bitmap and `rhs` slot by slot with `op` and stores result in `dst`,
+    /// which is resized to the exact length of result beforehand. Containers of the bitmap,
+    /// `rhs` and `dst` may be of different types.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{BitOp, VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_1000]);
+    /// let rhs = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container([0b0000_0011u8]);
+    /// let mut result = Vec::new();
+    /// bitmap.combine_into(&rhs, BitOp::And, &mut result);
+    /// assert_eq!(result, vec![0b0000_0001]);
+    /// bitmap.combine_into(&rhs, BitOp::Xor, &mut result);
+    /// assert_eq!(result, vec![0b0000_1010, 0b0000_1000]);
+    /// bitmap.combine_into(&rhs, BitOp::AndNot, &mut result);
+    /// assert_eq!(result, vec![0b0000_1000, 0b0000_1000]);
+    /// ```
+    pub fn combine_into<Rhs, Dst>(&self, rhs: &Rhs, op: BitOp, dst: &mut Dst)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+        Dst: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    {
+        combine_into_impl(&self.data, rhs, op, dst);
     }
-}
 
-impl<D, B, S, Rhs, N> Intersection<Rhs, N, B> for VarBitmap<D, B, S>
-where
-    D: ContainerRead<B, Slot = N>,
-    B: BitAccess,
-    Rhs: ContainerRead<B, Slot = N>,
-    N: Number,
-{
-    fn intersection_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst)
+    /// Returns iterator over indexes of bits set in both the bitmap and `other`, in ascending
+    /// order. Slots are intersected on the fly, so nothing is allocated. Bits that exceed
+    /// the shorter container are never yielded.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1011u8, 0b0000_0001]);
+    /// let common = bitmap.common_ones(&[0b0000_1110u8]).collect::<Vec<_>>();
+    /// assert_eq!(common, vec![1, 3]);
+    /// ```
+    pub fn common_ones<'a, Rhs>(&'a self, other: &'a Rhs) -> CombinedOnes<'a, D, Rhs, N, B>
     where
-        Dst: ContainerWrite<B, Slot = N>,
+        Rhs: ContainerRead<B, Slot = N>,
     {
-        try_intersection_in_impl(&self.data, rhs, dst).unwrap();
+        CombinedOnes::new(&self.data, other, BitOp::And)
     }
 
-    fn try_intersection_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst) -> Result<(), IntersectionError>
+    /// Returns iterator over indexes of bits set in exactly one of the bitmap and `other`
+    /// (symmetric difference), in ascending order. Slots are combined on the fly, so nothing
+    /// is allocated. Bits that exceed the shorter container are yielded as is.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1011u8]);
+    /// let changed = bitmap.symmetric_ones(&[0b0000_1110u8, 0b0000_0001]).collect::<Vec<_>>();
+    /// assert_eq!(changed, vec![0, 2, 8]);
+    /// ```
+    pub fn symmetric_ones<'a, Rhs>(&'a self, other: &'a Rhs) -> CombinedOnes<'a, D, Rhs, N, B>
     where
-        Dst: ContainerWrite<B, Slot = N>,
+        Rhs: ContainerRead<B, Slot = N>,
     {
-        try_intersection_in_impl(&self.data, rhs, dst)
+        CombinedOnes::new(&self.data, other, BitOp::Xor)
     }
 
-    fn intersection<Dst>(&self, rhs: &Rhs) -> Dst
+    /// Calculates intersection and wraps result into [`VarBitmap`] with default strategy,
+    /// so the result can be grown afterwards.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_1000]);
+    /// let mut result = bitmap.intersection_var::<_, MinimumRequiredStrategy>(&[0b0000_0001u8]);
+    /// assert_eq!(result.as_ref(), &vec![0b0000_0001]);
+    /// result.set(20, true);
+    /// assert_eq!(result.as_ref(), &vec![0b0000_0001, 0b0000_0000, 0b0001_0000]);
+    /// ```
+    ///
+    /// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+    pub fn intersection_var<Rhs, S2>(&self, rhs: &Rhs) -> VarBitmap<Vec<N>, B, S2>
     where
-        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+        Rhs: ContainerRead<B, Slot = N>,
+        S2: GrowStrategy + Default,
     {
-        try_intersection_impl(&self.data, rhs).unwrap()
+        // Result container is created with required size, so it cannot fail
+        VarBitmap::from_container(try_intersection_impl(&self.data, rhs).unwrap())
     }
 
-    fn try_intersection<Dst>(&self, rhs: &Rhs) -> Result<Dst, IntersectionError>
+    /// Calculates union and wraps result into [`VarBitmap`] with default strategy,
+    /// so the result can be grown afterwards.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_1000]);
+    /// let mut result = bitmap.union_var::<_, MinimumRequiredStrategy>(&[0b0000_0010u8]);
+    /// assert_eq!(result.as_ref(), &vec![0b0000_1011, 0b0000_1000]);
+    /// result.set(20, true);
+    /// assert_eq!(result.as_ref(), &vec![0b0000_1011, 0b0000_1000, 0b0001_0000]);
+    /// ```
+    ///
+    /// [`VarBitmap`]: crate::var_bitmap::VarBitmap
+    pub fn union_var<Rhs, S2>(&self, rhs: &Rhs) -> VarBitmap<Vec<N>, B, S2>
     where
-        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+        Rhs: ContainerRead<B, Slot = N>,
+        S2: GrowStrategy + Default,
     {
-        try_intersection_impl(&self.data, rhs)
+        // Result container is created with required size, so it cannot fail
+        VarBitmap::from_container(try_union_impl(&self.data, rhs).unwrap())
     }
+}
 
-    fn intersection_len(&self, rhs: &Rhs) -> usize {
-        intersection_len_impl(&self.data, rhs)
+impl<D, B, S, N> VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N> + Default,
+    B: BitAccess,
+    S: GrowStrategy,
+    N: Number,
+{
+    /// Creates default bitmap with specified strategy.
+    pub fn with_resizing_strategy(resizing_strategy: S) -> Self {
+        Self {
+            data: Default::default(),
+            resizing_strategy,
+            auto_shrink: false,
+            phantom: Default::default(),
+        }
     }
 }
 
-impl<D, B, S, Rhs, N> Union<Rhs, N, B> for VarBitmap<D, B, S>
+impl<D, B, S, N> VarBitmap<D, B, S>
 where
     D: ContainerRead<B, Slot = N>,
     B: BitAccess,
-    Rhs: ContainerRead<B, Slot = N>,
+    S: GrowStrategy + Default,
     N: Number,
 {
-    fn union_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst)
-    where
-        Dst: ContainerWrite<B, Slot = N>,
-    {
-        try_union_in_impl(&self.data, rhs, dst).unwrap();
+    /// Creates new bitmap from container with default strategy.
+    pub fn from_container(data: D) -> Self {
+        Self {
+            data,
+            resizing_strategy: Default::default(),
+            auto_shrink: false,
+            phantom: Default::default(),
+        }
     }
+}
 
-    fn try_union_in<Dst>(&self, rhs: &Rhs, dst: &mut Dst) -> Result<(), UnionError>
+impl<N, B, S> VarBitmap<Vec<N>, B, S>
+where
+    N: Number,
+    B: BitAccess,
+    S: GrowStrategy + Default,
+{
+    /// Creates new bitmap from slots with default strategy.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_slots([0b0000_0001u8, 0b0000_0010]);
+    /// assert!(bitmap.get(0));
+    /// assert!(bitmap.get(9));
+    /// assert_eq!(bitmap.as_ref().len(), 2);
+    /// ```
+    pub fn from_slots<I>(slots: I) -> Self
     where
-        Dst: ContainerWrite<B, Slot = N>,
+        I: IntoIterator<Item = N>,
     {
-        try_union_in_impl(&self.data, rhs, dst)
+        Self {
+            data: slots.into_iter().collect(),
+            resizing_strategy: Default::default(),
+            auto_shrink: false,
+            phantom: Default::default(),
+        }
     }
 
-    fn union<Dst>(&self, rhs: &Rhs) -> Dst
-    where
-        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
-    {
-        try_union_impl(&self.data, rhs).unwrap()
+    /// Creates bitmap with `n` zero slots and default strategy. Unlike reserving capacity,
+    /// the slots are part of the container, so setting any bit below `n * BITS_COUNT`
+    /// neither consults the strategy nor reallocates.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::with_initial_slots(4);
+    /// assert_eq!(bitmap.as_ref(), &vec![0; 4]);
+    /// bitmap.set(31, true);
+    /// assert_eq!(bitmap.as_ref().len(), 4);
+    /// ```
+    pub fn with_initial_slots(n: usize) -> Self {
+        Self::from_container(vec![N::ZERO; n])
     }
 
-    fn try_union<Dst>(&self, rhs: &Rhs) -> Result<Dst, UnionError>
-    where
-        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
-    {
-        try_union_impl(&self.data, rhs)
+    /// Creates bitmap from bytes in [`to_byte_vec`] layout. Number of slots is enough to hold
+    /// every byte, padding zero bytes are kept as zero slots.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_bytes_padded(&[0b0000_1001, 0b0000_0001, 0]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001_0000_1001u16, 0]);
+    /// ```
+    ///
+    /// [`to_byte_vec`]: crate::var_bitmap::VarBitmap::to_byte_vec
+    pub fn from_bytes_padded(bytes: &[u8]) -> Self {
+        Self::from_slots(slots_from_bytes_impl::<N, B>(bytes))
     }
 
-    fn union_len(&self, rhs: &Rhs) -> usize {
-        union_len_impl(&self.data, rhs)
+    /// Creates bitmap from the form produced by [`to_compressed`].
+    ///
+    /// Returns `Err(_)` if the form is malformed: its length is odd or some run is empty.
+    ///
+    /// [`to_compressed`]: crate::var_bitmap::VarBitmap::to_compressed
+    pub fn from_compressed(compressed: &[u8]) -> Result<Self, DecompressError> {
+        Ok(Self::from_bytes_padded(&decompress_impl(compressed)?))
     }
-}
 
-#[cfg(test)]
+    /// Creates bitmap with bits set at every index of `indices`. Container is allocated once
+    /// to fit the last index, then bits are set without growth checks.
+    ///
+    /// `indices` must be sorted in ascending order, duplicates are allowed.
+    ///
+    /// ## Panic
+    ///
+    /// Panics in debug builds if `indices` are not sorted. In release builds unsorted `indices`
+    /// panic only if some index doesn't fit the container sized by the last one.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_sorted_indices(&[0, 3, 9]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+    /// ```
+    pub fn from_sorted_indices(indices: &[usize]) -> Self {
+        debug_assert!(
+            indices.windows(2).all(|w| w[0] <= w[1]),
+            "indices are not sorted"
+        );
+        from_indexes(indices.iter().copied(), indices.last().copied())
+    }
+
+    /// Creates bitmap with bits `0..n` set. Number of slots is minimal to hold `n` bits.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::prefix_mask(10);
+    /// assert_eq!(bitmap.count_ones(), 10);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b1111_1111, 0b0000_0011]);
+    /// ```
+    pub fn prefix_mask(n: usize) -> Self {
+        Self::from_slots(mask_slots_impl::<N, B>(n, 0..n))
+    }
+
+    /// Creates `total_bits` wide bitmap with the last `n` bits (`total_bits - n..total_bits`) set.
+    /// Number of slots is minimal to hold `total_bits` bits.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `n` is greater than `total_bits`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::suffix_mask(12, 6);
+    /// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![6, 7, 8, 9, 10, 11]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b1100_0000, 0b0000_1111]);
+    /// ```
+    pub fn suffix_mask(total_bits: usize, n: usize) -> Self {
+        assert!(
+            n <= total_bits,
+            "mask of {} bits exceeds {} bits",
+            n,
+            total_bits
+        );
+        Self::from_slots(mask_slots_impl::<N, B>(
+            total_bits,
+            total_bits - n..total_bits,
+        ))
+    }
+}
+
+impl<D, B, S> VarBitmap<D, B, S>
+where
+    S: GrowStrategy,
+{
+    /// Returns human readable description of resizing strategy, see [`GrowStrategy::describe`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, FixedStrategy, LimitStrategy};
+    ///
+    /// let bitmap = VarBitmap::<Vec<u8>, LSB, _>::new(vec![], LimitStrategy { strategy: FixedStrategy(3), limit: 9 });
+    /// assert_eq!(bitmap.strategy_description(), "limit(9, fixed(3))");
+    /// ```
+    ///
+    /// [`GrowStrategy::describe`]: crate::grow_strategy::GrowStrategy::describe
+    pub fn strategy_description(&self) -> String {
+        self.resizing_strategy.describe()
+    }
+}
+
+impl<D, B, S> VarBitmap<D, B, S> {
+    /// Converts bitmap into inner container.
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+
+    /// Returns reference to resizing strategy.
+    pub fn strategy(&self) -> &S {
+        &self.resizing_strategy
+    }
+
+    /// Returns mutable reference to resizing strategy.
+    pub fn strategy_mut(&mut self) -> &mut S {
+        &mut self.resizing_strategy
+    }
+
+    /// Enables or disables auto-shrinking, it is disabled by default.
+    ///
+    /// When enabled, methods that clear bits check whether the last set bit now lies
+    /// in the lower half of the container, and if so truncate trailing zero slots.
+    /// So long-lived bitmaps don't keep their peak size. These are [`try_set`] (and [`set`]),
+    /// [`clear_if_set`], [`try_set_range`] (and [`set_range`]), [`difference_update`],
+    /// [`clear_where`], [`intersect_update`] and [`remove_bit`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8]);
+    /// bitmap.set_auto_shrink(true);
+    /// bitmap.set(39, true);
+    /// assert_eq!(bitmap.as_ref().len(), 5);
+    /// bitmap.set(39, false);
+    /// assert_eq!(bitmap.as_ref().len(), 1);
+    /// ```
+    ///
+    /// [`try_set`]: crate::var_bitmap::VarBitmap::try_set
+    /// [`set`]: crate::var_bitmap::VarBitmap::set
+    /// [`clear_if_set`]: crate::var_bitmap::VarBitmap::clear_if_set
+    /// [`try_set_range`]: crate::var_bitmap::VarBitmap::try_set_range
+    /// [`set_range`]: crate::var_bitmap::VarBitmap::set_range
+    /// [`difference_update`]: crate::var_bitmap::VarBitmap::difference_update
+    /// [`clear_where`]: crate::var_bitmap::VarBitmap::clear_where
+    /// [`intersect_update`]: crate::var_bitmap::VarBitmap::intersect_update
+    /// [`remove_bit`]: crate::var_bitmap::VarBitmap::remove_bit
+    pub fn set_auto_shrink(&mut self, enabled: bool) {
+        self.auto_shrink = enabled;
+    }
+
+    /// Returns `true` if auto-shrinking is enabled, see [`set_auto_shrink`].
+    ///
+    /// [`set_auto_shrink`]: crate::var_bitmap::VarBitmap::set_auto_shrink
+    pub fn auto_shrink(&self) -> bool {
+        self.auto_shrink
+    }
+
+    /// Replaces resizing strategy. Returns bitmap over the same container.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, FixedStrategy, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, _>::new(vec![0u8], FixedStrategy(8));
+    /// let mut bitmap = bitmap.set_strategy(MinimumRequiredStrategy);
+    /// bitmap.set(9, true);
+    /// assert_eq!(bitmap.as_ref().len(), 2);
+    /// ```
+    pub fn set_strategy<S2>(self, resizing_strategy: S2) -> VarBitmap<D, B, S2>
+    where
+        S2: GrowStrategy,
+    {
+        VarBitmap {
+            data: self.data,
+            resizing_strategy,
+            auto_shrink: self.auto_shrink,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Moves container into bitmap with another bit access. Container is not changed, so
+    /// **indexes of set bits change**: `i`-th bit of the result is not `i`-th bit of the
+    /// bitmap anymore, because slots are read according to `B2`.
+    ///
+    /// Use it only if container was written with `B2` in the first place. To keep indexes of
+    /// set bits, copy them into a new bitmap instead.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8]);
+    /// assert!(bitmap.get(0));
+    /// let bitmap = bitmap.reinterpret_bits::<MSB>();
+    /// assert!(!bitmap.get(0));
+    /// assert!(bitmap.get(7));
+    /// ```
+    pub fn reinterpret_bits<B2>(self) -> VarBitmap<D, B2, S>
+    where
+        B2: BitAccess,
+    {
+        VarBitmap {
+            data: self.data,
+            resizing_strategy: self.resizing_strategy,
+            auto_shrink: self.auto_shrink,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<D, B, S, N> VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    /// Represents bitmap as static bitmap over `&D` container.
+    pub fn as_static<'a>(&'a self) -> StaticBitmap<&'a D, B>
+    where
+        &'a D: ContainerRead<B>,
+    {
+        StaticBitmap::from(&self.data)
+    }
+
+    /// Converts bitmap into static bitmap.
+    pub fn into_static(self) -> StaticBitmap<D, B> {
+        StaticBitmap::from(self.data)
+    }
+}
+
+impl<D, B, S> VarBitmap<D, B, S>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    /// Gets single bit state.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{StaticBitmap, LSB};
+    ///
+    /// let bitmap = StaticBitmap::<_, LSB>::new([0b0000_0001u8, 0b0000_1000]);
+    /// assert!(bitmap.get(0));
+    /// assert!(bitmap.get(11));
+    /// assert!(!bitmap.get(13));
+    /// // Out of bounds bits always returns false
+    /// assert!(!bitmap.get(128));
+    /// ```
+    pub fn get(&self, idx: usize) -> bool {
+        self.data.get_bit(idx)
+    }
+
+    /// Returns view of the bitmap as a sequence of `bits_count` bits.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1000u8, 0b0000_0001]);
+    /// let bits = bitmap.bits();
+    /// assert_eq!(bits.len(), 16);
+    /// assert_eq!(bits.at(3), bitmap.get(3));
+    /// assert_eq!(bits.range(8..16).iter().filter(|&b| b).count(), 1);
+    /// ```
+    pub fn bits(&self) -> BitSlice<'_, D, B> {
+        BitSlice::new(&self.data)
+    }
+
+    /// Returns iterator over states of every `step`-th bit up to `bits_count`:
+    /// bits `0`, `step`, `2 * step` and so on.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0001_0101u8]);
+    /// assert_eq!(bitmap.step_bits(2).collect::<Vec<_>>(), vec![true, true, true, false]);
+    /// assert_eq!(bitmap.step_bits(3).collect::<Vec<_>>(), vec![true, false, false]);
+    /// ```
+    pub fn step_bits(&self, step: usize) -> impl Iterator<Item = bool> + '_ {
+        assert!(step != 0, "step must be non-zero");
+        (0..self.data.bits_count())
+            .step_by(step)
+            .map(move |idx| self.data.get_bit(idx))
+    }
+
+    /// Gets single bit state, returns `default` if `idx` is out of bounds.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8, 0b0000_1000]);
+    /// assert!(bitmap.get_or(0, false));
+    /// assert!(!bitmap.get_or(13, true));
+    /// // Out of bounds bits return default
+    /// assert!(bitmap.get_or(128, true));
+    /// assert!(!bitmap.get_or(128, false));
+    /// ```
+    pub fn get_or(&self, idx: usize, default: bool) -> bool {
+        if idx < self.data.bits_count() {
+            self.data.get_bit(idx)
+        } else {
+            default
+        }
+    }
+
+    /// Returns iterator over slots.
+    pub fn iter(&self) -> Iter<'_, D, B> {
+        Iter::new(&self.data)
+    }
+
+    /// Returns iterator over slots (copies). It is the same as [`iter`].
+    ///
+    /// [`iter`]: crate::var_bitmap::VarBitmap::iter
+    pub fn slots(&self) -> Iter<'_, D, B> {
+        self.iter()
+    }
+
+    /// Converts bitmap into iterator over slots. It is the same as [`into_iter`].
+    ///
+    /// [`into_iter`]: std::iter::IntoIterator::into_iter
+    pub fn into_slots(self) -> IntoIter<D, B> {
+        IntoIter::new(self.data)
+    }
+
+    /// Returns iterator over references to slots. Unlike [`slots`], it doesn't copy them,
+    /// but requires container to be a contiguous slice.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// assert!(bitmap.slots_ref().eq(bitmap.slots().collect::<Vec<_>>().iter()));
+    /// ```
+    ///
+    /// [`slots`]: crate::var_bitmap::VarBitmap::slots
+    pub fn slots_ref<N>(&self) -> std::slice::Iter<'_, N>
+    where
+        D: AsRef<[N]>,
+    {
+        self.data.as_ref().iter()
+    }
+
+    /// Returns iterator over chunks of `chunk_len` slots, the last chunk may be shorter.
+    /// Requires container to be a contiguous slice, like [`slots_ref`].
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `chunk_len` is `0`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![1u8, 2, 3, 4, 5]);
+    /// let chunks = bitmap.slot_chunks(2).collect::<Vec<_>>();
+    /// assert_eq!(chunks, vec![&[1u8, 2][..], &[3, 4], &[5]]);
+    /// ```
+    ///
+    /// [`slots_ref`]: crate::var_bitmap::VarBitmap::slots_ref
+    pub fn slot_chunks<N>(&self, chunk_len: usize) -> std::slice::Chunks<'_, N>
+    where
+        D: AsRef<[N]>,
+    {
+        self.data.as_ref().chunks(chunk_len)
+    }
+
+    /// Returns static bitmap that borrows slots of the container as a slice.
+    /// Nothing is copied, the view shares data with the bitmap.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// let view = bitmap.as_slice_bitmap();
+    /// assert_eq!(view.ones().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    pub fn as_slice_bitmap<N>(&self) -> StaticBitmap<&[N], B>
+    where
+        D: AsRef<[N]>,
+        N: Number,
+    {
+        StaticBitmap::from(self.data.as_ref())
+    }
+
+    /// Returns static bitmap that mutably borrows slots of the container as a slice.
+    /// Changes made through the view are applied to the bitmap.
+    /// The view cannot grow, unlike the bitmap itself.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// bitmap.as_mut_slice_bitmap().set(1, true);
+    /// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![0, 1, 3, 9]);
+    /// ```
+    pub fn as_mut_slice_bitmap<N>(&mut self) -> StaticBitmap<&mut [N], B>
+    where
+        D: AsMut<[N]>,
+        N: Number,
+    {
+        StaticBitmap::from(self.data.as_mut())
+    }
+
+    /// Returns iterator over indexes of set bits in ascending order.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    pub fn ones(&self) -> Ones<'_, D, B> {
+        Ones::new(&self.data)
+    }
+
+    /// Returns iterator over maximal runs of cleared bits (gaps) in ascending order,
+    /// including the leading and the trailing gaps.
+    ///
+    /// The trailing gap ends at [`bits_count`], not at [`bit_len`], so free bits after
+    /// the last set bit are reported as well. Bits beyond the container are clear too,
+    /// but they are not reported.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0010_0100u8]);
+    /// assert_eq!(bitmap.gaps().collect::<Vec<_>>(), vec![0..2, 3..5, 6..8]);
+    /// ```
+    ///
+    /// [`bits_count`]: crate::container::ContainerRead::bits_count
+    /// [`bit_len`]: crate::var_bitmap::VarBitmap::bit_len
+    pub fn gaps(&self) -> Gaps<'_, D, B> {
+        Gaps::new(&self.data)
+    }
+
+    /// Returns iterator over numbers of set bits in every window of `width` bits.
+    ///
+    /// Yields a count for each window start `i` in `0..=bits_count() - width`, that is
+    /// the number of ones in bits `i..i + width`. Nothing is yielded if `width` exceeds
+    /// the bitmap. The count is updated incrementally, the whole pass takes `O(bits_count())`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0011_1001u8]);
+    /// assert_eq!(bitmap.sliding_popcount(3).collect::<Vec<_>>(), vec![1, 1, 2, 3, 2, 1]);
+    /// ```
+    pub fn sliding_popcount(&self, width: usize) -> SlidingPopcount<'_, D, B> {
+        SlidingPopcount::new(&self.data, width)
+    }
+
+    /// Returns iterator over indexes of set bits, treating the bitmap as a set of integers.
+    /// It is the same as [`ones`].
+    ///
+    /// Note that iterating over `&bitmap` yields slots, not indexes.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// let mut ids = Vec::new();
+    /// for id in bitmap.ids() {
+    ///     ids.push(id);
+    /// }
+    /// assert_eq!(ids, vec![0, 3, 9]);
+    /// ```
+    ///
+    /// [`ones`]: crate::var_bitmap::VarBitmap::ones
+    pub fn ids(&self) -> Ones<'_, D, B> {
+        self.ones()
+    }
+
+    /// Returns iterator over `(rank, index)` pairs of set bits: `rank` is the number of set bits
+    /// before `index`. It is the same as `ones().enumerate()`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b1000_1000u8]);
+    /// assert_eq!(bitmap.enumerate_ones().collect::<Vec<_>>(), vec![(0, 3), (1, 7)]);
+    /// ```
+    pub fn enumerate_ones(&self) -> Enumerate<Ones<'_, D, B>> {
+        self.ones().enumerate()
+    }
+
+    /// Returns iterator over `(index, state)` pairs of every bit up to `bits_count()`.
+    /// It is the same as `iter().by_bits().enumerate()`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0101u8]);
+    /// let bits = bitmap.indexed_bits().take(4).collect::<Vec<_>>();
+    /// assert_eq!(bits, vec![(0, true), (1, false), (2, true), (3, false)]);
+    /// ```
+    pub fn indexed_bits(&self) -> IndexedBits<'_, D, B> {
+        IndexedBits::new(&self.data)
+    }
+
+    /// Collects indexes of set bits into [`BTreeSet`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// assert_eq!(bitmap.to_btree_set().into_iter().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    pub fn to_btree_set(&self) -> BTreeSet<usize> {
+        self.ones().collect()
+    }
+
+    /// Returns formatter that prints raw bytes of every slot in specified byte order.
+    /// `Debug` implementation uses [`Endianness::Little`]. See [`DebugBytes`].
+    ///
+    /// [`Endianness::Little`]: crate::number::Endianness::Little
+    /// [`DebugBytes`]: crate::debug::DebugBytes
+    pub fn debug_bytes(&self, endianness: Endianness) -> DebugBytes<'_, D, B> {
+        DebugBytes::new(&self.data, endianness)
+    }
+
+    /// Returns formatter that prints bits in logical order (0th bit first).
+    ///
+    /// Unlike `Debug` implementation, which prints raw bytes of every slot,
+    /// it honors `BitAccess` and slot width. See [`DebugLogical`].
+    ///
+    /// [`DebugLogical`]: crate::debug::DebugLogical
+    pub fn debug_logical(&self) -> DebugLogical<'_, D, B> {
+        DebugLogical::new(&self.data)
+    }
+}
+
+impl<D, B, S, N> VarBitmap<D, B, S>
+where
+    D: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    N: Number,
+    S: GrowStrategy,
+    B: BitAccess,
+{
+    /// Sets new state for a single bit.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_set`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy, LimitStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, LimitStrategy<MinimumRequiredStrategy>>::new(
+    ///     vec![0u8; 1], LimitStrategy{ strategy: Default::default(), limit: 3 },
+    /// );
+    /// bitmap.set(6, true);
+    /// assert!(bitmap.get(6));
+    /// bitmap.set(13, true);
+    /// assert!(bitmap.get(13));
+    /// bitmap.set(13, false);
+    /// assert!(!bitmap.get(13));
+    /// // bitmap.set(128, false); <-- Panics
+    /// ```
+    ///
+    /// [`try_set`]: crate::var_bitmap::VarBitmap::try_set
+    pub fn set(&mut self, idx: usize, val: bool) {
+        self.try_set(idx, val).unwrap();
+    }
+
+    /// Sets new state for a single bit.
+    ///
+    /// Returns `Err(_)` if resizing fails.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy, LimitStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, LimitStrategy<MinimumRequiredStrategy>>::new(
+    ///     vec![0u8; 1], LimitStrategy{ strategy: Default::default(), limit: 3 },
+    /// );
+    /// assert!(bitmap.try_set(12, true).is_ok());
+    /// assert!(bitmap.get(12));
+    /// assert_eq!(bitmap.as_ref().len(), 2);
+    /// assert!(bitmap.try_set(12, false).is_ok());
+    /// assert!(!bitmap.get(12));
+    /// assert_eq!(bitmap.as_ref().len(), 2);
+    /// // Grow strategy returns error
+    /// assert!(bitmap.try_set(128, true).is_err());
+    /// assert!(!bitmap.get(128));
+    /// assert_eq!(bitmap.as_ref().len(), 2);
+    /// ```
+    pub fn try_set(&mut self, idx: usize, val: bool) -> Result<(), ResizeError> {
+        let max_idx = self.data.bits_count();
+        if idx < max_idx {
+            let shrink = self.auto_shrink && !val && self.data.get_bit(idx);
+            self.data.set_bit_unchecked(idx, val);
+            if shrink {
+                let slot_idx = idx / N::BITS_COUNT;
+                self.shrink_after_clear(slot_idx..slot_idx + 1);
+            }
+        } else {
+            // Call .try_resize() if new value is `1` or if strategy supports force resizing
+            if val || self.resizing_strategy.is_force_grow() {
+                self.try_grow_for(idx)?;
+                self.data.set_bit_unchecked(idx, val);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Consumes the bitmap and returns it with bit `idx` set.
+    ///
+    /// Returns `Err(_)` if resizing fails.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::default();
+    /// let bitmap = bitmap.try_with_bit(3).unwrap().try_with_bit(7).unwrap();
+    /// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![3, 7]);
+    /// ```
+    pub fn try_with_bit(mut self, idx: usize) -> Result<Self, ResizeError> {
+        self.try_set(idx, true)?;
+        Ok(self)
+    }
+
+    /// Consumes the bitmap and returns it with bit `idx` cleared.
+    ///
+    /// Returns `Err(_)` if resizing fails.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_slots([0xFFu8]);
+    /// let bitmap = bitmap.try_without_bit(3).unwrap();
+    /// assert!(!bitmap.get(3));
+    /// ```
+    pub fn try_without_bit(mut self, idx: usize) -> Result<Self, ResizeError> {
+        self.try_set(idx, false)?;
+        Ok(self)
+    }
+
+    /// Sets every bit with probability `density` and clears otherwise, using a tiny internal
+    /// pseudo-random generator. Container is never resized.
+    ///
+    /// The result depends only on `seed`, so it is useful for reproducible tests and benchmarks.
+    /// It is not suitable for anything that requires real randomness.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8; 4]);
+    /// bitmap.fill_pseudo_random(42, 1.0);
+    /// assert_eq!(bitmap.as_ref(), &vec![0xFF; 4]);
+    /// ```
+    pub fn fill_pseudo_random(&mut self, seed: u64, density: f64) {
+        fill_pseudo_random_impl(&mut self.data, seed, density);
+    }
+
+    /// Calls `f` with index and mutable reference of every slot, in order.
+    /// Useful for position dependent updates, e.g. masking the last partial slot.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFu8; 2]);
+    /// let last = bitmap.as_ref().len() - 1;
+    /// bitmap.for_each_slot_mut(|idx, slot| {
+    ///     if idx == last {
+    ///         *slot &= 0b0000_1111;
+    ///     }
+    /// });
+    /// assert_eq!(bitmap.as_ref(), &vec![0xFF, 0b0000_1111]);
+    /// ```
+    pub fn for_each_slot_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut N),
+    {
+        for idx in 0..self.data.slots_count() {
+            f(idx, self.data.get_mut_slot(idx));
+        }
+    }
+
+    /// Writes `bits[j]` to bit `start + j`. Container grows to fit the whole range.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_set_bits_from`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8]);
+    /// bitmap.set_bits_from(6, &[true, false, true]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0100_0000, 0b0000_0001]);
+    /// ```
+    ///
+    /// [`try_set_bits_from`]: crate::var_bitmap::VarBitmap::try_set_bits_from
+    pub fn set_bits_from(&mut self, start: usize, bits: &[bool]) {
+        self.try_set_bits_from(start, bits).unwrap();
+    }
+
+    /// Writes `bits[j]` to bit `start + j`. Container grows to fit the whole range.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    pub fn try_set_bits_from(&mut self, start: usize, bits: &[bool]) -> Result<(), ResizeError> {
+        if bits.is_empty() {
+            return Ok(());
+        }
+        let last_idx = start
+            .checked_add(bits.len() - 1)
+            .ok_or_else(|| ResizeError::new(format!("index too large: {}", start)))?;
+        if last_idx >= self.data.bits_count() {
+            self.try_grow_for(last_idx)?;
+        }
+        set_bits_from_impl(&mut self.data, start, bits);
+        Ok(())
+    }
+
+    /// Sets every bit in `range` to `val`. Slots are updated as a whole, not bit by bit.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_set_range`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8]);
+    /// bitmap.set_range(4..20, true);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b1111_0000, 0b1111_1111, 0b0000_1111]);
+    /// bitmap.set_range(2..100, false);
+    /// assert_eq!(bitmap.as_ref(), &vec![0, 0, 0]);
+    /// ```
+    ///
+    /// [`try_set_range`]: crate::var_bitmap::VarBitmap::try_set_range
+    pub fn set_range(&mut self, range: Range<usize>, val: bool) {
+        self.try_set_range(range, val).unwrap();
+    }
+
+    /// Sets every bit in `range` to `val`. Slots are updated as a whole, not bit by bit.
+    ///
+    /// If the range exceeds the container, the strategy is asked once for the length
+    /// required by `range.end`, so the container is resized at most once.
+    /// As with [`try_set`], clearing bits out of bounds doesn't grow the container
+    /// unless the strategy forces it.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    ///
+    /// [`try_set`]: crate::var_bitmap::VarBitmap::try_set
+    pub fn try_set_range(&mut self, range: Range<usize>, val: bool) -> Result<(), ResizeError> {
+        let mut range = range;
+        let bits_count = self.data.bits_count();
+        if range.start < range.end && range.end > bits_count {
+            if val || self.resizing_strategy.is_force_grow() {
+                self.try_grow_for(range.end - 1)?;
+            } else {
+                range.end = bits_count;
+            }
+        }
+        if !val && range.start < range.end {
+            let slots = range.start / N::BITS_COUNT..(range.end - 1) / N::BITS_COUNT + 1;
+            set_range_impl(&mut self.data, range, val);
+            self.shrink_after_clear(slots);
+        } else {
+            set_range_impl(&mut self.data, range, val);
+        }
+        Ok(())
+    }
+
+    /// Writes `value` to every slot in `range` of slot indexes.
+    ///
+    /// Unlike [`set_range`], the range addresses slots, not bits, so it is useful to fill
+    /// large regions with a whole-slot pattern.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_fill_slots`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8]);
+    /// bitmap.fill_slots(1..3, 0xFF);
+    /// assert_eq!(bitmap.as_ref(), &vec![0x00, 0xFF, 0xFF]);
+    /// ```
+    ///
+    /// [`set_range`]: crate::var_bitmap::VarBitmap::set_range
+    /// [`try_fill_slots`]: crate::var_bitmap::VarBitmap::try_fill_slots
+    pub fn fill_slots(&mut self, range: Range<usize>, value: N) {
+        self.try_fill_slots(range, value).unwrap();
+    }
+
+    /// Writes `value` to every slot in `range` of slot indexes.
+    ///
+    /// If the range exceeds the container, it grows to hold at least `range.end` slots,
+    /// the container is resized at most once. As with [`try_set_range`], filling with zeros
+    /// out of bounds doesn't grow the container unless the strategy forces it.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    ///
+    /// [`try_set_range`]: crate::var_bitmap::VarBitmap::try_set_range
+    pub fn try_fill_slots(&mut self, range: Range<usize>, value: N) -> Result<(), ResizeError> {
+        let mut range = range;
+        let slots_count = self.data.slots_count();
+        if range.start < range.end && range.end > slots_count {
+            if value != N::ZERO || self.resizing_strategy.is_force_grow() {
+                let last_bit = range.end.checked_mul(N::BITS_COUNT).ok_or_else(|| {
+                    ResizeError::new(format!("slot index too large: {}", range.end))
+                })? - 1;
+                self.try_grow_for(last_bit)?;
+            } else {
+                range.end = slots_count;
+            }
+        }
+        for i in range {
+            *self.data.get_mut_slot(i) = value;
+        }
+        Ok(())
+    }
+
+    /// Sets bits at every index of `indices`. Container grows by the strategy at most once,
+    /// to fit the last index, then bits are set without growth checks.
+    ///
+    /// `indices` must be sorted in ascending order, duplicates are allowed.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    ///
+    /// ## Panic
+    ///
+    /// Panics in debug builds if `indices` are not sorted. In release builds unsorted `indices`
+    /// panic only if some index doesn't fit the container sized by the last one.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::default();
+    /// bitmap.set_all_sorted(&[0, 3, 9]).unwrap();
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+    /// ```
+    pub fn set_all_sorted(&mut self, indices: &[usize]) -> Result<(), ResizeError> {
+        debug_assert!(
+            indices.windows(2).all(|w| w[0] <= w[1]),
+            "indices are not sorted"
+        );
+
+        let max_idx = match indices.last() {
+            Some(&idx) => idx,
+            None => return Ok(()),
+        };
+        if max_idx >= self.data.bits_count() {
+            self.try_grow_for(max_idx)?;
+        }
+        for &idx in indices {
+            let slot = self.data.get_mut_slot(idx / N::BITS_COUNT);
+            *slot = B::set(*slot, idx % N::BITS_COUNT, true);
+        }
+        Ok(())
+    }
+
+    /// Sets new state for a single bit like [`try_set`], and reports what happened.
+    ///
+    /// Returns `Err(_)` if resizing fails.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8]);
+    /// let outcome = bitmap.set_reporting(12, true).unwrap();
+    /// assert!(outcome.grew);
+    /// assert!(outcome.changed_bit);
+    /// assert_eq!((outcome.old_len, outcome.new_len), (1, 2));
+    /// let outcome = bitmap.set_reporting(12, true).unwrap();
+    /// assert!(!outcome.grew);
+    /// assert!(!outcome.changed_bit);
+    /// ```
+    ///
+    /// [`try_set`]: crate::var_bitmap::VarBitmap::try_set
+    pub fn set_reporting(&mut self, idx: usize, val: bool) -> Result<SetOutcome, ResizeError> {
+        let old_len = self.data.slots_count();
+        let old_val = self.data.get_bit(idx);
+        self.try_set(idx, val)?;
+        let new_len = self.data.slots_count();
+
+        Ok(SetOutcome {
+            grew: new_len > old_len,
+            old_len,
+            new_len,
+            changed_bit: old_val != val,
+        })
+    }
+
+    /// Sets a single bit to `1` only if it is `0` now. Returns `true` if the bit has changed.
+    /// The bit is not written at all if it is already set.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_set_if_clear`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8]);
+    /// assert!(!bitmap.set_if_clear(0));
+    /// assert!(bitmap.set_if_clear(9));
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001, 0b0000_0010]);
+    /// ```
+    ///
+    /// [`try_set_if_clear`]: crate::var_bitmap::VarBitmap::try_set_if_clear
+    pub fn set_if_clear(&mut self, idx: usize) -> bool {
+        self.try_set_if_clear(idx).unwrap()
+    }
+
+    /// Sets a single bit to `1` only if it is `0` now. Returns `Ok(true)` if the bit has changed.
+    /// The bit is not written at all if it is already set.
+    ///
+    /// Returns `Err(_)` if resizing fails.
+    pub fn try_set_if_clear(&mut self, idx: usize) -> Result<bool, ResizeError> {
+        if self.data.get_bit(idx) {
+            return Ok(false);
+        }
+        self.try_set(idx, true)?;
+        Ok(true)
+    }
+
+    /// Sets new state for a single bit like [`try_set`]. Returns `Ok(true)` if the stored bit
+    /// differed from `val` and was updated, `Ok(false)` if it already was `val`.
+    /// The bit is not written at all if it is unchanged, so setting an out of bounds bit
+    /// to `0` returns `Ok(false)` and never grows the container.
+    ///
+    /// Returns `Err(_)` if resizing fails.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8]);
+    /// assert_eq!(bitmap.set_changed(0, true).unwrap(), false);
+    /// assert_eq!(bitmap.set_changed(20, false).unwrap(), false);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001]);
+    /// assert_eq!(bitmap.set_changed(9, true).unwrap(), true);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001, 0b0000_0010]);
+    /// ```
+    ///
+    /// [`try_set`]: crate::var_bitmap::VarBitmap::try_set
+    pub fn set_changed(&mut self, idx: usize, val: bool) -> Result<bool, ResizeError> {
+        if self.data.get_bit(idx) == val {
+            return Ok(false);
+        }
+        self.try_set(idx, val)?;
+        Ok(true)
+    }
+
+    /// Sets a single bit to `0` only if it is `1` now. Returns `true` if the bit has changed.
+    /// The bit is not written at all if it is already cleared. Container never grows.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8]);
+    /// assert!(!bitmap.clear_if_set(1));
+    /// assert!(bitmap.clear_if_set(0));
+    /// assert!(!bitmap.clear_if_set(128));
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0000]);
+    /// ```
+    pub fn clear_if_set(&mut self, idx: usize) -> bool {
+        if !self.data.get_bit(idx) {
+            return false;
+        }
+        self.data.set_bit_unchecked(idx, false);
+        let slot_idx = idx / N::BITS_COUNT;
+        self.shrink_after_clear(slot_idx..slot_idx + 1);
+        true
+    }
+
+    /// Removes a single bit and shifts all higher bits down by one, like [`Vec::remove`].
+    /// Returns state of removed bit. Container never grows.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1101u8]);
+    /// assert!(bitmap.remove_bit(2));
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0101]);
+    /// // Out of bounds bits are always `0`
+    /// assert!(!bitmap.remove_bit(128));
+    /// ```
+    pub fn remove_bit(&mut self, idx: usize) -> bool {
+        if idx >= self.data.bits_count() {
+            return false;
+        }
+        let removed = remove_bit_impl(&mut self.data, idx);
+        self.shrink_after_clear(idx / N::BITS_COUNT..self.data.slots_count());
+        removed
+    }
+
+    /// Inserts a single bit and shifts all bits starting from `idx` up by one, like [`Vec::insert`].
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_insert_bit`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b1000_0101u8]);
+    /// bitmap.insert_bit(2, true);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1101, 0b0000_0001]);
+    /// ```
+    ///
+    /// [`try_insert_bit`]: crate::var_bitmap::VarBitmap::try_insert_bit
+    pub fn insert_bit(&mut self, idx: usize, val: bool) {
+        self.try_insert_bit(idx, val).unwrap();
+    }
+
+    /// Inserts a single bit and shifts all bits starting from `idx` up by one, like [`Vec::insert`].
+    /// Container grows if the last bit is shifted out of it.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    pub fn try_insert_bit(&mut self, idx: usize, val: bool) -> Result<(), ResizeError> {
+        let max_idx = self.data.bits_count();
+        if idx >= max_idx {
+            return self.try_set(idx, val);
+        }
+
+        if self.data.get_bit(max_idx - 1) {
+            self.try_grow_for(max_idx)?;
+        }
+        insert_bit_impl(&mut self.data, idx, val);
+        Ok(())
+    }
+
+    /// Clears all bits and resizes container to the minimum number of slots that fits `new_bit_len` bits.
+    ///
+    /// Container is resized in-place, so its allocation is reused if it is possible.
+    /// Resizing strategy is not involved.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+    /// bitmap.reset(20);
+    /// assert_eq!(bitmap.as_ref(), &vec![0, 0, 0]);
+    /// bitmap.set(3, true);
+    /// bitmap.reset(4);
+    /// assert_eq!(bitmap.as_ref(), &vec![0]);
+    /// ```
+    pub fn reset(&mut self, new_bit_len: usize) {
+        let old_len = self.data.slots_count();
+        let new_len = new_bit_len / N::BITS_COUNT + (new_bit_len % N::BITS_COUNT != 0) as usize;
+
+        for i in 0..usize::min(old_len, new_len) {
+            *self.data.get_mut_slot(i) = N::ZERO;
+        }
+        if new_len != old_len {
+            self.data.resize(new_len, N::ZERO);
+        }
+    }
+
+    /// Shortens container to `len` slots, bits of removed slots are dropped.
+    /// Does nothing if `len` is greater or equal to the current number of slots.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8, 0b0000_0001]);
+    /// bitmap.truncate_slots(1);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001]);
+    /// assert!(!bitmap.get(8));
+    /// ```
+    pub fn truncate_slots(&mut self, len: usize) {
+        if len < self.data.slots_count() {
+            self.data.resize(len, N::ZERO);
+        }
+    }
+
+    /// Inserts slot `value` at position `at`, shifting all slots after it up by one.
+    /// So every bit with index `>= at * BITS_COUNT` moves up by `BITS_COUNT`.
+    /// Resizing strategy is not used, container grows by exactly one slot.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `at` is greater than the number of slots.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8, 0b0000_0010]);
+    /// bitmap.insert_slot(1, 0b1000_0000);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001, 0b1000_0000, 0b0000_0010]);
+    /// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![0, 15, 17]);
+    /// ```
+    pub fn insert_slot(&mut self, at: usize, value: N) {
+        let len = self.data.slots_count();
+        assert!(at <= len, "insertion index {} is out of 0..={}", at, len);
+
+        self.data.resize(len + 1, N::ZERO);
+        for i in (at..len).rev() {
+            *self.data.get_mut_slot(i + 1) = self.data.get_slot(i);
+        }
+        *self.data.get_mut_slot(at) = value;
+    }
+
+    /// Removes slot at position `at` and returns it, shifting all slots after it down by one.
+    /// So every bit with index `>= (at + 1) * BITS_COUNT` moves down by `BITS_COUNT`.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if `at` is out of bounds.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8, 0b1000_0000, 0b0000_0010]);
+    /// assert_eq!(bitmap.remove_slot(1), 0b1000_0000);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001, 0b0000_0010]);
+    /// ```
+    pub fn remove_slot(&mut self, at: usize) -> N {
+        let len = self.data.slots_count();
+        assert!(at < len, "removal index {} is out of 0..{}", at, len);
+
+        let value = self.data.get_slot(at);
+        for i in at + 1..len {
+            *self.data.get_mut_slot(i - 1) = self.data.get_slot(i);
+        }
+        self.data.resize(len - 1, N::ZERO);
+        value
+    }
+
+    /// Tries to reserve capacity of container for at least `additional_bits` more bits
+    /// (rounded up to whole slots). Length of container and resizing strategy are not affected.
+    ///
+    /// Returns `Err(_)` if allocation fails, see [`Resizable::try_reserve`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8]);
+    /// assert!(bitmap.try_reserve_bits(20).is_ok());
+    /// assert!(bitmap.as_ref().capacity() >= 4);
+    /// assert!(bitmap.try_reserve_bits(usize::MAX).is_err());
+    /// ```
+    ///
+    /// [`Resizable::try_reserve`]: crate::resizable::Resizable::try_reserve
+    pub fn try_reserve_bits(&mut self, additional_bits: usize) -> Result<(), ResizeError> {
+        let additional =
+            additional_bits / N::BITS_COUNT + (additional_bits % N::BITS_COUNT != 0) as usize;
+        self.data.try_reserve(additional)
+    }
+
+    /// Updates the bitmap with union of itself and `rhs` (`self | rhs`).
+    /// Container grows to absorb the ones of `rhs` that exceed the bitmap.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_union_update`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+    /// bitmap.union_update(&[0b0000_0110u8, 0b0000_0001]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1111, 0b0000_0001]);
+    /// ```
+    ///
+    /// [`try_union_update`]: crate::var_bitmap::VarBitmap::try_union_update
+    pub fn union_update<Rhs>(&mut self, rhs: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        self.try_union_update(rhs).unwrap();
+    }
+
+    /// Updates the bitmap with union of itself and `rhs` (`self | rhs`).
+    /// Container grows to absorb the ones of `rhs` that exceed the bitmap.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    pub fn try_union_update<Rhs>(&mut self, rhs: &Rhs) -> Result<(), ResizeError>
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        self.try_grow_for_tail(rhs)?;
+        union_update_impl(&mut self.data, rhs);
+        Ok(())
+    }
+
+    /// Updates the bitmap with union of itself and every source, one after another.
+    /// Container grows once, to the length of the longest source.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_union_in_many`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8]);
+    /// bitmap.union_in_many(&[vec![0b0000_0010u8], vec![0, 0b0000_0001], vec![0b0000_1000, 0, 0]]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1011, 0b0000_0001, 0]);
+    /// ```
+    ///
+    /// [`try_union_in_many`]: crate::var_bitmap::VarBitmap::try_union_in_many
+    pub fn union_in_many<'a, I, Rhs>(&mut self, sources: I)
+    where
+        I: IntoIterator<Item = &'a Rhs>,
+        Rhs: ContainerRead<B, Slot = N> + 'a,
+    {
+        self.try_union_in_many(sources).unwrap();
+    }
+
+    /// Updates the bitmap with union of itself and every source, one after another.
+    /// Container grows once, to the length of the longest source.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    pub fn try_union_in_many<'a, I, Rhs>(&mut self, sources: I) -> Result<(), ResizeError>
+    where
+        I: IntoIterator<Item = &'a Rhs>,
+        Rhs: ContainerRead<B, Slot = N> + 'a,
+    {
+        let mut max_len = 0;
+        let sources = sources
+            .into_iter()
+            .inspect(|rhs| max_len = usize::max(max_len, rhs.slots_count()))
+            .collect::<Vec<_>>();
+        if max_len > self.data.slots_count() {
+            self.try_grow_for(last_bit_idx::<N>(max_len)?)?;
+        }
+
+        for rhs in sources {
+            union_update_impl(&mut self.data, rhs);
+        }
+        Ok(())
+    }
+
+    /// Overlays `other` onto the bitmap starting at bit `0`, i.e. sets every bit that is set
+    /// in `other`. Container grows to the length of `other` if it is longer.
+    ///
+    /// It doesn't concatenate bitmaps: bits of `other` keep their indexes. Unlike [`union_update`],
+    /// container grows to the full length of `other` even if its tail slots are zeros.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_extend_from`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+    /// bitmap.extend_from(&[0b0000_0010u8, 0b0000_0001, 0]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1011, 0b0000_0001, 0]);
+    /// ```
+    ///
+    /// [`union_update`]: crate::var_bitmap::VarBitmap::union_update
+    /// [`try_extend_from`]: crate::var_bitmap::VarBitmap::try_extend_from
+    pub fn extend_from<Rhs>(&mut self, other: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        self.try_extend_from(other).unwrap();
+    }
+
+    /// Overlays `other` onto the bitmap starting at bit `0`, i.e. sets every bit that is set
+    /// in `other`. Container grows to the length of `other` if it is longer.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    pub fn try_extend_from<Rhs>(&mut self, other: &Rhs) -> Result<(), ResizeError>
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        let other_len = other.slots_count();
+        if other_len > self.data.slots_count() {
+            self.try_grow_for(last_bit_idx::<N>(other_len)?)?;
+        }
+        union_update_impl(&mut self.data, other);
+        Ok(())
+    }
+
+    /// Updates the bitmap with intersection of itself and `rhs` (`self & rhs`).
+    /// Bits that exceed `rhs` are cleared. Container never grows.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0001]);
+    /// bitmap.intersect_update(&[0b0000_0011u8]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_0001, 0b0000_0000]);
+    /// ```
+    pub fn intersect_update<Rhs>(&mut self, rhs: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        intersect_update_impl(&mut self.data, rhs);
+        self.shrink_after_clear(0..self.data.slots_count());
+    }
+
+    /// Returns intersection of the bitmap and `rhs` (`self & rhs`), consuming `rhs`.
+    ///
+    /// The result is written into `rhs` container and keeps resizing strategy of the bitmap,
+    /// so no new container is allocated. The result has as many slots as `rhs`,
+    /// bits of `rhs` that exceed the bitmap are cleared.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+    /// let rhs = vec![0b0000_0011u8, 0b0000_0001];
+    /// let res = bitmap.intersection_consuming(rhs);
+    /// assert_eq!(res.as_ref(), &vec![0b0000_0001, 0b0000_0000]);
+    /// ```
+    pub fn intersection_consuming(self, rhs: D) -> VarBitmap<D, B, S> {
+        let mut res = VarBitmap::new(rhs, self.resizing_strategy);
+        res.intersect_update(&self.data);
+        res
+    }
+
+    /// Removes all bits of `rhs` from the bitmap (`self & !rhs`). Container never grows.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0001]);
+    /// bitmap.difference_update(&[0b0000_0011u8]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1000, 0b0000_0001]);
+    /// ```
+    pub fn difference_update<Rhs>(&mut self, rhs: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        difference_update_impl(&mut self.data, rhs);
+        self.shrink_after_clear(0..rhs.slots_count());
+    }
+
+    /// Alias for [`difference_update`], clears every bit that is set in `mask`.
+    ///
+    /// [`difference_update`]: crate::var_bitmap::VarBitmap::difference_update
+    pub fn clear_where<M>(&mut self, mask: &M)
+    where
+        M: ContainerRead<B, Slot = N>,
+    {
+        self.difference_update(mask);
+    }
+
+    /// Flips every bit of every slot, padding bits of the last slot are set too.
+    /// See [`invert_to_len`] to flip only a prefix of bits.
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0000]);
+    /// bitmap.invert();
+    /// assert_eq!(bitmap.as_ref(), &vec![0b1111_0110, 0b1111_1111]);
+    /// ```
+    ///
+    /// [`invert_to_len`]: crate::var_bitmap::VarBitmap::invert_to_len
+    pub fn invert(&mut self) {
+        invert_impl(&mut self.data);
+    }
+
+    /// Flips bits `0..bit_len`, the rest of bits are cleared.
+    ///
+    /// Unlike [`invert`], padding bits past `bit_len` stay zero, so it is a complement
+    /// within a length. If `bit_len` exceeds the container, it grows to hold `bit_len` bits.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_invert_to_len`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+    /// bitmap.invert_to_len(10);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b1111_0110, 0b0000_0011]);
+    /// ```
+    ///
+    /// [`invert`]: crate::var_bitmap::VarBitmap::invert
+    /// [`try_invert_to_len`]: crate::var_bitmap::VarBitmap::try_invert_to_len
+    pub fn invert_to_len(&mut self, bit_len: usize) {
+        self.try_invert_to_len(bit_len).unwrap();
+    }
+
+    /// Flips bits `0..bit_len`, the rest of bits are cleared.
+    ///
+    /// If `bit_len` exceeds the container, it grows to hold `bit_len` bits.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    pub fn try_invert_to_len(&mut self, bit_len: usize) -> Result<(), ResizeError> {
+        if bit_len > self.data.bits_count() {
+            self.try_grow_for(bit_len - 1)?;
+        }
+        invert_to_len_impl(&mut self.data, bit_len);
+        Ok(())
+    }
+
+    /// Updates the bitmap with symmetric difference of itself and `rhs` (`self ^ rhs`).
+    /// Container grows to absorb the ones of `rhs` that exceed the bitmap.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if resizing fails.
+    /// See non-panic function [`try_symmetric_difference_update`].
+    ///
+    /// ## Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+    /// bitmap.symmetric_difference_update(&[0b0000_0011u8, 0b0000_0001]);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1010, 0b0000_0001]);
+    /// ```
+    ///
+    /// [`try_symmetric_difference_update`]: crate::var_bitmap::VarBitmap::try_symmetric_difference_update
+    pub fn symmetric_difference_update<Rhs>(&mut self, rhs: &Rhs)
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        self.try_symmetric_difference_update(rhs).unwrap();
+    }
+
+    /// Updates the bitmap with symmetric difference of itself and `rhs` (`self ^ rhs`).
+    /// Container grows to absorb the ones of `rhs` that exceed the bitmap.
+    ///
+    /// Returns `Err(_)` if resizing fails, bitmap stays unchanged in this case.
+    pub fn try_symmetric_difference_update<Rhs>(&mut self, rhs: &Rhs) -> Result<(), ResizeError>
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        self.try_grow_for_tail(rhs)?;
+        symmetric_difference_update_impl(&mut self.data, rhs);
+        Ok(())
+    }
+
+    /// Grows container by strategy so that it can store all ones of `rhs`.
+    fn try_grow_for_tail<Rhs>(&mut self, rhs: &Rhs) -> Result<(), ResizeError>
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        match last_tail_slot(rhs, self.data.slots_count()) {
+            Some(slot_idx) => self.try_grow_for(last_bit_idx::<N>(slot_idx + 1)?),
+            None => Ok(()),
+        }
+    }
+
+    /// Truncates trailing zero slots after bits of `slots` were cleared, if auto-shrinking
+    /// is enabled and the last set bit lies in the lower half of the container.
+    fn shrink_after_clear(&mut self, slots: Range<usize>) {
+        if !self.auto_shrink {
+            return;
+        }
+        let old_len = self.data.slots_count();
+        let half = old_len / 2;
+        let start = usize::max(slots.start, half);
+        let end = usize::min(slots.end, old_len);
+        // Clearing the lower half doesn't change whether the upper half is empty
+        if start >= end || (start..end).any(|i| self.data.get_slot(i) != N::ZERO) {
+            return;
+        }
+
+        // Walk away from cleared slots in both directions: bits are usually cleared in order,
+        // so the nearest slots are the most likely to be non-zero
+        let (mut lo, mut hi) = (start, end);
+        while lo > half || hi < old_len {
+            if lo > half {
+                lo -= 1;
+                if self.data.get_slot(lo) != N::ZERO {
+                    return;
+                }
+            }
+            if hi < old_len {
+                if self.data.get_slot(hi) != N::ZERO {
+                    return;
+                }
+                hi += 1;
+            }
+        }
+
+        let new_len = (0..half)
+            .rev()
+            .find(|&i| self.data.get_slot(i) != N::ZERO)
+            .map_or(0, |i| i + 1);
+        self.data.resize(new_len, N::ZERO);
+    }
+
+    /// Grows container by strategy so that it can store bit `idx`.
+    fn try_grow_for(&mut self, idx: usize) -> Result<(), ResizeError> {
+        let () = N::ASSERT_NON_ZERO_WIDTH;
+        let max_idx = self.data.bits_count();
+        let old_len = self.data.slots_count();
+        let min_req_len = old_len
+            .checked_add((idx - max_idx) / N::BITS_COUNT)
+            .and_then(|v| v.checked_add(1))
+            .ok_or_else(|| ResizeError::new(format!("index too large: {}", idx)))?;
+        let min_req_len = MinimumRequiredLength(min_req_len);
+
+        // Minimal strategies would return the required length anyway
+        let FinalLength(new_len) = if self.resizing_strategy.is_minimal() {
+            min_req_len.finalize()
+        } else {
+            self.resizing_strategy.try_grow(min_req_len, old_len, idx)?
+        };
+
+        // Resize container if new length doesn't match old length.
+        // Reserve fallibly first, so that huge indexes return error instead of aborting
+        if new_len != old_len {
+            self.data.try_reserve(new_len.saturating_sub(old_len))?;
+            self.data.resize(new_len, N::ZERO);
+        }
+        Ok(())
+    }
+}
+
+/// Returns index of the last bit of container with `slots_count` slots.
+fn last_bit_idx<N>(slots_count: usize) -> Result<usize, ResizeError>
+where
+    N: Number,
+{
+    slots_count
+        .checked_mul(N::BITS_COUNT)
+        .map(|bits_count| bits_count - 1)
+        .ok_or_else(|| ResizeError::new(format!("slots count too large: {}", slots_count)))
+}
+
+/// Returns the bitmap with bit set, same as [`try_with_bit`] but panics on error.
+///
+/// ## Panic
+///
+/// Panics if resizing fails.
+/// See non-panic function [`try_with_bit`].
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+///
+/// let bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::default() | 3 | 7;
+/// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![3, 7]);
+/// ```
+///
+/// [`try_with_bit`]: crate::var_bitmap::VarBitmap::try_with_bit
+impl<D, N, B, S> BitOr<usize> for VarBitmap<D, B, S>
+where
+    D: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    N: Number,
+    S: GrowStrategy,
+    B: BitAccess,
+{
+    type Output = Self;
+
+    fn bitor(self, idx: usize) -> Self::Output {
+        self.try_with_bit(idx).unwrap()
+    }
+}
+
+/// Returns the bitmap with bit cleared, same as [`try_without_bit`] but panics on error.
+///
+/// ## Panic
+///
+/// Panics if resizing fails.
+/// See non-panic function [`try_without_bit`].
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+///
+/// let bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::default() | 3 | 7;
+/// assert_eq!((bitmap - 3).ones().collect::<Vec<_>>(), vec![7]);
+/// ```
+///
+/// [`try_without_bit`]: crate::var_bitmap::VarBitmap::try_without_bit
+impl<D, N, B, S> Sub<usize> for VarBitmap<D, B, S>
+where
+    D: ContainerWrite<B, Slot = N> + Resizable<Slot = N>,
+    N: Number,
+    S: GrowStrategy,
+    B: BitAccess,
+{
+    type Output = Self;
+
+    fn sub(self, idx: usize) -> Self::Output {
+        self.try_without_bit(idx).unwrap()
+    }
+}
+
+/// Updates the bitmap with intersection of itself and `rhs`, same as [`intersect_update`].
+///
+/// Slots of the bitmap that exceed `rhs` are intersected with implicit zeros, so they are cleared.
+/// Slots of `rhs` that exceed the bitmap are ignored. Container is never resized.
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+///
+/// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0001]);
+/// bitmap &= &[0b0000_0011u8];
+/// assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![0]);
+/// ```
+///
+/// [`intersect_update`]: crate::var_bitmap::VarBitmap::intersect_update
+impl<D, N, B, S, Rhs> BitAndAssign<&Rhs> for VarBitmap<D, B, S>
+where
+    D: ContainerWrite<B, Slot = N>,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    fn bitand_assign(&mut self, rhs: &Rhs) {
+        intersect_update_impl(&mut self.data, rhs);
+    }
+}
+
+impl<D, N, B, S> From<D> for VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+    S: Default,
+{
+    fn from(f: D) -> Self {
+        Self {
+            data: f,
+            resizing_strategy: Default::default(),
+            auto_shrink: false,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl From<&BTreeSet<usize>> for VarBitmap<Vec<u8>, LSB, MinimumRequiredStrategy> {
+    /// Creates bitmap with bits set at every index of the set.
+    ///
+    /// Usage example:
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let set = BTreeSet::from_iter([0, 3, 9]);
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from(&set);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+    /// assert_eq!(bitmap.to_btree_set(), set);
+    /// ```
+    fn from(f: &BTreeSet<usize>) -> Self {
+        from_indexes(f.iter().copied(), f.iter().next_back().copied())
+    }
+}
+
+impl<H> From<&HashSet<usize, H>> for VarBitmap<Vec<u8>, LSB, MinimumRequiredStrategy> {
+    /// Creates bitmap with bits set at every index of the set.
+    ///
+    /// Usage example:
+    /// ```
+    /// use std::collections::HashSet;
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let set = HashSet::<_>::from_iter([0, 3, 9]);
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from(&set);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+    /// ```
+    fn from(f: &HashSet<usize, H>) -> Self {
+        from_indexes(f.iter().copied(), f.iter().max().copied())
+    }
+}
+
+#[cfg(feature = "roaring")]
+impl VarBitmap<Vec<u8>, LSB, MinimumRequiredStrategy> {
+    /// Creates bitmap with bits set at every value of [`RoaringBitmap`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let r = roaring::RoaringBitmap::from_iter([0, 3, 9]);
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_roaring(&r);
+    /// assert_eq!(bitmap.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+    /// ```
+    ///
+    /// [`RoaringBitmap`]: roaring::RoaringBitmap
+    pub fn from_roaring(r: &roaring::RoaringBitmap) -> Self {
+        from_indexes(r.iter().map(|v| v as usize), r.max().map(|v| v as usize))
+    }
+}
+
+#[cfg(feature = "roaring")]
+impl From<&roaring::RoaringBitmap> for VarBitmap<Vec<u8>, LSB, MinimumRequiredStrategy> {
+    /// Creates bitmap with bits set at every value of the roaring bitmap,
+    /// see [`from_roaring`](VarBitmap::from_roaring).
+    fn from(f: &roaring::RoaringBitmap) -> Self {
+        Self::from_roaring(f)
+    }
+}
+
+/// Creates bitmap that fits `max_idx` with bits set at every index of `indexes`.
+/// Container is allocated once, every index must be less or equal to `max_idx`.
+fn from_indexes<I, N, B, S>(indexes: I, max_idx: Option<usize>) -> VarBitmap<Vec<N>, B, S>
+where
+    I: Iterator<Item = usize>,
+    N: Number,
+    B: BitAccess,
+    S: GrowStrategy + Default,
+{
+    let slots_count = max_idx.map_or(0, |idx| idx / N::BITS_COUNT + 1);
+    let mut data = vec![N::ZERO; slots_count];
+    for idx in indexes {
+        ContainerWrite::<B>::set_bit_unchecked(&mut data, idx, true);
+    }
+    VarBitmap::from_container(data)
+}
+
+#[cfg(feature = "roaring")]
+impl<D, N, B, S> VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    /// Collects indexes of set bits into [`RoaringBitmap`].
+    ///
+    /// ## Panic
+    ///
+    /// Panics if index of any set bit doesn't fit `u32`.
+    /// See non-panic function [`try_to_roaring`].
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// assert_eq!(bitmap.to_roaring().iter().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    ///
+    /// [`RoaringBitmap`]: roaring::RoaringBitmap
+    /// [`try_to_roaring`]: crate::var_bitmap::VarBitmap::try_to_roaring
+    pub fn to_roaring(&self) -> roaring::RoaringBitmap {
+        self.try_to_roaring().unwrap()
+    }
+
+    /// Collects indexes of set bits into [`RoaringBitmap`].
+    ///
+    /// Returns `Err(_)` if index of any set bit doesn't fit `u32`.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8, 0b0000_0010]);
+    /// assert_eq!(bitmap.try_to_roaring().unwrap().iter().collect::<Vec<_>>(), vec![0, 3, 9]);
+    /// ```
+    ///
+    /// [`RoaringBitmap`]: roaring::RoaringBitmap
+    pub fn try_to_roaring(&self) -> Result<roaring::RoaringBitmap, OutOfBoundsError> {
+        let bit_len = bit_len_impl(&self.data);
+        if bit_len > 0 && u32::try_from(bit_len - 1).is_err() {
+            let bounds = 0..(u32::MAX as usize).saturating_add(1);
+            return Err(OutOfBoundsError::new(bit_len - 1, bounds, 0));
+        }
+
+        let mut res = roaring::RoaringBitmap::new();
+        for idx in self.ones() {
+            // Indexes are sorted, so every index is appended to the end
+            res.push(idx as u32);
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<D, N, B, S> VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N> + AsRef<[N]> + Sync,
+    N: Number + Sync,
+    B: BitAccess,
+{
+    /// Returns number of ones in the bitmap, slots are split between threads of `rayon` pool.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1111u8; 10_000]);
+    /// assert_eq!(bitmap.par_count_ones(), bitmap.count_ones());
+    /// ```
+    pub fn par_count_ones(&self) -> usize {
+        par_count_ones_impl(self.data.as_ref())
+    }
+
+    /// Calculates intersection length, slots are split between threads of `rayon` pool.
+    ///
+    /// Falls back to sequential calculation if `other` is not a contiguous slice of slots.
+    ///
+    /// Usage example:
+    /// ```
+    /// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+    ///
+    /// let bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1111u8; 10_000]);
+    /// assert_eq!(bitmap.par_intersection_len(&vec![0b0011_1100u8; 20_000]), 20_000);
+    /// ```
+    pub fn par_intersection_len<Rhs>(&self, other: &Rhs) -> usize
+    where
+        Rhs: ContainerRead<B, Slot = N>,
+    {
+        match other.as_slot_slice() {
+            Some(other) => par_intersection_len_impl(self.data.as_ref(), other),
+            None => intersection_len_impl(&self.data, other),
+        }
+    }
+}
+
+/// Compares containers and resizing strategies, the auto-shrink flag is not a part
+/// of bitmap identity.
+impl<D, B, S> PartialEq for VarBitmap<D, B, S>
+where
+    D: PartialEq,
+    S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && self.resizing_strategy == other.resizing_strategy
+    }
+}
+
+impl<D, B, S> Eq for VarBitmap<D, B, S>
+where
+    D: Eq,
+    S: Eq,
+{
+}
+
+impl<D, B, S> AsRef<D> for VarBitmap<D, B, S> {
+    fn as_ref(&self) -> &D {
+        &self.data
+    }
+}
+
+impl<D, B, S> AsMut<D> for VarBitmap<D, B, S> {
+    fn as_mut(&mut self) -> &mut D {
+        &mut self.data
+    }
+}
+
+/// Indexes **slots**, not bits: `bitmap[i]` is `i`-th slot of the container.
+/// Use [`get`] to read a single bit.
+///
+/// ## Panic
+///
+/// Panics if `idx` is out of bounds of the slice of slots.
+///
+/// ## Usage example:
+/// ```
+/// use bitmac::{VarBitmap, LSB, MinimumRequiredStrategy};
+///
+/// let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0001u8, 0b0000_0010]);
+/// assert_eq!(bitmap[1], 0b0000_0010);
+/// bitmap[1] |= 0b1000_0000;
+/// assert!(bitmap.get(15));
+/// ```
+///
+/// [`get`]: crate::var_bitmap::VarBitmap::get
+impl<D, N, B, S> Index<usize> for VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N> + AsRef<[N]>,
+    N: Number,
+    B: BitAccess,
+{
+    type Output = N;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.data.as_ref()[idx]
+    }
+}
+
+/// Indexes **slots**, not bits, the same way as `Index` implementation does.
+impl<D, N, B, S> IndexMut<usize> for VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N> + AsRef<[N]> + AsMut<[N]>,
+    N: Number,
+    B: BitAccess,
+{
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.data.as_mut()[idx]
+    }
+}
+
+impl<D, B, S> ContainerRead<B> for VarBitmap<D, B, S>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    type Slot = D::Slot;
+
+    fn get_slot(&self, idx: usize) -> Self::Slot {
+        self.data.get_slot(idx)
+    }
+
+    fn slots_count(&self) -> usize {
+        self.data.slots_count()
+    }
+
+    fn get_bit(&self, idx: usize) -> bool {
+        self.data.get_bit(idx)
+    }
+
+    fn bits_count(&self) -> usize {
+        self.data.bits_count()
+    }
+
+    fn as_slot_slice(&self) -> Option<&[Self::Slot]> {
+        self.data.as_slot_slice()
+    }
+}
+
+impl<D, B, S> ContainerWrite<B> for VarBitmap<D, B, S>
+where
+    D: ContainerWrite<B>,
+    B: BitAccess,
+{
+    fn get_mut_slot(&mut self, idx: usize) -> &mut Self::Slot {
+        self.data.get_mut_slot(idx)
+    }
+
+    fn try_set_bit(&mut self, idx: usize, val: bool) -> Result<(), OutOfBoundsError> {
+        self.data.try_set_bit(idx, val)
+    }
+
+    fn set_bit_unchecked(&mut self, idx: usize, val: bool) {
+        self.data.set_bit_unchecked(idx, val)
+    }
+}
+
+/// Prints raw bytes of every slot, bytes of a slot are in little-endian order
+/// (the least significant byte first). See [`debug_bytes`] to choose the order.
+///
+/// [`debug_bytes`]: crate::var_bitmap::VarBitmap::debug_bytes
+impl<D, B, S, N> Debug for VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N>,
+    N: Number,
+    B: BitAccess,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&DebugBytes::new(&self.data, Endianness::Little), f)
+    }
+}
+
+impl<D, B, S> IntoIterator for VarBitmap<D, B, S>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    type Item = <IntoIter<D, B> as Iterator>::Item;
+    type IntoIter = IntoIter<D, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.data)
+    }
+}
+
+impl<'a, D, B, S> IntoIterator for &'a VarBitmap<D, B, S>
+where
+    D: ContainerRead<B>,
+    B: BitAccess,
+{
+    type Item = <Iter<'a, D, B> as Iterator>::Item;
+    type IntoIter = Iter<'a, D, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<D, B, S, Rhs, N> BitmapOps<Rhs, N, B> for VarBitmap<D, B, S>
+where
+    D: ContainerRead<B, Slot = N>,
+    B: BitAccess,
+    Rhs: ContainerRead<B, Slot = N>,
+    N: Number,
+{
+    fn combine_in<Dst>(&self, rhs: &Rhs, op: BitOp, dst: &mut Dst)
+    where
+        Dst: ContainerWrite<B, Slot = N>,
+    {
+        try_combine_in_impl(&self.data, rhs, op, dst).unwrap();
+    }
+
+    fn try_combine_in<Dst>(&self, rhs: &Rhs, op: BitOp, dst: &mut Dst) -> Result<(), CombineError>
+    where
+        Dst: ContainerWrite<B, Slot = N>,
+    {
+        try_combine_in_impl(&self.data, rhs, op, dst)
+    }
+
+    fn combine<Dst>(&self, rhs: &Rhs, op: BitOp) -> Dst
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    {
+        try_combine_impl(&self.data, rhs, op).unwrap()
+    }
+
+    fn try_combine<Dst>(&self, rhs: &Rhs, op: BitOp) -> Result<Dst, CombineError>
+    where
+        Dst: ContainerWrite<B, Slot = N> + TryWithSlots,
+    {
+        try_combine_impl(&self.data, rhs, op)
+    }
+
+    fn combine_len(&self, rhs: &Rhs, op: BitOp) -> usize {
+        combine_len_impl(&self.data, rhs, op)
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
-    use crate::{MinimumRequiredStrategy, LSB};
+    use crate::{
+        grow_strategy::CACHE_LINE_BYTES, BitLimitStrategy, CacheLineStrategy, FixedStrategy,
+        LimitStrategy, NoGrowStrategy, MSB,
+    };
+    use crate::{Intersection, Union};
+
+    #[test]
+    #[rustfmt::skip]
+    fn get_bit() {
+        // Number
+        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 0).get(0));
+        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 1).get(1));
+        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 2).get(2));
+        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 3).get(3));
+        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 4).get(4));
+        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 5).get(5));
+        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 6).get(6));
+        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 7).get(7));
+        assert!(!VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(0b1111_1111).get(8));
+
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 0).get(0));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 1).get(1));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 2).get(2));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 3).get(3));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 4).get(4));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 5).get(5));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 6).get(6));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 7).get(7));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 8).get(8));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 9).get(9));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 10).get(10));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 11).get(11));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 12).get(12));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 13).get(13));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 14).get(14));
+        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 15).get(15));
+        assert!(!VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(0b1111_1111_1111_1111).get(16));
+
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 0).get(0));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 1).get(1));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 2).get(2));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 3).get(3));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 4).get(4));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 5).get(5));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 6).get(6));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 7).get(7));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 8).get(8));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 9).get(9));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 10).get(10));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 11).get(11));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 12).get(12));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 13).get(13));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 14).get(14));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 15).get(15));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 16).get(16));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 17).get(17));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 18).get(18));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 19).get(19));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 20).get(20));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 21).get(21));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 22).get(22));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 23).get(23));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 24).get(24));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 25).get(25));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 26).get(26));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 27).get(27));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 28).get(28));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 29).get(29));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 30).get(30));
+        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 31).get(31));
+        assert!(!VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(0b0000_0000_0000_0000_0000_0000_0000_0000).get(32));
+
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 0).get(0));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 1).get(1));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 2).get(2));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 3).get(3));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 4).get(4));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 5).get(5));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 6).get(6));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 7).get(7));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 8).get(8));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 9).get(9));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 10).get(10));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 11).get(11));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 12).get(12));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 13).get(13));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 14).get(14));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 15).get(15));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 16).get(16));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 17).get(17));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 18).get(18));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 19).get(19));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 20).get(20));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 21).get(21));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 22).get(22));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 23).get(23));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 24).get(24));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 25).get(25));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 26).get(26));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 27).get(27));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 28).get(28));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 29).get(29));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 30).get(30));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 31).get(31));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 32).get(32));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 33).get(33));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 34).get(34));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 35).get(35));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 36).get(36));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 37).get(37));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 38).get(38));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 39).get(39));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 40).get(40));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 41).get(41));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 42).get(42));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 43).get(43));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 44).get(44));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 45).get(45));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 46).get(46));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 47).get(47));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 48).get(48));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 49).get(49));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 50).get(50));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 51).get(51));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 52).get(52));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 53).get(53));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 54).get(54));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 55).get(55));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 56).get(56));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 57).get(57));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 58).get(58));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 59).get(59));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 60).get(60));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 61).get(61));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 62).get(62));
+        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 63).get(63));
+        assert!(!VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111).get(64));
+
+        // Slice
+        assert!(VarBitmap::<&'static [u8], LSB, MinimumRequiredStrategy>::from_container(&[1u8][..]).get(0));
+        assert!(VarBitmap::<&'static [u8], LSB, MinimumRequiredStrategy>::from_container(&[1u8, 1][..]).get(8));
+        assert!(!VarBitmap::<&'static [u8], LSB, MinimumRequiredStrategy>::from_container(&[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..]).get(999));
+        assert!(VarBitmap::<&'static [u16], LSB, MinimumRequiredStrategy>::from_container(&[1u16][..]).get(0));
+        assert!(VarBitmap::<&'static [u16], LSB, MinimumRequiredStrategy>::from_container(&[1u16, 1u16][..]).get(16));
+        assert!(!VarBitmap::<&'static [u16], LSB, MinimumRequiredStrategy>::from_container(&[0b1111_1111_1111_1111u16, 0b1111_1111_1111_1111, 0b1111_1111_1111_1111][..]).get(999));
+        assert!(VarBitmap::<&'static [u32], LSB, MinimumRequiredStrategy>::from_container(&[1u32][..]).get(0));
+        assert!(VarBitmap::<&'static [u32], LSB, MinimumRequiredStrategy>::from_container(&[1u32, 1][..]).get(32));
+        assert!(!VarBitmap::<&'static [u32], LSB, MinimumRequiredStrategy>::from_container(&[0b1111_1111_1111_1111_1111_1111_1111_1111u32, 0b1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111][..]).get(999));
+        assert!(VarBitmap::<&'static [u64], LSB, MinimumRequiredStrategy>::from_container(&[1u64][..]).get(0));
+        assert!(VarBitmap::<&'static [u64], LSB, MinimumRequiredStrategy>::from_container(&[1u64, 1][..]).get(64));
+        assert!(!VarBitmap::<&'static [u64], LSB, MinimumRequiredStrategy>::from_container(&[0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111][..]).get(999));
+
+        let v = &[1u8][..];
+        assert!(VarBitmap::<&[u8], LSB, MinimumRequiredStrategy>::from_container(v).get(0));
+        let v = &[1u8, 1][..];
+        assert!(VarBitmap::<&[u8], LSB, MinimumRequiredStrategy>::from_container(v).get(8));
+        let v = &[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..];
+        assert!(!VarBitmap::<&[u8], LSB, MinimumRequiredStrategy>::from_container(v).get(999));
+        let v = &[1u16][..];
+        assert!(VarBitmap::<&[u16], LSB, MinimumRequiredStrategy>::from_container(v).get(0));
+        let v = &[1u16, 1u16][..];
+        assert!(VarBitmap::<&[u16], LSB, MinimumRequiredStrategy>::from_container(v).get(16));
+        let v = &[0b1111_1111_1111_1111u16, 0b1111_1111_1111_1111, 0b1111_1111_1111_1111][..];
+        assert!(!VarBitmap::<&[u16], LSB, MinimumRequiredStrategy>::from_container(v).get(999));
+        let v = &[1u32][..];
+        assert!(VarBitmap::<&[u32], LSB, MinimumRequiredStrategy>::from_container(v).get(0));
+        let v = &[1u32, 1][..];
+        assert!(VarBitmap::<&[u32], LSB, MinimumRequiredStrategy>::from_container(v).get(32));
+        let v = &[0b1111_1111_1111_1111_1111_1111_1111_1111u32, 0b1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111][..];
+        assert!(!VarBitmap::<&[u32], LSB, MinimumRequiredStrategy>::from_container(v).get(999));
+        let v = &[1u64][..];
+        assert!(VarBitmap::<&[u64], LSB, MinimumRequiredStrategy>::from_container(v).get(0));
+        let v = &[1u64, 1][..];
+        assert!(VarBitmap::<&[u64], LSB, MinimumRequiredStrategy>::from_container(v).get(64));
+        let v = &[0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111][..];
+        assert!(!VarBitmap::<&[u64], LSB, MinimumRequiredStrategy>::from_container(v).get(999));
+
+        // Array
+        assert!(VarBitmap::<[u8; 1], LSB, MinimumRequiredStrategy>::from_container([1; 1]).get(0));
+        assert!(VarBitmap::<[u8; 2], LSB, MinimumRequiredStrategy>::from_container([1; 2]).get(8));
+        assert!(!VarBitmap::<[u8; 3], LSB, MinimumRequiredStrategy>::from_container([0b1111_1111; 3]).get(999));
+        assert!(VarBitmap::<[u16; 1], LSB, MinimumRequiredStrategy>::from_container([1; 1]).get(0));
+        assert!(VarBitmap::<[u16; 2], LSB, MinimumRequiredStrategy>::from_container([1; 2]).get(16));
+        assert!(!VarBitmap::<[u16; 3], LSB, MinimumRequiredStrategy>::from_container([0b1111_1111_1111_1111; 3]).get(999));
+        assert!(VarBitmap::<[u32; 1], LSB, MinimumRequiredStrategy>::from_container([1; 1]).get(0));
+        assert!(VarBitmap::<[u32; 2], LSB, MinimumRequiredStrategy>::from_container([1; 2]).get(32));
+        assert!(!VarBitmap::<[u32; 3], LSB, MinimumRequiredStrategy>::from_container([0b1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+        assert!(VarBitmap::<[u64; 1], LSB, MinimumRequiredStrategy>::from_container([1; 1]).get(0));
+        assert!(VarBitmap::<[u64; 2], LSB, MinimumRequiredStrategy>::from_container([1; 2]).get(64));
+        assert!(!VarBitmap::<[u64; 3], LSB, MinimumRequiredStrategy>::from_container([0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+
+        // Vec
+        assert!(VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 1]).get(0));
+        assert!(VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 2]).get(8));
+        assert!(!VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_1111; 3]).get(999));
+        assert!(VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 1]).get(0));
+        assert!(VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 2]).get(16));
+        assert!(!VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_1111_1111_1111; 3]).get(999));
+        assert!(VarBitmap::<Vec<u32>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 1]).get(0));
+        assert!(VarBitmap::<Vec<u32>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 2]).get(32));
+        assert!(!VarBitmap::<Vec<u32>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+        assert!(VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 1]).get(0));
+        assert!(VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 2]).get(64));
+        assert!(!VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+
+        // Bytes
+        #[cfg(feature = "bytes")]
+        {
+            use bytes::{Bytes, BytesMut};
+            assert!(VarBitmap::<Bytes, LSB, MinimumRequiredStrategy>::from_container(Bytes::from_static(&[1])).get(0));
+            assert!(VarBitmap::<Bytes, LSB, MinimumRequiredStrategy>::from_container(Bytes::from_static(&[1, 1])).get(8));
+            assert!(!VarBitmap::<Bytes, LSB, MinimumRequiredStrategy>::from_container(Bytes::from_static(&[0b1111_1111, 0b1111_1111, 0b1111_1111])).get(999));
+            assert!(VarBitmap::<BytesMut, LSB, MinimumRequiredStrategy>::from_container(BytesMut::from(&[1u8][..])).get(0));
+            assert!(VarBitmap::<BytesMut, LSB, MinimumRequiredStrategy>::from_container(BytesMut::from(&[1u8, 1][..])).get(8));
+            assert!(!VarBitmap::<BytesMut, LSB, MinimumRequiredStrategy>::from_container(BytesMut::from(&[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..])).get(999));
+        }
+
+        // SmallVec
+        #[cfg(feature = "smallvec")]
+        {
+            use smallvec::SmallVec;
+            assert!(VarBitmap::<SmallVec<[u8; 1]>, LSB, MinimumRequiredStrategy>::from_container(SmallVec::from([1u8])).get(0));
+            assert!(VarBitmap::<SmallVec<[u8; 2]>, LSB, MinimumRequiredStrategy>::from_container(SmallVec::from([1u8, 1])).get(8));
+            assert!(!VarBitmap::<SmallVec<[u8; 3]>, LSB, MinimumRequiredStrategy>::from_container(SmallVec::from([0b1111_1111u8, 0b1111_1111, 0b1111_1111])).get(999));
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn set_bit() {
+        // Vec
+        let mut v = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![0, 0]);
+        v.set(0, true);
+        v.set(15, true);
+        v.set(16, true);
+        assert!(v.get(0));
+        assert!(v.get(15));
+        assert!(v.get(16));
+
+        let mut v = VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_container(vec![0, 0]);
+        v.set(0, true);
+        v.set(31, true);
+        v.set(32, true);
+        assert!(v.get(0));
+        assert!(v.get(31));
+        assert!(v.get(32));
+
+        let mut v = VarBitmap::<Vec<u32>, LSB, MinimumRequiredStrategy>::from_container(vec![0, 0]);
+        v.set(0, true);
+        v.set(63, true);
+        v.set(64, true);
+        assert!(v.get(0));
+        assert!(v.get(63));
+        assert!(v.get(64));
+
+        let mut v = VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![0, 0]);
+        v.set(0, true);
+        v.set(127, true);
+        v.set(128, true);
+        assert!(v.get(0));
+        assert!(v.get(127));
+        assert!(v.get(128));
+
+        // Bytes
+        #[cfg(feature = "bytes")]
+        {
+            use bytes::{BytesMut};
+            let mut v = VarBitmap::<BytesMut, LSB, MinimumRequiredStrategy>::from_container(BytesMut::zeroed(2));
+            v.set(0, true);
+            v.set(15, true);
+            v.set(16, true);
+            assert!(v.get(0));
+            assert!(v.get(15));
+            assert!(v.get(16));
+        }
+
+        #[cfg(feature = "smallvec")]
+        {
+            use smallvec::{SmallVec, smallvec};
+            let mut v = VarBitmap::<SmallVec<[u8; 2]>, LSB, MinimumRequiredStrategy>::from_container(smallvec![0, 0]);
+            v.set(0, true);
+            v.set(15, true);
+            v.set(16, true);
+            assert!(v.get(0));
+            assert!(v.get(15));
+            assert!(v.get(16));
+        }
+    }
 
     #[test]
-    #[rustfmt::skip]
-    fn get_bit() {
-        // Number
-        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 0).get(0));
-        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 1).get(1));
-        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 2).get(2));
-        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 3).get(3));
-        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 4).get(4));
-        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 5).get(5));
-        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 6).get(6));
-        assert!(VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(1 << 7).get(7));
-        assert!(!VarBitmap::<u8, LSB, MinimumRequiredStrategy>::from_container(0b1111_1111).get(8));
+    fn from_slots() {
+        let v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_slots([0b1u8, 0b10]);
+        assert_eq!(v.as_ref(), &vec![0b1u8, 0b10]);
+        assert!(v.get(0));
+        assert!(v.get(9));
+        assert_eq!(v.count_ones(), 2);
 
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 0).get(0));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 1).get(1));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 2).get(2));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 3).get(3));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 4).get(4));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 5).get(5));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 6).get(6));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 7).get(7));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 8).get(8));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 9).get(9));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 10).get(10));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 11).get(11));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 12).get(12));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 13).get(13));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 14).get(14));
-        assert!(VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(1 << 15).get(15));
-        assert!(!VarBitmap::<u16, LSB, MinimumRequiredStrategy>::from_container(0b1111_1111_1111_1111).get(16));
+        let v =
+            VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_slots((0..3).map(|i| 1 << i));
+        assert!(v.get(0));
+        assert!(v.get(17));
+        assert!(v.get(34));
+        assert_eq!(v.count_ones(), 3);
+    }
 
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 0).get(0));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 1).get(1));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 2).get(2));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 3).get(3));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 4).get(4));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 5).get(5));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 6).get(6));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 7).get(7));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 8).get(8));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 9).get(9));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 10).get(10));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 11).get(11));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 12).get(12));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 13).get(13));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 14).get(14));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 15).get(15));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 16).get(16));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 17).get(17));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 18).get(18));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 19).get(19));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 20).get(20));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 21).get(21));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 22).get(22));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 23).get(23));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 24).get(24));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 25).get(25));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 26).get(26));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 27).get(27));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 28).get(28));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 29).get(29));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 30).get(30));
-        assert!(VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(1 << 31).get(31));
-        assert!(!VarBitmap::<u32, LSB, MinimumRequiredStrategy>::from_container(0b0000_0000_0000_0000_0000_0000_0000_0000).get(32));
+    #[test]
+    fn debug_logical() {
+        use crate::MSB;
+
+        let mut v = VarBitmap::<Vec<u16>, MSB, MinimumRequiredStrategy>::default();
+        v.set(0, true);
+        v.set(17, true);
+        assert_eq!(
+            format!("{:?}", v.debug_logical()),
+            "1000000000000000 0100000000000000"
+        );
+    }
+
+    #[test]
+    fn remove_insert_bit() {
+        let mut v =
+            VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1101]);
+        assert!(v.remove_bit(2));
+        assert_eq!(v.as_ref(), &vec![0b0101]);
+        assert!(!v.remove_bit(100));
+        assert_eq!(v.as_ref(), &vec![0b0101]);
+
+        // Last bit is not set, so container doesn't grow
+        v.insert_bit(0, true);
+        assert_eq!(v.as_ref(), &vec![0b1011]);
+
+        // Last bit is set, so container grows
+        let mut v =
+            VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1000_0000]);
+        v.insert_bit(0, false);
+        assert_eq!(v.as_ref(), &vec![0b0000_0000, 0b0000_0001]);
+
+        // Out of bounds insert is a set
+        v.insert_bit(20, true);
+        assert_eq!(v.as_ref(), &vec![0b0000_0000, 0b0000_0001, 0b0001_0000]);
+
+        let mut v = VarBitmap::<Vec<u8>, LSB, LimitStrategy<MinimumRequiredStrategy>>::new(
+            vec![0b1000_0000],
+            LimitStrategy {
+                strategy: MinimumRequiredStrategy,
+                limit: 1,
+            },
+        );
+        assert!(v.try_insert_bit(0, true).is_err());
+        assert_eq!(v.as_ref(), &vec![0b1000_0000]);
+    }
+
+    #[test]
+    fn combine_var() {
+        use crate::FixedStrategy;
+
+        let v = VarBitmap::<Vec<u8>, LSB, FixedStrategy>::new(
+            vec![0b0000_1001, 0b0000_1000],
+            FixedStrategy(4),
+        );
+        let mut r = v.intersection_var::<_, MinimumRequiredStrategy>(&[0b0000_1111u8]);
+        assert_eq!(r.as_ref(), &vec![0b0000_1001]);
+        r.set(9, true);
+        assert_eq!(r.as_ref(), &vec![0b0000_1001, 0b0000_0010]);
+
+        let mut r = v.union_var::<_, MinimumRequiredStrategy>(&[0b0000_0110u8]);
+        assert_eq!(r.as_ref(), &vec![0b0000_1111, 0b0000_1000]);
+        r.set(16, true);
+        assert_eq!(r.as_ref(), &vec![0b0000_1111, 0b0000_1000, 0b0000_0001]);
+    }
+
+    #[test]
+    fn update_in_place() {
+        let lhs = vec![0b0000_1001u8, 0b0000_1000];
+        let rhs = vec![0b0000_0011u8, 0b0000_0000, 0b0100_0000, 0b0000_0000];
+
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(lhs.clone());
+        v.union_update(&rhs);
+        let expected: Vec<u8> = v.union(&rhs);
+        assert_eq!(v.as_ref(), &expected[..3].to_vec());
+
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(lhs.clone());
+        v.intersect_update(&rhs);
+        let expected: Vec<u8> =
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(lhs.clone())
+                .intersection(&rhs);
+        assert_eq!(&v.as_ref()[..expected.len()], &expected[..]);
+        assert_eq!(v.as_ref().len(), 2);
+
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(lhs.clone());
+        v.difference_update(&rhs);
+        assert_eq!(v.as_ref(), &vec![0b0000_1000, 0b0000_1000]);
+
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(lhs.clone());
+        v.symmetric_difference_update(&rhs);
+        assert_eq!(v.as_ref(), &vec![0b0000_1010, 0b0000_1000, 0b0100_0000]);
+
+        // Growing is limited by strategy
+        let mut v = VarBitmap::<_, LSB, LimitStrategy<MinimumRequiredStrategy>>::new(
+            lhs.clone(),
+            LimitStrategy {
+                strategy: Default::default(),
+                limit: 2,
+            },
+        );
+        assert!(v.try_union_update(&rhs).is_err());
+        assert_eq!(v.as_ref(), &lhs);
+        assert!(v.try_symmetric_difference_update(&rhs).is_err());
+        assert_eq!(v.as_ref(), &lhs);
+    }
+
+    #[test]
+    fn reset() {
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFu8; 8]);
+        let capacity = v.as_ref().capacity();
+
+        v.reset(20);
+        assert!(v.is_empty());
+        assert!(v.bits_count() >= 20);
+        assert_eq!(v.as_ref().len(), 3);
+        assert_eq!(v.as_ref().capacity(), capacity);
+
+        v.set(19, true);
+        v.reset(64);
+        assert!(v.is_empty());
+        assert_eq!(v.bits_count(), 64);
+
+        v.reset(0);
+        assert_eq!(v.bits_count(), 0);
+
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFFFu16]);
+        v.reset(17);
+        assert_eq!(v.as_ref(), &vec![0, 0]);
+    }
+
+    #[test]
+    fn set_round_trip() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let set = BTreeSet::from_iter([1, 8, 15, 16, 100]);
+        let v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from(&set);
+        assert_eq!(v.as_ref().len(), 13);
+        assert_eq!(v.to_btree_set(), set);
+        assert_eq!(v.ones().collect::<Vec<_>>(), vec![1, 8, 15, 16, 100]);
+
+        let hash_set = HashSet::<_>::from_iter(set.iter().copied());
+        let v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from(&hash_set);
+        assert_eq!(v.to_btree_set(), set);
+
+        let v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from(&BTreeSet::new());
+        assert_eq!(v.as_ref().len(), 0);
+        assert!(v.to_btree_set().is_empty());
+
+        let v = VarBitmap::<_, MSB, MinimumRequiredStrategy>::from_container(vec![
+            0u16,
+            0b1000_0000_0000_0001,
+        ]);
+        assert_eq!(v.ones().collect::<Vec<_>>(), vec![16, 31]);
+    }
+
+    #[test]
+    fn switch_strategy() {
+        use crate::FixedStrategy;
+
+        let mut v = VarBitmap::<_, LSB, _>::new(vec![0u8], FixedStrategy(4));
+        v.set(8, true);
+        assert_eq!(v.as_ref().len(), 4);
+        assert_eq!(v.strategy().0, 4);
+
+        v.strategy_mut().0 = 3;
+        v.set(32, true);
+        assert_eq!(v.as_ref().len(), 6);
+
+        let mut v = v.set_strategy(MinimumRequiredStrategy);
+        v.set(56, true);
+        assert_eq!(v.as_ref().len(), 8);
+        assert!(v.get(8));
+        assert!(v.get(32));
+    }
+
+    #[test]
+    fn try_reserve_bits() {
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(Vec::<u16>::new());
+        assert!(v.try_reserve_bits(1000).is_ok());
+        assert!(v.as_ref().capacity() >= 63);
+        assert_eq!(v.as_ref().len(), 0);
+
+        let capacity = v.as_ref().capacity();
+        v.set(999, true);
+        assert_eq!(v.as_ref().capacity(), capacity);
+
+        assert!(v.try_reserve_bits(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn fill_pseudo_random() {
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u16; 8]);
+        v.fill_pseudo_random(1, 1.0);
+        assert!(v.is_full());
+        assert_eq!(v.as_ref().len(), 8);
+        v.fill_pseudo_random(1, 0.0);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn extend_from() {
+        let mut v =
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+        v.extend_from(&[0b0001_0000u8, 0b0000_0001, 0, 0]);
+        assert_eq!(v.as_ref(), &vec![0b0001_1001, 0b0000_0001, 0, 0]);
+
+        // Shorter bitmap doesn't shrink the container
+        v.extend_from(&[0b0000_0010u8]);
+        assert_eq!(v.as_ref(), &vec![0b0001_1011, 0b0000_0001, 0, 0]);
+
+        let mut v = VarBitmap::<_, LSB, LimitStrategy<MinimumRequiredStrategy>>::new(
+            vec![0b0000_1001u8],
+            LimitStrategy {
+                strategy: Default::default(),
+                limit: 2,
+            },
+        );
+        assert!(v.try_extend_from(&[0u8, 0, 0]).is_err());
+        assert_eq!(v.as_ref(), &vec![0b0000_1001]);
+    }
+
+    #[test]
+    fn to_bool_and_byte_vec() {
+        let v = VarBitmap::<_, MSB, MinimumRequiredStrategy>::from_container(vec![
+            0b1000_0000_0000_0001u16,
+            0b0100_0000_1000_0000,
+        ]);
+
+        let mut bools = vec![false; 32];
+        bools[0] = true;
+        bools[15] = true;
+        bools[17] = true;
+        bools[24] = true;
+        assert_eq!(v.to_bool_vec(), bools);
+
+        let bytes = vec![0b1000_0000u8, 0b0000_0001, 0b0100_0000, 0b1000_0000];
+        assert_eq!(v.to_byte_vec(), bytes);
+        let from_bytes = VarBitmap::<_, MSB, MinimumRequiredStrategy>::from_container(bytes);
+        assert_eq!(from_bytes.to_bool_vec(), bools);
+
+        let v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0x0102u16]);
+        assert_eq!(v.to_byte_vec(), vec![0x02, 0x01]);
+    }
+
+    #[test]
+    fn huge_index() {
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8; 2]);
+        assert!(v.try_set(usize::MAX, true).is_err());
+        assert!(v.try_set(usize::MAX - 1, true).is_err());
+        assert_eq!(v.as_ref().len(), 2);
+
+        let mut v = VarBitmap::<_, LSB, crate::GrowByFactorStrategy>::from_container(vec![0u128]);
+        assert!(v.try_set(usize::MAX, true).is_err());
+        assert!(v.try_insert_bit(usize::MAX, true).is_err());
+        assert_eq!(v.as_ref().len(), 1);
+    }
+
+    #[test]
+    fn clone_into_bitmap() {
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u16; 2]);
+        let mut dst = StaticBitmap::<Vec<u16>, LSB>::default();
+
+        v.set(3, true);
+        v.clone_into_bitmap(&mut dst);
+        assert_eq!(dst.as_ref(), v.as_ref());
+        let capacity = dst.as_ref().capacity();
+
+        for i in 0..32 {
+            v.set(i, i % 2 == 0);
+            v.clone_into_bitmap(&mut dst);
+            assert_eq!(dst.as_ref(), v.as_ref());
+            assert_eq!(dst.as_ref().capacity(), capacity);
+        }
+
+        // Destination shrinks to the source length
+        let mut dst = StaticBitmap::<_, LSB>::new(vec![0xFFFFu16; 8]);
+        v.clone_into_bitmap(&mut dst);
+        assert_eq!(dst.as_ref(), v.as_ref());
+    }
+
+    #[test]
+    fn set_reporting() {
+        let mut v =
+            VarBitmap::<_, LSB, crate::FixedStrategy>::new(vec![0u8], crate::FixedStrategy(2));
+        assert_eq!(
+            v.set_reporting(8, true).unwrap(),
+            SetOutcome {
+                grew: true,
+                old_len: 1,
+                new_len: 2,
+                changed_bit: true,
+            }
+        );
+        assert_eq!(
+            v.set_reporting(8, false).unwrap(),
+            SetOutcome {
+                grew: false,
+                old_len: 2,
+                new_len: 2,
+                changed_bit: true,
+            }
+        );
+        assert_eq!(
+            v.set_reporting(100, false).unwrap(),
+            SetOutcome {
+                grew: false,
+                old_len: 2,
+                new_len: 2,
+                changed_bit: false,
+            }
+        );
+        assert_eq!(v.set_reporting(16, true).unwrap().new_len, 4);
+    }
+
+    #[test]
+    fn truncate_slots() {
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFu8; 3]);
+        v.truncate_slots(5);
+        assert_eq!(v.as_ref().len(), 3);
+        v.truncate_slots(1);
+        assert_eq!(v.as_ref(), &vec![0xFF]);
+        assert_eq!(v.count_ones(), 8);
+        assert!(!v.get(8));
+        v.truncate_slots(0);
+        assert!(v.as_ref().is_empty());
+    }
+
+    #[test]
+    fn similarity() {
+        let v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![
+            0b0000_1111u8,
+            0b0000_0011,
+        ]);
+        let other = [0b0000_0011u8, 0b1111_0001, 0b0000_0001];
+        // intersection_len = 3, count_ones = 6, other count_ones = 8
+        assert_eq!(v.overlap_coefficient(&other), 0.5);
+        assert_eq!(v.containment(&other), 0.5);
+        assert_eq!(
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(other.to_vec())
+                .containment(v.as_ref()),
+            3.0 / 8.0
+        );
+
+        let empty = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(Vec::<u8>::new());
+        assert_eq!(empty.overlap_coefficient(&other), 0.0);
+        assert_eq!(empty.containment(&other), 0.0);
+        assert_eq!(v.overlap_coefficient(&[0u8]), 0.0);
+    }
+
+    #[test]
+    fn set_bits_from() {
+        let bits = [
+            true, false, true, true, false, false, true, true, true, false,
+        ];
+
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFu8]);
+        v.set_bits_from(5, &bits);
+        assert_eq!(v.as_ref().len(), 2);
+        assert_eq!(&v.to_bool_vec()[..5], &[true; 5]);
+        assert_eq!(&v.to_bool_vec()[5..15], &bits);
+        assert!(!v.get(15));
+
+        let mut v = VarBitmap::<_, LSB, LimitStrategy<MinimumRequiredStrategy>>::new(
+            vec![0u8],
+            LimitStrategy {
+                strategy: Default::default(),
+                limit: 1,
+            },
+        );
+        assert!(v.try_set_bits_from(5, &bits).is_err());
+        assert_eq!(v.as_ref(), &vec![0]);
+        assert!(v.try_set_bits_from(usize::MAX, &bits).is_err());
+        assert!(v.try_set_bits_from(usize::MAX, &[]).is_ok());
+    }
+
+    #[test]
+    fn bytes_padded() {
+        let mut v = VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::default();
+        v.set(0, true);
+        v.set(17, true);
+        let bytes = v.to_bytes_padded(3);
+        assert_eq!(bytes, vec![0b0000_0001, 0, 0b0000_0010]);
+
+        let restored =
+            VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_bytes_padded(&bytes);
+        assert_eq!(restored.as_ref(), &bytes);
+        assert_eq!(restored.to_btree_set(), v.to_btree_set());
+    }
+
+    #[test]
+    fn no_grow() {
+        let mut v = VarBitmap::<_, LSB, _>::new(vec![0u8; 2], NoGrowStrategy);
+        v.set(0, true);
+        v.set(15, true);
+        assert!(v.try_set(16, true).is_err());
+        assert!(v.try_set(usize::MAX, true).is_err());
+        assert!(v.try_set(16, false).is_ok());
+        assert!(v.try_union_update(&[0u8, 0, 1]).is_err());
+        assert_eq!(v.as_ref(), &vec![0b0000_0001, 0b1000_0000]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_count() {
+        let mut lhs =
+            VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![0; 100_000]);
+        lhs.fill_pseudo_random(7, 0.3);
+        let mut rhs = StaticBitmap::<Vec<u64>, LSB>::new(vec![0; 120_000]);
+        rhs.fill_pseudo_random(11, 0.6);
+
+        assert_eq!(lhs.par_count_ones(), lhs.count_ones());
+        assert_eq!(rhs.par_count_ones(), rhs.count_ones());
+        assert_eq!(lhs.par_intersection_len(&rhs), lhs.intersection_len(&rhs));
+        assert_eq!(rhs.par_intersection_len(&lhs), rhs.intersection_len(&lhs));
+    }
+
+    #[test]
+    fn bit_ops() {
+        let v = VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::default() | 3 | 70;
+        assert_eq!(v.to_btree_set(), [3, 70].into_iter().collect());
+        assert_eq!(v.as_ref().len(), 5);
+        let v = v - 70 - 1000;
+        assert_eq!(v.to_btree_set(), [3].into_iter().collect());
+        assert_eq!(v.as_ref().len(), 5);
+
+        let v = VarBitmap::<Vec<u8>, LSB, _>::new(
+            vec![],
+            LimitStrategy {
+                strategy: MinimumRequiredStrategy,
+                limit: 1,
+            },
+        );
+        assert!(v.try_with_bit(8).is_err());
+    }
+
+    #[test]
+    fn get_or() {
+        let mut v = VarBitmap::<Vec<u16>, MSB, MinimumRequiredStrategy>::default();
+        assert!(v.get_or(0, true));
+        assert!(!v.get_or(0, false));
+
+        v.set(3, true);
+        assert!(v.get_or(3, false));
+        assert!(!v.get_or(15, true));
+        assert!(v.get_or(16, true));
+        assert!(!v.get_or(16, false));
+        assert!(v.get_or(usize::MAX, true));
+    }
+
+    #[test]
+    fn union_in_many() {
+        let sources = [
+            StaticBitmap::<_, LSB>::new(vec![0b0000_0001u16]),
+            StaticBitmap::<_, LSB>::new(vec![0, 0, 0b1000_0000_0000_0000, 0]),
+            StaticBitmap::<_, LSB>::new(vec![0b0000_0110, 0b0000_0001]),
+        ];
+
+        let mut v = VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::default();
+        v.union_in_many(&sources);
+        assert_eq!(v.to_btree_set(), [0, 1, 2, 16, 47].into_iter().collect());
+        assert_eq!(v.as_ref().len(), 4);
+
+        let mut v = StaticBitmap::<_, LSB>::new([0u16; 2]);
+        v.union_in_many(sources.iter());
+        assert_eq!(v.to_btree_set(), [0, 1, 2, 16].into_iter().collect());
+
+        // Grows once, to the longest source
+        #[derive(Default)]
+        struct CountingStrategy {
+            calls: usize,
+        }
+
+        impl GrowStrategy for CountingStrategy {
+            fn try_grow(
+                &mut self,
+                min_req_len: MinimumRequiredLength,
+                old_len: usize,
+                bit_idx: usize,
+            ) -> Result<FinalLength, ResizeError> {
+                self.calls += 1;
+                MinimumRequiredStrategy.try_grow(min_req_len, old_len, bit_idx)
+            }
+        }
+
+        let mut v = VarBitmap::<Vec<u16>, LSB, CountingStrategy>::default();
+        v.union_in_many(&sources);
+        assert_eq!(v.strategy().calls, 1);
+        assert_eq!(v.as_ref().len(), 4);
+
+        let mut v = VarBitmap::<Vec<u16>, LSB, _>::new(
+            vec![],
+            LimitStrategy {
+                strategy: MinimumRequiredStrategy,
+                limit: 2,
+            },
+        );
+        assert!(v.try_union_in_many(&sources).is_err());
+        assert_eq!(v.as_ref().len(), 0);
+    }
+
+    #[test]
+    fn enumerate_ones() {
+        let mut v = VarBitmap::<Vec<u32>, MSB, MinimumRequiredStrategy>::default();
+        assert_eq!(v.enumerate_ones().count(), 0);
+
+        v.set(3, true);
+        v.set(7, true);
+        v.set(100, true);
+        assert_eq!(
+            v.enumerate_ones().collect::<Vec<_>>(),
+            vec![(0, 3), (1, 7), (2, 100)]
+        );
+    }
+
+    #[cfg(feature = "roaring")]
+    #[test]
+    fn roaring_round_trip() {
+        let set: BTreeSet<usize> = [0, 7, 8, 1000, 65_535, 65_536, 1_000_000]
+            .into_iter()
+            .collect();
+        let v = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from(&set);
+
+        let r = v.to_roaring();
+        assert_eq!(r.len(), set.len() as u64);
+        assert!(set.iter().all(|&idx| r.contains(idx as u32)));
+
+        let restored = VarBitmap::from_roaring(&r);
+        assert_eq!(restored.to_btree_set(), set);
+        assert_eq!(restored, v);
+        assert_eq!(
+            StaticBitmap::<_, LSB>::new(restored.into_inner()).to_roaring(),
+            r
+        );
+
+        assert!(VarBitmap::from_roaring(&roaring::RoaringBitmap::new()).is_empty());
+    }
+
+    #[test]
+    fn set_changed() {
+        let mut bitmap =
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_0101u8]);
+        assert!(!bitmap.set_changed(0, true).unwrap());
+        assert!(!bitmap.set_changed(2, true).unwrap());
+        assert!(bitmap.set_changed(1, true).unwrap());
+        assert!(!bitmap.set_changed(1, true).unwrap());
+        assert!(bitmap.set_changed(0, false).unwrap());
+        assert!(!bitmap.set_changed(0, false).unwrap());
+        assert_eq!(bitmap.as_ref(), &vec![0b0000_0110]);
+
+        // Clearing an out of bounds bit never grows the container
+        assert!(!bitmap.set_changed(100, false).unwrap());
+        assert_eq!(bitmap.as_ref().len(), 1);
+        assert!(bitmap.set_changed(15, true).unwrap());
+        assert_eq!(bitmap.as_ref(), &vec![0b0000_0110, 0b1000_0000]);
+    }
+
+    #[test]
+    fn prefix_and_suffix_mask() {
+        type Bitmap<N, B> = VarBitmap<Vec<N>, B, MinimumRequiredStrategy>;
+
+        assert_eq!(Bitmap::<u8, LSB>::prefix_mask(10).count_ones(), 10);
+        assert!(Bitmap::<u8, LSB>::prefix_mask(0).as_ref().is_empty());
+        assert_eq!(
+            Bitmap::<u8, LSB>::prefix_mask(16).as_ref(),
+            &vec![0xFF, 0xFF]
+        );
+        assert_eq!(
+            Bitmap::<u8, MSB>::prefix_mask(10).as_ref(),
+            &vec![0b1111_1111, 0b1100_0000]
+        );
+        assert_eq!(
+            Bitmap::<u32, LSB>::prefix_mask(40).as_ref(),
+            &vec![u32::MAX, 0xFF]
+        );
+
+        for total in 0..40 {
+            for n in 0..=total {
+                let bitmap = Bitmap::<u16, MSB>::suffix_mask(total, n);
+                assert_eq!(bitmap.as_ref().len(), (total + 15) / 16);
+                assert_eq!(
+                    bitmap.ones().collect::<Vec<_>>(),
+                    (total - n..total).collect::<Vec<_>>()
+                );
+                let bitmap = Bitmap::<u8, LSB>::prefix_mask(n);
+                assert_eq!(
+                    bitmap.ones().collect::<Vec<_>>(),
+                    (0..n).collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn set_range_grows_once() {
+        #[derive(Default)]
+        struct CountingStrategy {
+            calls: usize,
+        }
+
+        impl GrowStrategy for CountingStrategy {
+            fn try_grow(
+                &mut self,
+                min_req_len: MinimumRequiredLength,
+                old_len: usize,
+                bit_idx: usize,
+            ) -> Result<FinalLength, ResizeError> {
+                self.calls += 1;
+                MinimumRequiredStrategy.try_grow(min_req_len, old_len, bit_idx)
+            }
+        }
+
+        let mut bitmap = VarBitmap::<Vec<u8>, LSB, CountingStrategy>::default();
+        bitmap.set_range(3..10_000, true);
+        assert_eq!(bitmap.strategy().calls, 1);
+        assert_eq!(bitmap.as_ref().len(), 1250);
+        assert_eq!(bitmap.count_ones(), 10_000 - 3);
+        assert_eq!(bitmap.ones().next(), Some(3));
+
+        // Clearing out of bounds and growing within bounds don't consult strategy
+        bitmap.set_range(9_000..20_000, false);
+        bitmap.set_range(0..10_000, true);
+        assert_eq!(bitmap.strategy().calls, 1);
+        assert_eq!(bitmap.count_ones(), 10_000);
+        assert_eq!(bitmap.as_ref().len(), 1250);
+        assert_eq!(bitmap.ones().last(), Some(9_999));
+
+        for start in 0..24 {
+            for end in start..24 {
+                let mut bitmap =
+                    VarBitmap::<_, MSB, MinimumRequiredStrategy>::from_container(vec![
+                        0b1010_1010u8,
+                        0b0101_0101,
+                    ]);
+                let mut expected = bitmap.to_bool_vec();
+                expected.resize(24, false);
+                bitmap.set_range(start..end, true);
+                for bit in &mut expected[start..end] {
+                    *bit = true;
+                }
+                let expected_len = if start < end && end > 16 { 3 } else { 2 };
+                assert_eq!(bitmap.to_bool_vec(), &expected[..expected_len * 8]);
+
+                bitmap.set_range(start..end, false);
+                for bit in &mut expected[start..end] {
+                    *bit = false;
+                }
+                assert_eq!(bitmap.to_bool_vec(), &expected[..expected_len * 8]);
+            }
+        }
+    }
+
+    #[test]
+    fn intersection_consuming() {
+        let bitmap = VarBitmap::<_, LSB, FixedStrategy>::new(vec![0b1010_1010u8], FixedStrategy(4));
+        let rhs = vec![0b0110_0110u8, 0b1000_0001];
+        let ptr = rhs.as_ptr();
+        let res = bitmap.intersection_consuming(rhs);
+        assert_eq!(res.as_ref().as_ptr(), ptr);
+        assert_eq!(res.strategy(), &FixedStrategy(4));
+        assert_eq!(res.into_inner(), vec![0b0010_0010, 0b0000_0000]);
+    }
+
+    #[test]
+    fn insert_and_remove_slot() {
+        let ones = vec![0, 5, 17, 30, 31];
+        let mut bitmap = VarBitmap::<Vec<u16>, MSB, MinimumRequiredStrategy>::default();
+        for &idx in &ones {
+            bitmap.set(idx, true);
+        }
+
+        for at in 0..=2 {
+            let mut edited = bitmap.clone();
+            edited.insert_slot(at, 0);
+            let expected = ones
+                .iter()
+                .map(|&idx| if idx >= at * 16 { idx + 16 } else { idx })
+                .collect::<Vec<_>>();
+            assert_eq!(edited.ones().collect::<Vec<_>>(), expected);
+            assert_eq!(edited.as_ref().len(), 3);
+
+            assert_eq!(edited.remove_slot(at), 0);
+            assert_eq!(edited, bitmap);
+        }
+
+        let mut bitmap = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![1u8]);
+        bitmap.insert_slot(0, 0xFF);
+        assert_eq!(bitmap.as_ref(), &vec![0xFF, 1]);
+        assert_eq!(bitmap.remove_slot(1), 1);
+        assert_eq!(bitmap.remove_slot(0), 0xFF);
+        assert!(bitmap.as_ref().is_empty());
+    }
+
+    #[test]
+    fn auto_shrink() {
+        let mut bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::default();
+        assert!(!bitmap.auto_shrink());
+        bitmap.set(3, true);
+        bitmap.set(100, true);
+        bitmap.set(100, false);
+        // Disabled by default
+        assert_eq!(bitmap.as_ref().len(), 13);
 
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 0).get(0));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 1).get(1));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 2).get(2));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 3).get(3));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 4).get(4));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 5).get(5));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 6).get(6));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 7).get(7));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 8).get(8));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 9).get(9));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 10).get(10));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 11).get(11));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 12).get(12));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 13).get(13));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 14).get(14));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 15).get(15));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 16).get(16));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 17).get(17));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 18).get(18));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 19).get(19));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 20).get(20));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 21).get(21));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 22).get(22));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 23).get(23));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 24).get(24));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 25).get(25));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 26).get(26));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 27).get(27));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 28).get(28));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 29).get(29));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 30).get(30));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 31).get(31));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 32).get(32));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 33).get(33));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 34).get(34));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 35).get(35));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 36).get(36));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 37).get(37));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 38).get(38));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 39).get(39));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 40).get(40));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 41).get(41));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 42).get(42));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 43).get(43));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 44).get(44));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 45).get(45));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 46).get(46));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 47).get(47));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 48).get(48));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 49).get(49));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 50).get(50));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 51).get(51));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 52).get(52));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 53).get(53));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 54).get(54));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 55).get(55));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 56).get(56));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 57).get(57));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 58).get(58));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 59).get(59));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 60).get(60));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 61).get(61));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 62).get(62));
-        assert!(VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(1 << 63).get(63));
-        assert!(!VarBitmap::<u64, LSB, MinimumRequiredStrategy>::from_container(0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111).get(64));
+        bitmap.set_auto_shrink(true);
+        bitmap.set(100, true);
+        bitmap.set(60, true);
+        bitmap.set(100, false);
+        // The last one is still in the upper half
+        assert_eq!(bitmap.as_ref().len(), 13);
+        bitmap.set(60, false);
+        assert_eq!(bitmap.as_ref().len(), 1);
+        assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![3]);
 
-        // Slice
-        assert!(VarBitmap::<&'static [u8], LSB, MinimumRequiredStrategy>::from_container(&[1u8][..]).get(0));
-        assert!(VarBitmap::<&'static [u8], LSB, MinimumRequiredStrategy>::from_container(&[1u8, 1][..]).get(8));
-        assert!(!VarBitmap::<&'static [u8], LSB, MinimumRequiredStrategy>::from_container(&[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..]).get(999));
-        assert!(VarBitmap::<&'static [u16], LSB, MinimumRequiredStrategy>::from_container(&[1u16][..]).get(0));
-        assert!(VarBitmap::<&'static [u16], LSB, MinimumRequiredStrategy>::from_container(&[1u16, 1u16][..]).get(16));
-        assert!(!VarBitmap::<&'static [u16], LSB, MinimumRequiredStrategy>::from_container(&[0b1111_1111_1111_1111u16, 0b1111_1111_1111_1111, 0b1111_1111_1111_1111][..]).get(999));
-        assert!(VarBitmap::<&'static [u32], LSB, MinimumRequiredStrategy>::from_container(&[1u32][..]).get(0));
-        assert!(VarBitmap::<&'static [u32], LSB, MinimumRequiredStrategy>::from_container(&[1u32, 1][..]).get(32));
-        assert!(!VarBitmap::<&'static [u32], LSB, MinimumRequiredStrategy>::from_container(&[0b1111_1111_1111_1111_1111_1111_1111_1111u32, 0b1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111][..]).get(999));
-        assert!(VarBitmap::<&'static [u64], LSB, MinimumRequiredStrategy>::from_container(&[1u64][..]).get(0));
-        assert!(VarBitmap::<&'static [u64], LSB, MinimumRequiredStrategy>::from_container(&[1u64, 1][..]).get(64));
-        assert!(!VarBitmap::<&'static [u64], LSB, MinimumRequiredStrategy>::from_container(&[0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111][..]).get(999));
+        // Clearing a zero bit or a bit of non-empty slot doesn't shrink
+        let mut bitmap =
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![1u8, 0, 0, 0b11]);
+        bitmap.set_auto_shrink(true);
+        bitmap.set(16, false);
+        bitmap.set(25, false);
+        assert_eq!(bitmap.as_ref().len(), 4);
+        bitmap.set(24, false);
+        assert_eq!(bitmap.as_ref(), &vec![1]);
+        bitmap.set(0, false);
+        assert!(bitmap.as_ref().is_empty());
 
-        let v = &[1u8][..];
-        assert!(VarBitmap::<&[u8], LSB, MinimumRequiredStrategy>::from_container(v).get(0));
-        let v = &[1u8, 1][..];
-        assert!(VarBitmap::<&[u8], LSB, MinimumRequiredStrategy>::from_container(v).get(8));
-        let v = &[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..];
-        assert!(!VarBitmap::<&[u8], LSB, MinimumRequiredStrategy>::from_container(v).get(999));
-        let v = &[1u16][..];
-        assert!(VarBitmap::<&[u16], LSB, MinimumRequiredStrategy>::from_container(v).get(0));
-        let v = &[1u16, 1u16][..];
-        assert!(VarBitmap::<&[u16], LSB, MinimumRequiredStrategy>::from_container(v).get(16));
-        let v = &[0b1111_1111_1111_1111u16, 0b1111_1111_1111_1111, 0b1111_1111_1111_1111][..];
-        assert!(!VarBitmap::<&[u16], LSB, MinimumRequiredStrategy>::from_container(v).get(999));
-        let v = &[1u32][..];
-        assert!(VarBitmap::<&[u32], LSB, MinimumRequiredStrategy>::from_container(v).get(0));
-        let v = &[1u32, 1][..];
-        assert!(VarBitmap::<&[u32], LSB, MinimumRequiredStrategy>::from_container(v).get(32));
-        let v = &[0b1111_1111_1111_1111_1111_1111_1111_1111u32, 0b1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111][..];
-        assert!(!VarBitmap::<&[u32], LSB, MinimumRequiredStrategy>::from_container(v).get(999));
-        let v = &[1u64][..];
-        assert!(VarBitmap::<&[u64], LSB, MinimumRequiredStrategy>::from_container(v).get(0));
-        let v = &[1u64, 1][..];
-        assert!(VarBitmap::<&[u64], LSB, MinimumRequiredStrategy>::from_container(v).get(64));
-        let v = &[0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111u64, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111, 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111][..];
-        assert!(!VarBitmap::<&[u64], LSB, MinimumRequiredStrategy>::from_container(v).get(999));
+        // The setting is kept when strategy is replaced
+        let bitmap = bitmap.set_strategy(FixedStrategy(2));
+        assert!(bitmap.auto_shrink());
+    }
 
-        // Array
-        assert!(VarBitmap::<[u8; 1], LSB, MinimumRequiredStrategy>::from_container([1; 1]).get(0));
-        assert!(VarBitmap::<[u8; 2], LSB, MinimumRequiredStrategy>::from_container([1; 2]).get(8));
-        assert!(!VarBitmap::<[u8; 3], LSB, MinimumRequiredStrategy>::from_container([0b1111_1111; 3]).get(999));
-        assert!(VarBitmap::<[u16; 1], LSB, MinimumRequiredStrategy>::from_container([1; 1]).get(0));
-        assert!(VarBitmap::<[u16; 2], LSB, MinimumRequiredStrategy>::from_container([1; 2]).get(16));
-        assert!(!VarBitmap::<[u16; 3], LSB, MinimumRequiredStrategy>::from_container([0b1111_1111_1111_1111; 3]).get(999));
-        assert!(VarBitmap::<[u32; 1], LSB, MinimumRequiredStrategy>::from_container([1; 1]).get(0));
-        assert!(VarBitmap::<[u32; 2], LSB, MinimumRequiredStrategy>::from_container([1; 2]).get(32));
-        assert!(!VarBitmap::<[u32; 3], LSB, MinimumRequiredStrategy>::from_container([0b1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
-        assert!(VarBitmap::<[u64; 1], LSB, MinimumRequiredStrategy>::from_container([1; 1]).get(0));
-        assert!(VarBitmap::<[u64; 2], LSB, MinimumRequiredStrategy>::from_container([1; 2]).get(64));
-        assert!(!VarBitmap::<[u64; 3], LSB, MinimumRequiredStrategy>::from_container([0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+    #[test]
+    fn auto_shrink_clearing_paths() {
+        fn bitmap() -> VarBitmap<Vec<u8>, LSB, MinimumRequiredStrategy> {
+            let mut bitmap =
+                VarBitmap::from_container(vec![0b0000_0001, 0, 0, 0, 0, 0, 0, 0b1000_0001]);
+            bitmap.set_auto_shrink(true);
+            bitmap
+        }
 
-        // Vec
-        assert!(VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 1]).get(0));
-        assert!(VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 2]).get(8));
-        assert!(!VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_1111; 3]).get(999));
-        assert!(VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 1]).get(0));
-        assert!(VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 2]).get(16));
-        assert!(!VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_1111_1111_1111; 3]).get(999));
-        assert!(VarBitmap::<Vec<u32>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 1]).get(0));
-        assert!(VarBitmap::<Vec<u32>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 2]).get(32));
-        assert!(!VarBitmap::<Vec<u32>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
-        assert!(VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 1]).get(0));
-        assert!(VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![1; 2]).get(64));
-        assert!(!VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111; 3]).get(999));
+        let mut v = bitmap();
+        assert!(v.clear_if_set(56));
+        assert_eq!(v.as_ref().len(), 8);
+        assert!(v.clear_if_set(63));
+        assert_eq!(v.as_ref(), &vec![0b0000_0001]);
 
-        // Bytes
-        #[cfg(feature = "bytes")]
-        {
-            use bytes::{Bytes, BytesMut};
-            assert!(VarBitmap::<Bytes, LSB, MinimumRequiredStrategy>::from_container(Bytes::from_static(&[1])).get(0));
-            assert!(VarBitmap::<Bytes, LSB, MinimumRequiredStrategy>::from_container(Bytes::from_static(&[1, 1])).get(8));
-            assert!(!VarBitmap::<Bytes, LSB, MinimumRequiredStrategy>::from_container(Bytes::from_static(&[0b1111_1111, 0b1111_1111, 0b1111_1111])).get(999));
-            assert!(VarBitmap::<BytesMut, LSB, MinimumRequiredStrategy>::from_container(BytesMut::from(&[1u8][..])).get(0));
-            assert!(VarBitmap::<BytesMut, LSB, MinimumRequiredStrategy>::from_container(BytesMut::from(&[1u8, 1][..])).get(8));
-            assert!(!VarBitmap::<BytesMut, LSB, MinimumRequiredStrategy>::from_container(BytesMut::from(&[0b1111_1111u8, 0b1111_1111, 0b1111_1111][..])).get(999));
+        let mut v = bitmap();
+        v.set_range(57..64, false);
+        assert_eq!(v.as_ref().len(), 8);
+        v.set_range(8..64, false);
+        assert_eq!(v.as_ref(), &vec![0b0000_0001]);
+        v.set_range(0..100, false);
+        assert!(v.as_ref().is_empty());
+
+        let mut v = bitmap();
+        v.difference_update(&[0u8, 0, 0, 0, 0, 0, 0, 0b1000_0001]);
+        assert_eq!(v.as_ref(), &vec![0b0000_0001]);
+
+        let mut v = bitmap();
+        v.clear_where(&[0u8, 0, 0, 0, 0, 0, 0, 0b1000_0001]);
+        assert_eq!(v.as_ref(), &vec![0b0000_0001]);
+
+        let mut v = bitmap();
+        v.intersect_update(&[0xFFu8]);
+        assert_eq!(v.as_ref(), &vec![0b0000_0001]);
+
+        let mut v = bitmap();
+        assert!(v.remove_bit(56));
+        assert_eq!(v.as_ref().len(), 8);
+        assert!(v.remove_bit(62));
+        assert_eq!(v.as_ref(), &vec![0b0000_0001]);
+
+        // Clearing bits one by one keeps the last set bit in the upper half
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFu8; 64]);
+        v.set_auto_shrink(true);
+        for idx in (8..512).rev() {
+            assert!(v.clear_if_set(idx));
+            let required = (idx + 7) / 8;
+            let len = v.as_ref().len();
+            assert!(len >= required && len <= 2 * required, "{} {}", idx, len);
         }
+        assert_eq!(v.as_ref(), &vec![0xFF]);
 
-        // SmallVec
-        #[cfg(feature = "smallvec")]
-        {
-            use smallvec::SmallVec;
-            assert!(VarBitmap::<SmallVec<[u8; 1]>, LSB, MinimumRequiredStrategy>::from_container(SmallVec::from([1u8])).get(0));
-            assert!(VarBitmap::<SmallVec<[u8; 2]>, LSB, MinimumRequiredStrategy>::from_container(SmallVec::from([1u8, 1])).get(8));
-            assert!(!VarBitmap::<SmallVec<[u8; 3]>, LSB, MinimumRequiredStrategy>::from_container(SmallVec::from([0b1111_1111u8, 0b1111_1111, 0b1111_1111])).get(999));
+        // Disabled auto-shrinking keeps the container
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8, 1]);
+        assert!(v.clear_if_set(8));
+        v.set_range(0..16, false);
+        assert_eq!(v.as_ref(), &vec![0, 0]);
+    }
+
+    #[test]
+    fn from_sorted_indices() {
+        let indices = [1usize, 2, 2, 9, 31, 32, 64, 200];
+        let bitmap =
+            VarBitmap::<Vec<u32>, MSB, MinimumRequiredStrategy>::from_sorted_indices(&indices);
+        assert_eq!(bitmap.as_ref().len(), 7);
+
+        let mut expected = VarBitmap::<Vec<u32>, MSB, MinimumRequiredStrategy>::default();
+        for &idx in indices.iter().rev() {
+            expected.set(idx, true);
         }
+        assert_eq!(bitmap, expected);
+
+        let bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_sorted_indices(&[]);
+        assert!(bitmap.as_ref().is_empty());
+        let set = indices.iter().copied().collect::<BTreeSet<_>>();
+        assert_eq!(
+            VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_sorted_indices(&indices),
+            VarBitmap::from(&set)
+        );
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn set_bit() {
-        // Vec
-        let mut v = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::from_container(vec![0, 0]);
-        v.set(0, true);
-        v.set(15, true);
-        v.set(16, true);
-        assert!(v.get(0));
-        assert!(v.get(15));
-        assert!(v.get(16));
+    fn with_initial_slots() {
+        let mut bitmap =
+            VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::with_initial_slots(64);
+        let ptr = bitmap.as_ref().as_ptr();
+        let capacity = bitmap.as_ref().capacity();
+        for idx in 0..64 * 16 {
+            bitmap.set(idx, true);
+            assert_eq!(bitmap.as_ref().as_ptr(), ptr);
+        }
+        assert_eq!(bitmap.as_ref().capacity(), capacity);
+        assert_eq!(bitmap.as_ref().len(), 64);
+        assert!(bitmap.is_full());
 
-        let mut v = VarBitmap::<Vec<u16>, LSB, MinimumRequiredStrategy>::from_container(vec![0, 0]);
-        v.set(0, true);
-        v.set(31, true);
-        v.set(32, true);
-        assert!(v.get(0));
-        assert!(v.get(31));
-        assert!(v.get(32));
+        bitmap.set(64 * 16, true);
+        assert_eq!(bitmap.as_ref().len(), 65);
+    }
 
-        let mut v = VarBitmap::<Vec<u32>, LSB, MinimumRequiredStrategy>::from_container(vec![0, 0]);
-        v.set(0, true);
-        v.set(63, true);
-        v.set(64, true);
-        assert!(v.get(0));
-        assert!(v.get(63));
-        assert!(v.get(64));
+    #[test]
+    fn minimal_strategy_fast_path() {
+        let general = LimitStrategy {
+            strategy: MinimumRequiredStrategy,
+            limit: usize::MAX,
+        };
+        assert!(MinimumRequiredStrategy.is_minimal());
+        assert!(!general.is_minimal());
 
-        let mut v = VarBitmap::<Vec<u64>, LSB, MinimumRequiredStrategy>::from_container(vec![0, 0]);
-        v.set(0, true);
-        v.set(127, true);
-        v.set(128, true);
-        assert!(v.get(0));
-        assert!(v.get(127));
-        assert!(v.get(128));
+        let mut minimal_bitmap =
+            VarBitmap::<Vec<u16>, MSB, _>::new(vec![], MinimumRequiredStrategy);
+        let mut general_bitmap = VarBitmap::<Vec<u16>, MSB, _>::new(vec![], general);
+        for idx in [0, 5, 17, 16, 100, 99, 1000, 15] {
+            minimal_bitmap.set(idx, true);
+            general_bitmap.set(idx, true);
+            assert_eq!(minimal_bitmap.as_ref(), general_bitmap.as_ref());
+        }
+    }
 
-        // Bytes
-        #[cfg(feature = "bytes")]
-        {
-            use bytes::{BytesMut};
-            let mut v = VarBitmap::<BytesMut, LSB, MinimumRequiredStrategy>::from_container(BytesMut::zeroed(2));
-            v.set(0, true);
-            v.set(15, true);
-            v.set(16, true);
-            assert!(v.get(0));
-            assert!(v.get(15));
-            assert!(v.get(16));
+    #[test]
+    fn bit_limit_strategy() {
+        let mut bitmap = VarBitmap::<Vec<u8>, LSB, _>::new(
+            vec![],
+            BitLimitStrategy::new(MinimumRequiredStrategy, 10, u8::BITS_COUNT),
+        );
+        bitmap.try_set(9, true).unwrap();
+        assert_eq!(bitmap.as_ref().len(), 2);
+        // Bits in the last slot are available because of slot granularity
+        bitmap.try_set(15, true).unwrap();
+        assert!(bitmap.try_set(16, true).is_err());
+        assert_eq!(bitmap.as_ref().len(), 2);
+
+        // The same limit allows a single slot of 32 bits
+        let mut bitmap = VarBitmap::<Vec<u32>, LSB, _>::new(
+            vec![],
+            BitLimitStrategy::new(MinimumRequiredStrategy, 10, u32::BITS_COUNT),
+        );
+        bitmap.try_set(31, true).unwrap();
+        assert!(bitmap.try_set(32, true).is_err());
+        assert_eq!(bitmap.as_ref().len(), 1);
+    }
+
+    #[test]
+    fn fill_slots() {
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8]);
+        v.fill_slots(0..2, 0xAA);
+        assert_eq!(v.as_ref(), &vec![0xAA, 0xAA]);
+        v.fill_slots(1..4, 0);
+        assert_eq!(v.as_ref(), &vec![0xAA, 0x00]);
+
+        let mut v = VarBitmap::<_, LSB, _>::new(vec![0u8], NoGrowStrategy);
+        assert!(v.try_fill_slots(0..2, 0xAA).is_err());
+        assert_eq!(v.into_inner(), vec![0x00]);
+
+        // Last bit of the range overflows `usize`
+        let mut v = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0u8]);
+        assert!(v.try_fill_slots(0..usize::MAX, 0xAA).is_err());
+        assert_eq!(v.into_inner(), vec![0x00]);
+    }
+
+    #[test]
+    fn set_all_sorted_grows_once() {
+        #[derive(Default)]
+        struct CountingStrategy {
+            calls: usize,
         }
 
-        #[cfg(feature = "smallvec")]
-        {
-            use smallvec::{SmallVec, smallvec};
-            let mut v = VarBitmap::<SmallVec<[u8; 2]>, LSB, MinimumRequiredStrategy>::from_container(smallvec![0, 0]);
-            v.set(0, true);
-            v.set(15, true);
-            v.set(16, true);
-            assert!(v.get(0));
-            assert!(v.get(15));
-            assert!(v.get(16));
+        impl GrowStrategy for CountingStrategy {
+            fn try_grow(
+                &mut self,
+                min_req_len: MinimumRequiredLength,
+                old_len: usize,
+                bit_idx: usize,
+            ) -> Result<FinalLength, ResizeError> {
+                self.calls += 1;
+                MinimumRequiredStrategy.try_grow(min_req_len, old_len, bit_idx)
+            }
+        }
+
+        let indices = [1, 5, 5, 64, 100, 999];
+        let mut bitmap = VarBitmap::<Vec<u16>, LSB, CountingStrategy>::default();
+        bitmap.set_all_sorted(&indices).unwrap();
+        assert_eq!(bitmap.strategy().calls, 1);
+        assert_eq!(bitmap.as_ref().len(), 63);
+        assert_eq!(bitmap.ones().collect::<Vec<_>>(), vec![1, 5, 64, 100, 999]);
+
+        // Indexes within bounds don't consult strategy
+        bitmap.set_all_sorted(&[0, 998]).unwrap();
+        bitmap.set_all_sorted(&[]).unwrap();
+        assert_eq!(bitmap.strategy().calls, 1);
+        assert_eq!(bitmap.count_ones(), 7);
+
+        let mut bitmap = VarBitmap::<_, LSB, _>::new(vec![0u8], NoGrowStrategy);
+        assert!(bitmap.set_all_sorted(&[1, 8]).is_err());
+        assert_eq!(bitmap.into_inner(), vec![0]);
+    }
+
+    #[test]
+    fn eq_ignores_auto_shrink() {
+        let lhs = VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0b0000_1001u8]);
+        let mut rhs = lhs.clone();
+        rhs.set_auto_shrink(true);
+        assert_eq!(lhs, rhs);
+        rhs.set(1, true);
+        assert_ne!(lhs, rhs);
+    }
+
+    #[test]
+    fn for_each_slot_mut() {
+        let mut bitmap =
+            VarBitmap::<_, LSB, MinimumRequiredStrategy>::from_container(vec![0xFFFFu16; 3]);
+        let bit_len = 36;
+        let mut visited = Vec::new();
+        bitmap.for_each_slot_mut(|idx, slot| {
+            visited.push(idx);
+            let start = idx * 16;
+            if start + 16 > bit_len {
+                *slot &= (1 << (bit_len - start)) - 1;
+            }
+        });
+        assert_eq!(visited, vec![0, 1, 2]);
+        assert_eq!(bitmap.as_ref(), &vec![0xFFFF, 0xFFFF, 0b0000_1111]);
+        assert_eq!(bitmap.count_ones(), 36);
+
+        let mut bitmap = VarBitmap::<Vec<u8>, LSB, MinimumRequiredStrategy>::default();
+        bitmap.for_each_slot_mut(|_, _| unreachable!());
+    }
+
+    #[test]
+    fn cache_line_strategy() {
+        fn check<N: Number>(bits: &[usize]) {
+            let mut bitmap = VarBitmap::<Vec<N>, LSB, _>::new(
+                vec![],
+                CacheLineStrategy::new(MinimumRequiredStrategy, N::BITS_COUNT),
+            );
+            for &idx in bits {
+                bitmap.set(idx, true);
+                let len = bitmap.as_ref().len();
+                assert!(len * N::BITS_COUNT > idx);
+                assert_eq!(len * N::BYTES_COUNT % CACHE_LINE_BYTES, 0);
+            }
+        }
+
+        check::<u8>(&[0, 511, 512, 5000]);
+        check::<u64>(&[0, 511, 512, 5000]);
+    }
+
+    #[cfg(all(feature = "roaring", target_pointer_width = "64"))]
+    #[test]
+    fn try_to_roaring_out_of_u32() {
+        /// Container that pretends to have zero slots up to the last one without storing them.
+        struct LastBitContainer {
+            slots_count: usize,
+        }
+
+        impl ContainerRead<LSB> for LastBitContainer {
+            type Slot = u128;
+
+            fn get_slot(&self, idx: usize) -> Self::Slot {
+                if idx + 1 == self.slots_count {
+                    1
+                } else {
+                    0
+                }
+            }
+
+            fn slots_count(&self) -> usize {
+                self.slots_count
+            }
         }
+
+        let slots_count = (u32::MAX as usize + 1) / 128;
+        let v = StaticBitmap::<_, LSB>::new(LastBitContainer { slots_count });
+        assert_eq!(v.bit_len(), u32::MAX as usize - 126);
+
+        let v = StaticBitmap::<_, LSB>::new(LastBitContainer {
+            slots_count: slots_count + 1,
+        });
+        let err = v.try_to_roaring().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "index '{}' out of bounds 0..4294967296",
+                u32::MAX as usize + 1
+            )
+        );
     }
 }
@@ -3,6 +3,16 @@ use crate::{number::Number, resizable::Resizable, WithSlotsError};
 pub trait TryWithSlots: Sized {
     /// Creates new container with specified slots number.
     fn try_with_slots(len: usize) -> Result<Self, WithSlotsError>;
+
+    /// Creates new container with specified slots number and doesn't allocate more than required.
+    ///
+    /// Bitmaps use it to create containers for results of combinators. The default implementation
+    /// forwards to [`try_with_slots`].
+    ///
+    /// [`try_with_slots`]: crate::with_slots::TryWithSlots::try_with_slots
+    fn try_with_slots_exact(len: usize) -> Result<Self, WithSlotsError> {
+        Self::try_with_slots(len)
+    }
 }
 
 impl<T, N> TryWithSlots for T
@@ -15,6 +25,14 @@ where
         this.resize(len, N::ZERO);
         Ok(this)
     }
+
+    fn try_with_slots_exact(len: usize) -> Result<Self, WithSlotsError> {
+        let mut this = Self::default();
+        this.try_reserve_exact(len)
+            .map_err(|e| WithSlotsError::new(e.to_string()))?;
+        this.resize(len, N::ZERO);
+        Ok(this)
+    }
 }
 
 impl<N, const LEN: usize> TryWithSlots for [N; LEN]
@@ -26,8 +44,10 @@ where
             Ok([N::ZERO; LEN])
         } else {
             Err(WithSlotsError::new(format!(
-                "array can only store {} slots, but handled {}",
-                LEN, len
+                "array can only store {} slots of {} bits, but handled {} slots",
+                LEN,
+                N::BITS_COUNT,
+                len
             )))
         }
     }
@@ -41,7 +61,9 @@ macro_rules! with_slots_impl {
                     Ok(<$ty as Number>::ZERO)
                 } else {
                     Err(WithSlotsError::new(format!(
-                        "number can only store 1 slot, but handled {}",
+                        "number `{}` can only store 1 slot ({} bits), but handled {} slots",
+                        stringify!($ty),
+                        <$ty as Number>::BITS_COUNT,
                         len
                     )))
                 }
@@ -55,3 +77,22 @@ with_slots_impl!(u16);
 with_slots_impl!(u32);
 with_slots_impl!(u64);
 with_slots_impl!(u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_capacity() {
+        let v = Vec::<u8>::try_with_slots_exact(3).unwrap();
+        assert_eq!(v, vec![0, 0, 0]);
+        assert_eq!(v.capacity(), 3);
+
+        let v = Vec::<u64>::try_with_slots_exact(0).unwrap();
+        assert_eq!(v.capacity(), 0);
+
+        assert!(Vec::<u8>::try_with_slots_exact(usize::MAX).is_err());
+        assert!(<[u8; 2]>::try_with_slots_exact(2).is_ok());
+        assert!(<[u8; 2]>::try_with_slots_exact(3).is_err());
+    }
+}